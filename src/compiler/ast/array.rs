@@ -0,0 +1,52 @@
+/// Sequence of elements of the same data type, optionally bounded by a
+/// maximum length.
+pub struct Array {
+    identifier: super::Identifier,
+    reference: super::Reference,
+    max_len: Option<usize>,
+}
+
+impl Array {
+    /// Creates a new [`Array`] of the referenced data type.
+    pub fn from(reference: String, max_len: Option<usize>) -> Self {
+        let identifier = match max_len {
+            Some(max_len) => format!("[{reference}; max {max_len}]"),
+            None => format!("[{reference}]"),
+        };
+
+        Self {
+            identifier: super::Identifier::from(identifier),
+            reference: super::Reference::from(reference),
+            max_len,
+        }
+    }
+
+    /// Reference to the element data type
+    #[inline]
+    pub fn reference(&self) -> &super::Reference {
+        &self.reference
+    }
+
+    #[inline]
+    pub fn reference_mut(&mut self) -> &mut super::Reference {
+        &mut self.reference
+    }
+
+    /// Maximum number of elements if the array is bounded
+    #[inline]
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+}
+
+impl super::Identified for Array {
+    #[inline]
+    fn identifier(&self) -> &super::Identifier {
+        &self.identifier
+    }
+
+    #[inline]
+    fn identifier_mut(&mut self) -> &mut super::Identifier {
+        &mut self.identifier
+    }
+}
@@ -0,0 +1,39 @@
+/// Data type stored behind a pointer, a structure or variant can hold itself through
+/// it.
+pub struct Boxed {
+    identifier: super::Identifier,
+    reference: super::Reference,
+}
+
+impl Boxed {
+    /// Creates a new [`Boxed`] of the referenced data type.
+    pub fn from(reference: String) -> Self {
+        Self {
+            identifier: super::Identifier::from(format!("box<{reference}>")),
+            reference: super::Reference::from(reference),
+        }
+    }
+
+    /// Reference to the boxed data type
+    #[inline]
+    pub fn reference(&self) -> &super::Reference {
+        &self.reference
+    }
+
+    #[inline]
+    pub fn reference_mut(&mut self) -> &mut super::Reference {
+        &mut self.reference
+    }
+}
+
+impl super::Identified for Boxed {
+    #[inline]
+    fn identifier(&self) -> &super::Identifier {
+        &self.identifier
+    }
+
+    #[inline]
+    fn identifier_mut(&mut self) -> &mut super::Identifier {
+        &mut self.identifier
+    }
+}
@@ -1,4 +1,3 @@
-use super::Fielded;
 use super::Identified;
 
 pub struct DataType {
@@ -6,7 +5,9 @@ pub struct DataType {
     kind: DataTypeKind,
 
     // Additional informations about the data type
-    max_rank: usize,
+    layout: super::Layout,
+    fingerprint: u64,
+    is_recursive: bool,
 }
 
 impl DataType {
@@ -22,16 +23,31 @@ impl DataType {
                 DataTypeKind::UInt32 => super::Identifier::from_str("uint32"),
                 DataTypeKind::Int64 => super::Identifier::from_str("int64"),
                 DataTypeKind::UInt64 => super::Identifier::from_str("uint64"),
+                DataTypeKind::Int128 => super::Identifier::from_str("int128"),
+                DataTypeKind::UInt128 => super::Identifier::from_str("uint128"),
+                DataTypeKind::Half => super::Identifier::from_str("float16"),
                 DataTypeKind::Single => super::Identifier::from_str("single"),
                 DataTypeKind::Double => super::Identifier::from_str("double"),
+                DataTypeKind::Decimal(scale) => {
+                    super::Identifier::from(format!("decimal<{scale}>"))
+                }
+                DataTypeKind::Char => super::Identifier::from_str("char"),
                 DataTypeKind::String => super::Identifier::from_str("string"),
+                DataTypeKind::BoundedString(max_len) => {
+                    super::Identifier::from(format!("string<{max_len}>"))
+                }
                 DataTypeKind::Alias(alias) => alias.identifier().clone(),
+                DataTypeKind::Array(array) => array.identifier().clone(),
+                DataTypeKind::Box(boxed) => boxed.identifier().clone(),
+                DataTypeKind::Extern(extern_data_type) => extern_data_type.identifier().clone(),
                 DataTypeKind::Structure(structure) => structure.identifier().clone(),
                 DataTypeKind::Variant(variant) => variant.identifier().clone(),
             },
             kind,
 
-            max_rank: 0,
+            layout: super::Layout::default(),
+            fingerprint: 0,
+            is_recursive: false,
         }
     }
 
@@ -45,34 +61,84 @@ impl DataType {
         &mut self.kind
     }
 
-    pub fn size(&self) -> usize {
+    /// Returns whether the data type is encoded with a fixed size.
+    pub fn is_primitive(&self) -> bool {
+        matches!(
+            self.kind,
+            DataTypeKind::Boolean
+                | DataTypeKind::Int8
+                | DataTypeKind::Int16
+                | DataTypeKind::Int32
+                | DataTypeKind::Int64
+                | DataTypeKind::UInt8
+                | DataTypeKind::UInt16
+                | DataTypeKind::UInt32
+                | DataTypeKind::UInt64
+                | DataTypeKind::Int128
+                | DataTypeKind::UInt128
+                | DataTypeKind::Half
+                | DataTypeKind::Single
+                | DataTypeKind::Double
+                | DataTypeKind::Decimal(_)
+                | DataTypeKind::Char
+        )
+    }
+
+    /// Returns whether the data type is built into the compiler.
+    pub fn is_built_in(&self) -> bool {
+        !matches!(
+            self.kind,
+            DataTypeKind::Alias(_)
+                | DataTypeKind::Array(_)
+                | DataTypeKind::Box(_)
+                | DataTypeKind::Extern(_)
+                | DataTypeKind::Structure(_)
+                | DataTypeKind::Variant(_)
+        )
+    }
+
+    /// Returns the deprecation message if the data type is deprecated.
+    pub fn deprecated(&self) -> Option<&String> {
         match &self.kind {
-            DataTypeKind::Boolean => 1,
-            DataTypeKind::Int8 => 1,
-            DataTypeKind::Int16 => 2,
-            DataTypeKind::Int32 => 4,
-            DataTypeKind::Int64 => 8,
-            DataTypeKind::UInt8 => 1,
-            DataTypeKind::UInt16 => 2,
-            DataTypeKind::UInt32 => 4,
-            DataTypeKind::UInt64 => 8,
-            DataTypeKind::Single => 4,
-            DataTypeKind::Double => 8,
-            DataTypeKind::String => 2,
-            DataTypeKind::Alias(_) => 0,
-            DataTypeKind::Structure(structure) => structure.min_size(),
-            DataTypeKind::Variant(variant) => variant.min_size(),
+            DataTypeKind::Structure(structure) => structure.structure_options().deprecated.as_ref(),
+            DataTypeKind::Variant(variant) => variant.variant_options().deprecated.as_ref(),
+            _ => None,
         }
     }
 
+    /// Wire layout in a message buffer
+    #[inline]
+    pub fn layout(&self) -> &super::Layout {
+        &self.layout
+    }
+
+    #[inline]
+    pub fn set_layout(&mut self, layout: super::Layout) {
+        self.layout = layout;
+    }
+
+    /// Hash of the wire shape, the same on both ends of a connection if they agree on
+    /// the encoding
+    #[inline]
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    #[inline]
+    pub fn set_fingerprint(&mut self, fingerprint: u64) {
+        self.fingerprint = fingerprint;
+    }
+
+    /// Returns whether the data type contains itself through a box or an unbounded
+    /// array.
     #[inline]
-    pub fn max_rank(&self) -> usize {
-        self.max_rank
+    pub fn is_recursive(&self) -> bool {
+        self.is_recursive
     }
 
     #[inline]
-    pub fn update_max_rank(&mut self, max_rank: usize) {
-        self.max_rank = std::cmp::max(self.max_rank, max_rank);
+    pub fn set_recursive(&mut self, is_recursive: bool) {
+        self.is_recursive = is_recursive;
     }
 }
 
@@ -98,11 +164,22 @@ pub enum DataTypeKind {
     UInt16,
     UInt32,
     UInt64,
+    Int128,
+    UInt128,
+    Half,
     Single,
     Double,
+    /// Fixed-point decimal with the given number of fractional digits
+    Decimal(u8),
+    /// Unicode scalar value
+    Char,
     String,
+    BoundedString(usize),
 
     Alias(super::Alias),
+    Array(super::Array),
+    Box(super::Boxed),
+    Extern(super::Extern),
     Structure(super::Structure),
     Variant(super::Variant),
 }
@@ -1,11 +1,13 @@
-use super::Identifier;
+use super::{Identifier, Location};
 
 pub enum Expression {
     Value(Value),
     Variable(Identifier),
     Call(String, Vec<Expression>),
-    UnaryOperator(UnaryOperator, Box<Expression>),
-    BinaryOperator(Box<Expression>, BinaryOperator, Box<Expression>),
+    /// Operator with the location of its token, followed by the operand
+    UnaryOperator(UnaryOperator, Location, Box<Expression>),
+    /// Operands around the operator with the location of its token
+    BinaryOperator(Box<Expression>, BinaryOperator, Location, Box<Expression>),
 }
 
 impl Expression {
@@ -25,7 +27,10 @@ impl std::fmt::Display for Expression {
             Self::Call(name, arguments) => {
                 write!(f, "{}(", name)?;
 
-                for argument in arguments {
+                for (index, argument) in arguments.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
                     argument.fmt(f)?;
                 }
 
@@ -33,8 +38,8 @@ impl std::fmt::Display for Expression {
 
                 Ok(())
             }
-            Self::UnaryOperator(op, expression) => write!(f, "{}{}", op, expression),
-            Self::BinaryOperator(expression_1, op, expression_2) => {
+            Self::UnaryOperator(op, _, expression) => write!(f, "{}{}", op, expression),
+            Self::BinaryOperator(expression_1, op, _, expression_2) => {
                 write!(f, "{}{}{}", expression_1, op, expression_2)
             }
         }
@@ -66,16 +71,20 @@ impl std::fmt::Display for Value {
 
 impl Value {
     pub fn is_true(&self) -> bool {
-        match self {
-            Self::True | Self::Null => true,
-            _ => false,
-        }
+        matches!(self, Self::True | Self::Null)
     }
 
     pub fn is_false(&self) -> bool {
-        match self {
-            Self::False => true,
-            _ => false,
+        matches!(self, Self::False)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
         }
     }
 }
@@ -134,3 +143,26 @@ impl std::fmt::Display for BinaryOperator {
         }
     }
 }
+
+/// Resolves the escape sequences of a literal.
+pub fn unescape_literal(literal: &str) -> String {
+    let mut output = String::with_capacity(literal.len());
+
+    let mut chars = literal.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => output.push('\n'),
+                Some('r') => output.push('\r'),
+                Some('t') => output.push('\t'),
+                Some('0') => output.push('\0'),
+                Some(c) => output.push(c),
+                None => output.push('\\'),
+            }
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+
+/// Data type that is defined outside of the schema.
+///
+/// It is encoded like its underlying built-in data type and converted by
+/// user-provided functions in the generated code.
+pub struct Extern {
+    identifier: super::Identifier,
+    reference: super::Reference,
+
+    options: BTreeMap<String, Vec<super::Expression>>,
+    option_locations: BTreeMap<String, super::Location>,
+
+    // Evaluated options
+    extern_options: ExternOptions,
+}
+
+impl Extern {
+    pub fn new() -> Self {
+        Self {
+            identifier: super::Identifier::new(),
+            reference: super::Reference::new(),
+
+            options: BTreeMap::new(),
+            option_locations: BTreeMap::new(),
+
+            extern_options: ExternOptions::default(),
+        }
+    }
+
+    /// Reference to the underlying data type
+    #[inline]
+    pub fn reference(&self) -> &super::Reference {
+        &self.reference
+    }
+
+    #[inline]
+    pub fn reference_mut(&mut self) -> &mut super::Reference {
+        &mut self.reference
+    }
+
+    pub fn extern_options(&self) -> &ExternOptions {
+        &self.extern_options
+    }
+
+    pub fn extern_options_mut(&mut self) -> &mut ExternOptions {
+        &mut self.extern_options
+    }
+}
+
+impl super::Identified for Extern {
+    #[inline]
+    fn identifier(&self) -> &super::Identifier {
+        &self.identifier
+    }
+
+    #[inline]
+    fn identifier_mut(&mut self) -> &mut super::Identifier {
+        &mut self.identifier
+    }
+}
+
+impl super::Optioned for Extern {
+    fn options(&self) -> &BTreeMap<String, Vec<super::Expression>> {
+        &self.options
+    }
+
+    fn options_mut(&mut self) -> &mut BTreeMap<String, Vec<super::Expression>> {
+        &mut self.options
+    }
+
+    fn add_option(
+        &mut self,
+        name: String,
+        arguments: Vec<super::Expression>,
+        location: super::Location,
+    ) -> bool {
+        if self.options.contains_key(&name) {
+            return false;
+        }
+
+        self.option_locations.insert(name.clone(), location);
+        self.options.insert(name, arguments);
+        true
+    }
+
+    fn option(&self, name: &str) -> Option<&Vec<super::Expression>> {
+        self.options.get(name)
+    }
+
+    fn option_location(&self, name: &str) -> Option<super::Location> {
+        self.option_locations.get(name).copied()
+    }
+
+    fn option_mut(&mut self, name: &str) -> Option<&mut Vec<super::Expression>> {
+        self.options.get_mut(name)
+    }
+}
+
+pub struct ExternOptions {
+    /// C++ type name
+    pub cpp: String,
+    /// C++ header declaring the type and its conversion functions
+    pub cpp_include: Option<String>,
+    /// C++ function converting the type to its underlying data type,
+    /// called as `wire = to_wire(value)`
+    pub cpp_to_wire: String,
+    /// C++ function converting the underlying data type to the type,
+    /// called as `from_wire(wire, value)`
+    pub cpp_from_wire: String,
+}
+
+impl Default for ExternOptions {
+    fn default() -> Self {
+        Self {
+            cpp: String::new(),
+            cpp_include: None,
+            cpp_to_wire: "ToWire".to_string(),
+            cpp_from_wire: "FromWire".to_string(),
+        }
+    }
+}
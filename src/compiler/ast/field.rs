@@ -1,6 +1,17 @@
+use std::collections::BTreeMap;
+
 pub struct Field {
     identifier: super::Identifier,
     reference: super::Reference,
+
+    options: BTreeMap<String, Vec<super::Expression>>,
+    option_locations: BTreeMap<String, super::Location>,
+
+    // Location of the declaration in the source code
+    location: Option<super::Location>,
+
+    // Evaluated options
+    field_options: FieldOptions,
 }
 
 impl Field {
@@ -8,6 +19,13 @@ impl Field {
         Self {
             identifier: super::Identifier::new(),
             reference: super::Reference::new(),
+
+            options: BTreeMap::new(),
+            option_locations: BTreeMap::new(),
+
+            location: None,
+
+            field_options: FieldOptions::default(),
         }
     }
 
@@ -30,21 +48,84 @@ impl Field {
     pub fn reference_mut(&mut self) -> &mut super::Reference {
         &mut self.reference
     }
+
+    #[inline]
+    pub fn location(&self) -> Option<super::Location> {
+        self.location
+    }
+
+    #[inline]
+    pub fn set_location(&mut self, location: super::Location) {
+        self.location = Some(location);
+    }
+
+    pub fn field_options(&self) -> &FieldOptions {
+        &self.field_options
+    }
+
+    pub fn field_options_mut(&mut self) -> &mut FieldOptions {
+        &mut self.field_options
+    }
+}
+
+impl super::Optioned for Field {
+    fn options(&self) -> &BTreeMap<String, Vec<super::Expression>> {
+        &self.options
+    }
+
+    fn options_mut(&mut self) -> &mut BTreeMap<String, Vec<super::Expression>> {
+        &mut self.options
+    }
+
+    fn add_option(
+        &mut self,
+        name: String,
+        arguments: Vec<super::Expression>,
+        location: super::Location,
+    ) -> bool {
+        if self.options.contains_key(&name) {
+            return false;
+        }
+
+        self.option_locations.insert(name.clone(), location);
+        self.options.insert(name, arguments);
+        true
+    }
+
+    fn option(&self, name: &str) -> Option<&Vec<super::Expression>> {
+        self.options.get(name)
+    }
+
+    fn option_location(&self, name: &str) -> Option<super::Location> {
+        self.option_locations.get(name).copied()
+    }
+
+    fn option_mut(&mut self, name: &str) -> Option<&mut Vec<super::Expression>> {
+        self.options.get_mut(name)
+    }
+}
+
+#[derive(Default)]
+pub struct FieldOptions {
+    /// Deprecation message, empty if no message was given.
+    pub deprecated: Option<String>,
+
+    // Validation constraints
+    pub range: Option<(super::Value, super::Value)>,
+    pub max_len: Option<u64>,
+    pub non_empty: bool,
+    pub pattern: Option<String>,
 }
 
 // That contains fields
 pub trait Fielded {
     fn fields(&self) -> &[Field];
 
+    fn fields_mut(&mut self) -> &mut [Field];
+
     fn add_field(&mut self, field: Field);
 
     fn field(&self, id: usize) -> Option<&Field>;
 
     fn field_mut(&mut self, id: usize) -> Option<&mut Field>;
-
-    /// Returns the minimal data type size.
-    fn min_size(&self) -> usize;
-
-    /// Update minimal data structure size
-    fn update_min_size(&mut self, size: usize);
-}
\ No newline at end of file
+}
@@ -1,10 +1,15 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use super::Identified;
 
 pub struct File {
     name: String,
 
     data_types: Vec<super::DataType>,
-    options: HashMap<String, Vec<super::Expression>>,
+    /// Ids of the data types with the dependencies of a data type before it
+    order: Vec<usize>,
+    services: Vec<super::Service>,
+    options: BTreeMap<String, Vec<super::Expression>>,
+    option_locations: BTreeMap<String, super::Location>,
     file_options: FileOptions,
 }
 
@@ -13,7 +18,10 @@ impl File {
         Self {
             name: name.to_string(),
             data_types: Vec::new(),
-            options: HashMap::new(),
+            order: Vec::new(),
+            services: Vec::new(),
+            options: BTreeMap::new(),
+            option_locations: BTreeMap::new(),
             file_options: FileOptions::default(),
         }
     }
@@ -46,6 +54,58 @@ impl File {
             .push(super::DataType::from(super::DataTypeKind::Alias(alias)));
     }
 
+    /// Adds a bounded string data type unless it was already added.
+    pub fn add_bounded_string(&mut self, max_len: usize) -> String {
+        self.add_data_type_once(super::DataType::from(
+            super::DataTypeKind::BoundedString(max_len),
+        ))
+    }
+
+    /// Adds a decimal data type unless it was already added.
+    pub fn add_decimal(&mut self, scale: u8) -> String {
+        self.add_data_type_once(super::DataType::from(super::DataTypeKind::Decimal(scale)))
+    }
+
+    /// Adds an array data type unless it was already added.
+    pub fn add_array(&mut self, array: super::Array) -> String {
+        self.add_data_type_once(super::DataType::from(super::DataTypeKind::Array(array)))
+    }
+
+    /// Adds a boxed data type unless it was already added.
+    pub fn add_box(&mut self, boxed: super::Boxed) -> String {
+        self.add_data_type_once(super::DataType::from(super::DataTypeKind::Box(boxed)))
+    }
+
+    fn add_data_type_once(&mut self, data_type: super::DataType) -> String {
+        let name = data_type.identifier().get().to_string();
+
+        if !self
+            .data_types
+            .iter()
+            .any(|other| other.identifier().get() == name)
+        {
+            self.data_types.push(data_type);
+        }
+
+        name
+    }
+
+    pub fn add_extern(&mut self, extern_data_type: super::Extern) {
+        self.data_types
+            .push(super::DataType::from(super::DataTypeKind::Extern(
+                extern_data_type,
+            )));
+    }
+
+    /// Returns the data types in dependency order, generators declare them this way.
+    pub fn ordered_data_types(&self) -> impl Iterator<Item = &super::DataType> {
+        self.order.iter().filter_map(|id| self.data_types.get(*id))
+    }
+
+    pub fn set_order(&mut self, order: Vec<usize>) {
+        self.order = order;
+    }
+
     pub fn data_type(&self, id: usize) -> Option<&super::DataType> {
         self.data_types.get(id)
     }
@@ -54,6 +114,20 @@ impl File {
         self.data_types.get_mut(id)
     }
 
+    #[inline]
+    pub fn services(&self) -> &[super::Service] {
+        self.services.as_slice()
+    }
+
+    #[inline]
+    pub fn services_mut(&mut self) -> &mut [super::Service] {
+        self.services.as_mut_slice()
+    }
+
+    pub fn add_service(&mut self, service: super::Service) {
+        self.services.push(service);
+    }
+
     pub fn file_options(&self) -> &FileOptions {
         &self.file_options
     }
@@ -64,22 +138,37 @@ impl File {
 }
 
 impl super::Optioned for File {
-    fn options(&self) -> &HashMap<String, Vec<super::Expression>> {
+    fn options(&self) -> &BTreeMap<String, Vec<super::Expression>> {
         &self.options
     }
 
-    fn options_mut(&mut self) -> &mut HashMap<String, Vec<super::Expression>> {
+    fn options_mut(&mut self) -> &mut BTreeMap<String, Vec<super::Expression>> {
         &mut self.options
     }
 
-    fn add_option(&mut self, name: String, arguments: Vec<super::Expression>) -> bool {
-        self.options.insert(name, arguments).is_none()
+    fn add_option(
+        &mut self,
+        name: String,
+        arguments: Vec<super::Expression>,
+        location: super::Location,
+    ) -> bool {
+        if self.options.contains_key(&name) {
+            return false;
+        }
+
+        self.option_locations.insert(name.clone(), location);
+        self.options.insert(name, arguments);
+        true
     }
 
     fn option(&self, name: &str) -> Option<&Vec<super::Expression>> {
         self.options.get(name)
     }
 
+    fn option_location(&self, name: &str) -> Option<super::Location> {
+        self.option_locations.get(name).copied()
+    }
+
     fn option_mut(&mut self, name: &str) -> Option<&mut Vec<super::Expression>> {
         self.options.get_mut(name)
     }
@@ -88,4 +177,5 @@ impl super::Optioned for File {
 #[derive(Default)]
 pub struct FileOptions {
     pub cpp_namespace: Vec<String>,
+    pub message_buffer_size_type: super::SizeType,
 }
@@ -3,22 +3,28 @@ use convert_case::{Case, Casing};
 #[derive(Clone)]
 pub struct Identifier {
     value: Option<String>,
+    location: Option<super::Location>,
 }
 
 impl Identifier {
     pub fn new() -> Self {
-        Self { value: None }
+        Self {
+            value: None,
+            location: None,
+        }
     }
 
     pub fn from(identifier: String) -> Self {
         Self {
             value: Some(identifier.to_string()),
+            location: None,
         }
     }
 
     pub fn from_str(identifier: &str) -> Self {
         Self {
             value: Some(identifier.to_string()),
+            location: None,
         }
     }
 
@@ -52,6 +58,17 @@ impl Identifier {
     pub fn set(&mut self, name: String) {
         self.value = Some(name);
     }
+
+    /// Returns where the identifier was declared, built-in identifiers have no location.
+    #[inline]
+    pub fn location(&self) -> Option<super::Location> {
+        self.location
+    }
+
+    #[inline]
+    pub fn set_location(&mut self, location: super::Location) {
+        self.location = Some(location);
+    }
 }
 
 impl std::fmt::Display for Identifier {
@@ -63,4 +80,4 @@ impl std::fmt::Display for Identifier {
 pub trait Identified {
     fn identifier(&self) -> &super::Identifier;
     fn identifier_mut(&mut self) -> &mut super::Identifier;
-}
\ No newline at end of file
+}
@@ -0,0 +1,132 @@
+/// Number of bytes of encoded data, from a minimum to a maximum if it is bounded.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Size {
+    pub min: usize,
+    pub max: Option<usize>,
+}
+
+impl Size {
+    pub fn fixed(size: usize) -> Self {
+        Self {
+            min: size,
+            max: Some(size),
+        }
+    }
+
+    pub fn unbounded(min: usize) -> Self {
+        Self { min, max: None }
+    }
+
+    /// Returns whether every encoding has the same size.
+    #[inline]
+    pub fn is_fixed(&self) -> bool {
+        self.max == Some(self.min)
+    }
+
+    /// Returns the size of this data followed by the other.
+    pub fn then(&self, other: Size) -> Self {
+        Self {
+            min: self.min.saturating_add(other.min),
+            max: self.max.zip(other.max).and_then(|(a, b)| a.checked_add(b)),
+        }
+    }
+
+    /// Returns the size of either this data or the other.
+    pub fn or(&self, other: Size) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.zip(other.max).map(|(a, b)| a.max(b)),
+        }
+    }
+
+    /// Returns the size of the data repeated up to the given number of times.
+    pub fn repeat(&self, max_len: usize) -> Self {
+        Self {
+            min: 0,
+            max: self.max.and_then(|max| max.checked_mul(max_len)),
+        }
+    }
+}
+
+impl Default for Size {
+    fn default() -> Self {
+        Self::fixed(0)
+    }
+}
+
+/// Integer type of the size offset and of the lengths in a message buffer.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum SizeType {
+    #[default]
+    UInt16,
+    UInt32,
+}
+
+impl SizeType {
+    #[inline]
+    pub fn bits(&self) -> usize {
+        match self {
+            SizeType::UInt16 => 16,
+            SizeType::UInt32 => 32,
+        }
+    }
+
+    /// Encoded size in bytes
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.bits() / 8
+    }
+
+    /// Largest size or length it holds
+    #[inline]
+    pub fn max(&self) -> usize {
+        match self {
+            SizeType::UInt16 => u16::MAX as usize,
+            SizeType::UInt32 => u32::MAX as usize,
+        }
+    }
+}
+
+/// Position of a field in the encoded data of its structure or variant.
+#[derive(Clone, Copy)]
+pub struct FieldLayout {
+    /// Offset from the start of the encoded data, later when data before it is longer
+    pub offset: Size,
+    pub size: Size,
+}
+
+/// Wire layout of a data type in a message buffer.
+#[derive(Clone, Default)]
+pub struct Layout {
+    size: Size,
+    prefix_size: usize,
+    fields: Vec<FieldLayout>,
+}
+
+impl Layout {
+    pub fn new(size: Size, prefix_size: usize, fields: Vec<FieldLayout>) -> Self {
+        Self {
+            size,
+            prefix_size,
+            fields,
+        }
+    }
+
+    /// Encoded size of the data type
+    #[inline]
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Number of leading bytes that are at the same offset in every encoding
+    #[inline]
+    pub fn prefix_size(&self) -> usize {
+        self.prefix_size
+    }
+
+    /// Layout of the fields of a structure or variant, in declaration order
+    #[inline]
+    pub fn fields(&self) -> &[FieldLayout] {
+        &self.fields
+    }
+}
@@ -0,0 +1,46 @@
+use crate::compiler::parser::lexer::Token;
+
+/// Location of a syntax element in the source code.
+#[derive(Clone, Copy)]
+pub struct Location {
+    // Zero-based line and column of the start
+    line: usize,
+    column: usize,
+    span: (usize, usize),
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize, span: (usize, usize)) -> Self {
+        Self { line, column, span }
+    }
+
+    #[inline]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    #[inline]
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    #[inline]
+    pub fn span(&self) -> (usize, usize) {
+        self.span
+    }
+
+    /// Returns the location from the start of this one to the end of the other.
+    pub fn to(&self, other: Location) -> Self {
+        Self {
+            line: self.line,
+            column: self.column,
+            span: (self.span.0, other.span.1.max(self.span.0)),
+        }
+    }
+}
+
+impl From<Token<'_>> for Location {
+    fn from(token: Token) -> Self {
+        Location::new(token.line, token.column, token.span)
+    }
+}
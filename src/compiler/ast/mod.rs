@@ -8,6 +8,13 @@ pub mod opt;
 pub mod structure;
 pub mod variant;
 pub mod alias;
+pub mod array;
+pub mod boxed;
+pub mod external;
+pub mod layout;
+pub mod reserved;
+pub mod service;
+pub mod location;
 
 pub use file::*;
 pub use data_type::*;
@@ -19,3 +26,10 @@ pub use expression::*;
 pub use structure::*;
 pub use variant::*;
 pub use alias::*;
+pub use array::*;
+pub use boxed::*;
+pub use external::*;
+pub use layout::*;
+pub use reserved::*;
+pub use service::*;
+pub use location::*;
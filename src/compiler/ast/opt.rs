@@ -1,21 +1,37 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 // That contains options
 pub trait Optioned {
-    fn options(&self) -> &HashMap<String, Vec<super::Expression>>;
-    fn options_mut(&mut self) -> &mut HashMap<String, Vec<super::Expression>>;
+    fn options(&self) -> &BTreeMap<String, Vec<super::Expression>>;
+    fn options_mut(&mut self) -> &mut BTreeMap<String, Vec<super::Expression>>;
 
-    fn add_option(&mut self, name: String, arguments: Vec<super::Expression>) -> bool;
+    /// Adds an option entry, returns false and keeps the first entry if it is already defined.
+    fn add_option(
+        &mut self,
+        name: String,
+        arguments: Vec<super::Expression>,
+        location: super::Location,
+    ) -> bool;
 
     fn option(&self, name: &str) -> Option<&Vec<super::Expression>>;
 
+    /// Returns where the option entry was written.
+    fn option_location(&self, name: &str) -> Option<super::Location>;
+
+    /// Returns the names of the option entries in the order they were written.
+    fn option_names(&self) -> Vec<String> {
+        let mut names = self.options().keys().cloned().collect::<Vec<String>>();
+        names.sort_by_key(|name| self.option_location(name).map(|location| location.span()));
+
+        names
+    }
+
     fn is_option_enabled(&self, name: &str) -> bool {
         if let Some(opt) = self.option(name) {
-            if let [super::Expression::Value(value)] = opt.as_slice() {
-                match value {
-                    super::Value::True | super::Value::Null => return true,
-                    _ => {}
-                }
+            if let [super::Expression::Value(super::Value::True | super::Value::Null)] =
+                opt.as_slice()
+            {
+                return true;
             }
         }
 
@@ -30,11 +46,10 @@ pub trait Optioned {
     fn is_option_enabled_at(&self, name: &str, index: usize) -> bool {
         if let Some(opt) = self.option(name) {
             if index < opt.len() {
-                if let super::Expression::Value(value) = &opt[index] {
-                    match value {
-                        super::Value::True | super::Value::Null => return true,
-                        _ => {}
-                    }
+                if let super::Expression::Value(super::Value::True | super::Value::Null) =
+                    &opt[index]
+                {
+                    return true;
                 }
             }
         }
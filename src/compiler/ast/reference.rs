@@ -2,6 +2,7 @@
 pub struct Reference {
     value: Option<String>,
     id: Option<usize>,
+    location: Option<super::Location>,
 }
 
 impl Reference {
@@ -9,6 +10,7 @@ impl Reference {
         Self {
             value: None,
             id: None,
+            location: None,
         }
     }
 
@@ -16,6 +18,7 @@ impl Reference {
         Self {
             value: Some(reference),
             id: None,
+            location: None,
         }
     }
 
@@ -42,6 +45,17 @@ impl Reference {
         self.value = Some(name);
     }
 
+    /// Returns where the reference was written, implicit references have no location.
+    #[inline]
+    pub fn location(&self) -> Option<super::Location> {
+        self.location
+    }
+
+    #[inline]
+    pub fn set_location(&mut self, location: super::Location) {
+        self.location = Some(location);
+    }
+
     #[inline]
     pub fn has_id(&self) -> bool {
         self.id.is_some()
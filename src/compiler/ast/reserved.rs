@@ -0,0 +1,56 @@
+pub enum ReservationKind {
+    /// Inclusive range of field numbers.
+    Range(i64, i64),
+    /// Field name.
+    Name(String),
+}
+
+/// Field numbers or names that can not be used by any field.
+///
+/// Field numbers are the 1-based declaration order of the fields, which
+/// is also the discriminant of a variant case. Removing a field shifts the
+/// numbers after it, so a reserved number only protects removed trailing
+/// fields from being replaced by new ones.
+pub struct Reservation {
+    kind: ReservationKind,
+
+    // Location of the reservation in the source code
+    location: super::Location,
+}
+
+impl Reservation {
+    pub fn new(kind: ReservationKind, location: super::Location) -> Self {
+        Self { kind, location }
+    }
+
+    #[inline]
+    pub fn kind(&self) -> &ReservationKind {
+        &self.kind
+    }
+
+    #[inline]
+    pub fn location(&self) -> super::Location {
+        self.location
+    }
+
+    pub fn contains_number(&self, number: i64) -> bool {
+        match &self.kind {
+            ReservationKind::Range(start, end) => *start <= number && number <= *end,
+            ReservationKind::Name(_) => false,
+        }
+    }
+
+    pub fn contains_name(&self, name: &str) -> bool {
+        match &self.kind {
+            ReservationKind::Range(_, _) => false,
+            ReservationKind::Name(reserved_name) => reserved_name == name,
+        }
+    }
+}
+
+// That contains reservations
+pub trait Reserved {
+    fn reservations(&self) -> &[Reservation];
+
+    fn add_reservation(&mut self, reservation: Reservation);
+}
@@ -0,0 +1,90 @@
+pub struct Rpc {
+    identifier: super::Identifier,
+    request: super::Reference,
+    response: super::Reference,
+}
+
+impl Rpc {
+    pub fn new() -> Self {
+        Self {
+            identifier: super::Identifier::new(),
+            request: super::Reference::new(),
+            response: super::Reference::new(),
+        }
+    }
+
+    #[inline]
+    pub fn request(&self) -> &super::Reference {
+        &self.request
+    }
+
+    #[inline]
+    pub fn request_mut(&mut self) -> &mut super::Reference {
+        &mut self.request
+    }
+
+    #[inline]
+    pub fn response(&self) -> &super::Reference {
+        &self.response
+    }
+
+    #[inline]
+    pub fn response_mut(&mut self) -> &mut super::Reference {
+        &mut self.response
+    }
+}
+
+impl super::Identified for Rpc {
+    #[inline]
+    fn identifier(&self) -> &super::Identifier {
+        &self.identifier
+    }
+
+    #[inline]
+    fn identifier_mut(&mut self) -> &mut super::Identifier {
+        &mut self.identifier
+    }
+}
+
+pub struct Service {
+    identifier: super::Identifier,
+
+    rpcs: Vec<Rpc>,
+}
+
+impl Service {
+    pub fn new() -> Self {
+        Self {
+            identifier: super::Identifier::new(),
+
+            rpcs: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn rpcs(&self) -> &[Rpc] {
+        self.rpcs.as_slice()
+    }
+
+    #[inline]
+    pub fn rpcs_mut(&mut self) -> &mut [Rpc] {
+        self.rpcs.as_mut_slice()
+    }
+
+    #[inline]
+    pub fn add_rpc(&mut self, rpc: Rpc) {
+        self.rpcs.push(rpc);
+    }
+}
+
+impl super::Identified for Service {
+    #[inline]
+    fn identifier(&self) -> &super::Identifier {
+        &self.identifier
+    }
+
+    #[inline]
+    fn identifier_mut(&mut self) -> &mut super::Identifier {
+        &mut self.identifier
+    }
+}
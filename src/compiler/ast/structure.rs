@@ -1,15 +1,15 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 pub struct Structure {
     identifier: super::Identifier,
 
     fields: Vec<super::Field>,
-    options: HashMap<String, Vec<super::Expression>>,
+    options: BTreeMap<String, Vec<super::Expression>>,
+    option_locations: BTreeMap<String, super::Location>,
+    reservations: Vec<super::Reservation>,
 
     // Options
     structure_options: StructureOptions,
-
-    min_size: usize,
 }
 
 impl Structure {
@@ -18,11 +18,11 @@ impl Structure {
             identifier: super::Identifier::new(),
 
             fields: Vec::new(),
-            options: HashMap::new(),
+            options: BTreeMap::new(),
+            option_locations: BTreeMap::new(),
+            reservations: Vec::new(),
 
             structure_options: StructureOptions::default(),
-
-            min_size: 0,
         }
     }
 
@@ -53,6 +53,11 @@ impl super::Fielded for Structure {
         self.fields.as_slice()
     }
 
+    #[inline]
+    fn fields_mut(&mut self) -> &mut [super::Field] {
+        self.fields.as_mut_slice()
+    }
+
     #[inline]
     fn add_field(&mut self, field: super::Field) {
         self.fields.push(field);
@@ -67,37 +72,52 @@ impl super::Fielded for Structure {
     fn field_mut(&mut self, id: usize) -> Option<&mut super::Field> {
         self.fields.get_mut(id)
     }
+}
 
-    /// Get minimal in memory size
+impl super::Reserved for Structure {
     #[inline]
-    fn min_size(&self) -> usize {
-        self.min_size
+    fn reservations(&self) -> &[super::Reservation] {
+        self.reservations.as_slice()
     }
 
-    /// Update minimal in memory size
     #[inline]
-    fn update_min_size(&mut self, size: usize) {
-        self.min_size += size;
+    fn add_reservation(&mut self, reservation: super::Reservation) {
+        self.reservations.push(reservation);
     }
 }
 
 impl super::Optioned for Structure {
-    fn options(&self) -> &HashMap<String, Vec<super::Expression>> {
+    fn options(&self) -> &BTreeMap<String, Vec<super::Expression>> {
         &self.options
     }
 
-    fn options_mut(&mut self) -> &mut HashMap<String, Vec<super::Expression>> {
+    fn options_mut(&mut self) -> &mut BTreeMap<String, Vec<super::Expression>> {
         &mut self.options
     }
 
-    fn add_option(&mut self, name: String, arguments: Vec<super::Expression>) -> bool {
-        self.options.insert(name, arguments).is_none()
+    fn add_option(
+        &mut self,
+        name: String,
+        arguments: Vec<super::Expression>,
+        location: super::Location,
+    ) -> bool {
+        if self.options.contains_key(&name) {
+            return false;
+        }
+
+        self.option_locations.insert(name.clone(), location);
+        self.options.insert(name, arguments);
+        true
     }
 
     fn option(&self, name: &str) -> Option<&Vec<super::Expression>> {
         self.options.get(name)
     }
 
+    fn option_location(&self, name: &str) -> Option<super::Location> {
+        self.option_locations.get(name).copied()
+    }
+
     fn option_mut(&mut self, name: &str) -> Option<&mut Vec<super::Expression>> {
         self.options.get_mut(name)
     }
@@ -107,4 +127,6 @@ impl super::Optioned for Structure {
 pub struct StructureOptions {
     pub message_buffer: (bool, bool),
     pub json: (bool, bool),
-}
\ No newline at end of file
+    pub deprecated: Option<String>,
+    pub validate: bool,
+}
@@ -1,15 +1,15 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 pub struct Variant {
     identifier: super::Identifier,
 
     fields: Vec<super::Field>,
-    options: HashMap<String, Vec<super::Expression>>,
+    options: BTreeMap<String, Vec<super::Expression>>,
+    option_locations: BTreeMap<String, super::Location>,
+    reservations: Vec<super::Reservation>,
 
     // Evaluated options
     variant_options: VariantOptions,
-
-    min_size: usize,
 }
 
 impl Variant {
@@ -18,10 +18,10 @@ impl Variant {
             identifier: super::Identifier::new(),
 
             fields: Vec::new(),
-            options: HashMap::new(),
+            options: BTreeMap::new(),
+            option_locations: BTreeMap::new(),
+            reservations: Vec::new(),
             variant_options: VariantOptions::default(),
-
-            min_size: 0,
         }
     }
 
@@ -52,6 +52,11 @@ impl super::Fielded for Variant {
         self.fields.as_slice()
     }
 
+    #[inline]
+    fn fields_mut(&mut self) -> &mut [super::Field] {
+        self.fields.as_mut_slice()
+    }
+
     #[inline]
     fn add_field(&mut self, field: super::Field) {
         self.fields.push(field);
@@ -66,37 +71,52 @@ impl super::Fielded for Variant {
     fn field_mut(&mut self, id: usize) -> Option<&mut super::Field> {
         self.fields.get_mut(id)
     }
+}
 
-    /// Get minimal in memory size
+impl super::Reserved for Variant {
     #[inline]
-    fn min_size(&self) -> usize {
-        self.min_size + /* Kind */ 2
+    fn reservations(&self) -> &[super::Reservation] {
+        self.reservations.as_slice()
     }
 
-    /// Update minimal in memory size
     #[inline]
-    fn update_min_size(&mut self, size: usize) {
-        self.min_size = std::cmp::max(self.min_size, size);
+    fn add_reservation(&mut self, reservation: super::Reservation) {
+        self.reservations.push(reservation);
     }
 }
 
 impl super::Optioned for Variant {
-    fn options(&self) -> &HashMap<String, Vec<super::Expression>> {
+    fn options(&self) -> &BTreeMap<String, Vec<super::Expression>> {
         &self.options
     }
 
-    fn options_mut(&mut self) -> &mut HashMap<String, Vec<super::Expression>> {
+    fn options_mut(&mut self) -> &mut BTreeMap<String, Vec<super::Expression>> {
         &mut self.options
     }
 
-    fn add_option(&mut self, name: String, arguments: Vec<super::Expression>) -> bool {
-        self.options.insert(name, arguments).is_none()
+    fn add_option(
+        &mut self,
+        name: String,
+        arguments: Vec<super::Expression>,
+        location: super::Location,
+    ) -> bool {
+        if self.options.contains_key(&name) {
+            return false;
+        }
+
+        self.option_locations.insert(name.clone(), location);
+        self.options.insert(name, arguments);
+        true
     }
 
     fn option(&self, name: &str) -> Option<&Vec<super::Expression>> {
         self.options.get(name)
     }
 
+    fn option_location(&self, name: &str) -> Option<super::Location> {
+        self.option_locations.get(name).copied()
+    }
+
     fn option_mut(&mut self, name: &str) -> Option<&mut Vec<super::Expression>> {
         self.options.get_mut(name)
     }
@@ -106,4 +126,6 @@ impl super::Optioned for Variant {
 pub struct VariantOptions {
     pub message_buffer: (bool, bool),
     pub json: (bool, bool),
-}
\ No newline at end of file
+    pub deprecated: Option<String>,
+    pub validate: bool,
+}
@@ -1,72 +1,364 @@
+use super::ast::{Field, Identifier, Location, Optioned, Reference, SizeType, Value};
+use super::lint::{self, Lint};
+use super::naming;
+use super::options::{self, Scope};
 use super::parser::lexer::Token;
+use super::parser::line_index::LineIndex;
 
-pub enum Report {
-    Error {
-        message: String,
-        position: Option<Position>,
-        selection: Option<Selection>,
-    },
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
 }
 
-impl Report {
-    pub fn with_source_code(&self, _source_code: &String) -> String {
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Report::Error {
-                message,
-                position,
-                selection: _,
-            } => {
-                let line1 = if let Some(position) = position {
-                    format!("error : {} (Ln {})", message, position.line)
-                } else {
-                    format!("error : {}", message)
-                };
-
-                // let relative_position: usize;
-                // let slice = match selection {
-                //     &Selection::Offset(offset) => {
-                //         relative_position = std::cmp::min(offset - 10, 10);
-                //         source_code
-                //             .slice(std::ops::Range {
-                //                 start: offset - relative_position,
-                //                 end: std::cmp::min(offset + 10, source_code.len() - 1),
-                //             })
-                //             .unwrap()
-                //     }
-                //     &Selection::Span(start_offset, end_offset) => {
-                //         relative_position = std::cmp::min(start_offset - 10, 10);
-                //         source_code
-                //             .slice(std::ops::Range {
-                //                 start: start_offset - relative_position,
-                //                 end: std::cmp::min(end_offset + 5, source_code.len() - 1),
-                //             })
-                //             .unwrap()
-                //     }
-                // };
-
-                // // Remove newline before selection
-                // {
-                //     if let Some(index) = slice.find('\n') {
-                //         if index < relative_position {
-                //             slice = slice.slice(std::ops::Range { start: index + 1 })
-                //         }
-                //     }
-                // }
-
-                // format!("{}\n{}\n{}", line1, line1, line1)
-                format!("{}", line1)
+            Severity::Error => f.write_str("error"),
+            Severity::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+pub struct Report {
+    pub severity: Severity,
+    /// Stable code that `pbc explain` describes
+    pub code: &'static str,
+    pub message: String,
+    pub position: Option<Position>,
+    pub selection: Option<Selection>,
+    /// Secondary locations like the first definition of a redefined name
+    pub labels: Vec<Label>,
+    /// Lint that decides the severity of the report
+    pub lint: Option<&'static Lint>,
+    /// Suggested replacements, rendered as help notes
+    pub fixes: Vec<Fix>,
+}
+
+pub struct Label {
+    pub message: String,
+    pub position: Option<Position>,
+    pub selection: Option<Selection>,
+}
+
+impl Label {
+    pub fn new(message: &str, location: Option<super::ast::Location>) -> Self {
+        Self {
+            message: message.to_string(),
+            position: location.map(Position::from),
+            selection: location.map(Selection::from),
+        }
+    }
+}
+
+/// Replacement of a part of the source code that resolves a report.
+///
+/// Fixes with a selection can be applied by tools, the others are only shown.
+pub struct Fix {
+    pub message: String,
+    pub replacement: String,
+    pub selection: Option<Selection>,
+}
+
+/// Source line with an underlined part
+struct Snippet<'a> {
+    line_number: usize,
+    column: usize,
+    line: &'a str,
+    prefix: &'a str,
+    selected: &'a str,
+    marker: char,
+    color: &'static str,
+    message: &'a str,
+}
+
+impl<'a> Snippet<'a> {
+    fn from(
+        source_code: &'a str,
+        line_index: &LineIndex,
+        selection: &Selection,
+        marker: char,
+        color: &'static str,
+        message: &'a str,
+    ) -> Self {
+        let (start, end) = selection.span();
+        let start = floor_char_boundary(source_code, start);
+        let end = floor_char_boundary(source_code, end);
+
+        // Line containing the start of the selection
+        let line_number = line_index.line(start);
+        let line_start = line_index.line_start(line_number);
+        let line_end = source_code[start..]
+            .find('\n')
+            .map_or(source_code.len(), |index| start + index);
+        let line = source_code[line_start..line_end].trim_end_matches('\r');
+
+        // Selections spanning several lines are underlined to the end of the first one
+        let prefix = &source_code[line_start..start];
+        let selected = &source_code[start..end.min(line_start + line.len()).max(start)];
+
+        Self {
+            line_number: line_number + 1,
+            column: prefix.chars().count() + 1,
+            line,
+            prefix,
+            selected,
+            marker,
+            color,
+            message,
+        }
+    }
+
+    fn underline(&self) -> String {
+        let underline = format!(
+            "{}{}",
+            " ".repeat(expand_tabs(self.prefix).chars().count()),
+            self.marker
+                .to_string()
+                .repeat(expand_tabs(self.selected).chars().count().max(1))
+        );
+
+        if self.message.is_empty() {
+            underline
+        } else {
+            format!("{underline} {}", self.message)
+        }
+    }
+}
+
+impl Report {
+    #[inline]
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// Suggests a similar name for a misspelled one, replacing it at the location if known.
+    pub fn with_similar_name(
+        mut self,
+        kind: &str,
+        similar: Option<&str>,
+        location: Option<Location>,
+    ) -> Self {
+        if let Some(similar) = similar {
+            self.fixes.push(Fix {
+                message: format!("{kind} with a similar name exists: `{similar}`"),
+                replacement: similar.to_string(),
+                selection: location.map(Selection::from),
+            });
+        }
+        self
+    }
+
+    /// Suggests 32-bit sizes when the limit of 16-bit sizes is exceeded.
+    fn with_wider_size_type(mut self, size_type: SizeType) -> Self {
+        if size_type == SizeType::UInt16 {
+            self.fixes.push(Fix {
+                message: "use 32-bit sizes: `opt message_buffer_size_bits = 32;`".to_string(),
+                replacement: "opt message_buffer_size_bits = 32;".to_string(),
+                selection: None,
+            });
+        }
+        self
+    }
+
+    /// Suggests the name converted to the naming convention it should use.
+    fn with_converted_name(mut self, identifier: &Identifier, case: naming::Case) -> Self {
+        let converted = case.convert(identifier.get());
+        if !converted.is_empty() && converted != identifier.get() {
+            self.fixes.push(Fix {
+                message: format!("convert to {case}: `{converted}`"),
+                replacement: converted,
+                selection: identifier.location().map(Selection::from),
+            });
+        }
+        self
+    }
+
+    /// Renders the report with snippets of the offending source lines and
+    /// underlines of the selections.
+    pub fn with_source_code(&self, file_name: &str, source_code: &str, colored: bool) -> String {
+        let style = Style { colored };
+
+        let mut output = format!(
+            "{}{}",
+            style.paint(
+                self.severity.color(),
+                &format!("{}[{}]", self.severity, self.code)
+            ),
+            style.paint(BOLD, &format!(": {}", self.message)),
+        );
+
+        let Some(selection) = &self.selection else {
+            // Without a selection there is nothing to underline
+            match &self.position {
+                Some(position) => {
+                    output += &format!(
+                        "\n{} {file_name}:{}:{}",
+                        style.paint(BLUE, " -->"),
+                        position.line + 1,
+                        position.column + 1
+                    )
+                }
+                None => output += &format!("\n{} {file_name}", style.paint(BLUE, " -->")),
+            }
+            for note in self.notes(true) {
+                output += &format!("\n{} {note}", style.paint(BLUE, "  ="));
+            }
+            return output;
+        };
+
+        let line_index = LineIndex::new(source_code);
+        let primary = Snippet::from(
+            source_code,
+            &line_index,
+            selection,
+            '^',
+            self.severity.color(),
+            "",
+        );
+
+        let mut snippets = self
+            .labels
+            .iter()
+            .filter_map(|label| {
+                label.selection.as_ref().map(|selection| {
+                    Snippet::from(
+                        source_code,
+                        &line_index,
+                        selection,
+                        '-',
+                        BLUE,
+                        &label.message,
+                    )
+                })
+            })
+            .collect::<Vec<Snippet>>();
+        let header = format!("{file_name}:{}:{}", primary.line_number, primary.column);
+        snippets.push(primary);
+        snippets.sort_by_key(|snippet| snippet.line_number);
+
+        let width = snippets
+            .iter()
+            .map(|snippet| snippet.line_number.to_string().len())
+            .max()
+            .unwrap_or(1);
+        let gutter = " ".repeat(width);
+
+        output += &format!("\n{gutter}{} {header}", style.paint(BLUE, "-->"));
+        output += &format!("\n{gutter} {}", style.paint(BLUE, "|"));
+
+        let mut previous_line_number = None;
+        for snippet in &snippets {
+            if previous_line_number != Some(snippet.line_number) {
+                output += &format!(
+                    "\n{} {}",
+                    style.paint(BLUE, &format!("{:>width$} |", snippet.line_number)),
+                    expand_tabs(snippet.line)
+                );
             }
+            output += &format!(
+                "\n{gutter} {} {}",
+                style.paint(BLUE, "|"),
+                style.paint(snippet.color, &snippet.underline())
+            );
+            previous_line_number = Some(snippet.line_number);
+        }
+
+        for note in self.notes(false) {
+            output += &format!("\n{gutter} {} {note}", style.paint(BLUE, "="));
+        }
+
+        output
+    }
+
+    /// Returns the labels that are rendered without a snippet, the help of the fixes
+    /// and the lint note.
+    fn notes(&self, include_selected: bool) -> Vec<String> {
+        let mut notes = self
+            .labels
+            .iter()
+            .filter(|label| include_selected || label.selection.is_none())
+            .map(|label| label.message.clone())
+            .collect::<Vec<String>>();
+
+        for fix in &self.fixes {
+            notes.push(format!("help: {}", fix.message));
+        }
+
+        if let Some(lint) = self.lint {
+            notes.push(format!(
+                "note: `{}` lint, allow it with `opt allow = \"{}\";`",
+                lint.name, lint.name
+            ));
         }
+
+        notes
     }
 }
 
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+const RESET: &str = "\x1b[0m";
+
+impl Severity {
+    fn color(&self) -> &'static str {
+        match self {
+            Severity::Error => RED,
+            Severity::Warning => YELLOW,
+        }
+    }
+}
+
+/// Wraps text in ANSI escape codes when colours are enabled.
+struct Style {
+    colored: bool,
+}
+
+impl Style {
+    fn paint(&self, code: &str, text: &str) -> String {
+        if self.colored {
+            format!("{code}{text}{RESET}")
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', "    ")
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Zero-based line and column in characters.
 pub struct Position {
     pub line: usize,
+    pub column: usize,
 }
 
 impl From<Token<'_>> for Position {
     fn from(token: Token) -> Self {
-        Position { line: token.line }
+        Position {
+            line: token.line,
+            column: token.column,
+        }
+    }
+}
+
+impl From<super::ast::Location> for Position {
+    fn from(location: super::ast::Location) -> Self {
+        Position {
+            line: location.line(),
+            column: location.column(),
+        }
     }
 }
 
@@ -75,12 +367,28 @@ pub enum Selection {
     Span(usize, usize),
 }
 
+impl Selection {
+    /// Returns the start and end byte offsets.
+    pub fn span(&self) -> (usize, usize) {
+        match *self {
+            Selection::Offset(offset) => (offset, offset),
+            Selection::Span(start, end) => (start, end.max(start)),
+        }
+    }
+}
+
 impl From<(usize, usize)> for Selection {
     fn from(span: (usize, usize)) -> Self {
         Selection::Span(span.0, span.1)
     }
 }
 
+impl From<super::ast::Location> for Selection {
+    fn from(location: super::ast::Location) -> Self {
+        location.span().into()
+    }
+}
+
 impl From<usize> for Selection {
     fn from(offset: usize) -> Self {
         Selection::Offset(offset)
@@ -88,234 +396,752 @@ impl From<usize> for Selection {
 }
 
 pub fn internal_error(message: &str) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0001",
         message: message.to_string(),
         position: None,
         selection: None,
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 // ---- General Errors ----
 
 pub fn err_unexp_token(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0002",
         message: format!("Unexpected token '{}'", token.kind),
         position: Some(token.into()),
         selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_invalid_character(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0063",
+        message: format!(
+            "Invalid character {:?}",
+            token.slice.chars().next().unwrap_or_default()
+        ),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_number_out_of_range(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0064",
+        message: format!("Number '{}' is out of range", token.slice),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_unterminated_literal(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0065",
+        message: "Unterminated literal, expected '\"'".to_string(),
+        position: Some(token.into()),
+        selection: Some((token.span.0, token.span.0 + 1).into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_exp_semicolon(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0003",
         message: "Expected semicolon ';'".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_miss_semicolon(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0004",
         message: "Missing semicolon ';'".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.0.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_exp_colon(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0005",
         message: "Expected colon ':'".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_miss_colon(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0006",
         message: "Missing colon ':'".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.0.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_exp_equal(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0007",
         message: "Expected equal '='".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_miss_equal(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0008",
         message: "Missing equal '='".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.0.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_exp_rparen(token: Token) -> Report {
-    Report::Error {
-        message: "Expected equal ')'".to_string(),
+    Report {
+        severity: Severity::Error,
+        code: "E0009",
+        message: "Expected ')'".to_string(),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_exp_lparen(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0010",
+        message: "Expected '('".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_exp_identifier(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0011",
         message: "Expected valid identifier".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.0.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_miss_identifier(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0012",
         message: "Missing valid identifier".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.0.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 // ---- Body Errors ----
 
 pub fn err_miss_body(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0013",
         message: "Missing struct body".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.0.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_exp_body_lcurly(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0014",
         message: "Expected '{'".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_exp_body_rcurly(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0015",
         message: "Expected '}'".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 // ---- Expression Errors ----
 
 pub fn err_exp_binary_operator(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0016",
         message: "Expected binary operator (+, -, *, /, %, etc...)".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 // ---- Field Errors ----
 
 pub fn err_exp_field_type(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0017",
         message: "Expected field type".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_miss_field_type(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0018",
         message: "Missing field type".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.0.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+// ---- Bounded Data Type Errors ----
+
+pub fn err_exp_rangle(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0019",
+        message: "Expected '>'".to_string(),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_exp_rbrack(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0020",
+        message: "Expected ']'".to_string(),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_exp_max(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0021",
+        message: "Expected 'max'".to_string(),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_exp_bound(token: Token, min: i64, max: i64) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0022",
+        message: format!("Expected bound between {min} and {max}"),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+// ---- Reserved Errors ----
+
+pub fn err_exp_reserved_entry(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0023",
+        message: "Expected reserved field number or name".to_string(),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_exp_reserved_range_end(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0024",
+        message: "Expected end of reserved range".to_string(),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+// ---- Service Errors ----
+
+pub fn err_exp_returns(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0025",
+        message: "Expected 'returns'".to_string(),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_exp_rpc_data_type(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0026",
+        message: "Expected rpc data type".to_string(),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+// ---- Extern Errors ----
+
+pub fn err_exp_type(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0027",
+        message: "Expected 'type'".to_string(),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_exp_extern_data_type(token: Token) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0028",
+        message: "Expected underlying data type".to_string(),
+        position: Some(token.into()),
+        selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 // ---- Alias Errors ----
 
 pub fn err_exp_alias_data_type(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0029",
         message: "Expected type type".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_miss_alias_data_type(token: Token) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0030",
         message: "Missing type type".to_string(),
         position: Some(token.into()),
         selection: Some(token.span.0.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 // ---- Semantic Analysis Errors ----
 
-pub fn err_invalid_data_type_identifer(identifier: &str) -> Report {
-    Report::Error {
-        message: format!(
-            "Data type identifier '{}' should use pascal case",
-            identifier
-        ),
-        position: None,
-        selection: None,
+pub fn err_invalid_data_type_identifer(
+    kind: naming::Kind,
+    identifier: &Identifier,
+    case: naming::Case,
+) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0031",
+        message: format!("{} identifier '{}' should use {}", kind, identifier, case),
+        position: identifier.location().map(Position::from),
+        selection: identifier.location().map(Selection::from),
+        labels: Vec::new(),
+        lint: Some(&lint::NAMING_CONVENTION),
+        fixes: Vec::new(),
     }
+    .with_converted_name(identifier, case)
 }
 
-pub fn err_undeclared_data_type(identifier: &str) -> Report {
-    Report::Error {
+pub fn err_undeclared_data_type(
+    identifier: &str,
+    location: Option<Location>,
+    similar: Option<&str>,
+) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0032",
         message: format!("Use of undeclared data type '{}'", identifier),
-        position: None,
-        selection: None,
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
+    .with_similar_name("a data type", similar, location)
 }
 
-pub fn err_redefined_data_type(identifier: &str) -> Report {
-    Report::Error {
+pub fn err_redefined_data_type(identifier: &Identifier, first: &Identifier) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0033",
         message: format!("Redefinition of data type '{}'", identifier),
-        position: None,
-        selection: None,
+        position: identifier.location().map(Position::from),
+        selection: identifier.location().map(Selection::from),
+        labels: vec![Label::new("first defined here", first.location())],
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
-pub fn err_redefined_field(identifier: &str) -> Report {
-    Report::Error {
+pub fn err_redefined_field(identifier: &Identifier, first: &Identifier) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0034",
         message: format!("Redefinition of field '{}'", identifier),
-        position: None,
-        selection: None,
+        position: identifier.location().map(Position::from),
+        selection: identifier.location().map(Selection::from),
+        labels: vec![Label::new("first defined here", first.location())],
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
-pub fn err_invalid_field_identifer(identifier: &str) -> Report {
-    Report::Error {
+pub fn err_invalid_reserved_range(reservation: &super::ast::Reservation) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0035",
+        message: "Reserved range must be ascending and start at 1 or above".to_string(),
+        position: Some(reservation.location().into()),
+        selection: Some(reservation.location().into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_reserved_field_number(
+    identifier: &str,
+    number: i64,
+    reservation: &super::ast::Reservation,
+) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0036",
         message: format!(
-            "Field identifier '{}' should use snake case and not start with an underscore",
-            identifier
+            "Field '{}' uses the reserved field number {}",
+            identifier, number
         ),
-        position: None,
-        selection: None,
+        position: Some(reservation.location().into()),
+        selection: Some(reservation.location().into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
-pub fn err_cyclical_dependency(identifiers: &[String]) -> Report {
-    Report::Error {
+pub fn err_reserved_field_name(identifier: &str, reservation: &super::ast::Reservation) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0037",
+        message: format!("Field '{}' uses a reserved field name", identifier),
+        position: Some(reservation.location().into()),
+        selection: Some(reservation.location().into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_invalid_field_identifer(identifier: &Identifier, case: naming::Case) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0038",
+        message: format!("Field identifier '{}' should use {}", identifier, case),
+        position: identifier.location().map(Position::from),
+        selection: identifier.location().map(Selection::from),
+        labels: Vec::new(),
+        lint: Some(&lint::NAMING_CONVENTION),
+        fixes: Vec::new(),
+    }
+    .with_converted_name(identifier, case)
+}
+
+pub fn err_invalid_constraint_data_type(constraint: &str, field: &Field) -> Report {
+    let location = field.option_location(constraint);
+    Report {
+        severity: Severity::Error,
+        code: "E0039",
         message: format!(
-            "Cyclical dependency between the types {}",
-            identifiers.join(", ")
+            "Constraint '{}' can not be applied to the data type of field '{}'",
+            constraint,
+            field.identifier()
         ),
-        position: None,
-        selection: None,
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: vec![Label::new("field declared here", field.location())],
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_invalid_constraint_arguments(constraint: &str, field: &Field) -> Report {
+    let location = field.option_location(constraint);
+    Report {
+        severity: Severity::Error,
+        code: "E0040",
+        message: format!(
+            "Invalid arguments for constraint '{}' of field '{}'",
+            constraint,
+            field.identifier()
+        ),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_invalid_constraint_range(field: &Field) -> Report {
+    let location = field.option_location("range");
+    Report {
+        severity: Severity::Error,
+        code: "E0041",
+        message: format!(
+            "Minimum of range constraint of field '{}' is greater than its maximum",
+            field.identifier()
+        ),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_invalid_constraint_pattern(field: &Field, error: &str) -> Report {
+    let location = field.option_location("pattern");
+    Report {
+        severity: Severity::Error,
+        code: "E0042",
+        message: format!(
+            "Invalid pattern constraint of field '{}': {}",
+            field.identifier(),
+            error
+        ),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_redefined_service(identifier: &Identifier, first: &Identifier) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0043",
+        message: format!("Redefinition of service '{}'", identifier),
+        position: identifier.location().map(Position::from),
+        selection: identifier.location().map(Selection::from),
+        labels: vec![Label::new("first defined here", first.location())],
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_redefined_rpc(identifier: &Identifier, first: &Identifier) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0044",
+        message: format!("Redefinition of rpc '{}'", identifier),
+        position: identifier.location().map(Position::from),
+        selection: identifier.location().map(Selection::from),
+        labels: vec![Label::new("first defined here", first.location())],
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_invalid_rpc_data_type(rpc: &str, reference: &Reference) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0045",
+        message: format!(
+            "Rpc '{}' requires '{}' to be a structure or variant with message buffer support",
+            rpc, reference
+        ),
+        position: reference.location().map(Position::from),
+        selection: reference.location().map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_invalid_extern_data_type(identifier: &str, reference: &Reference) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0046",
+        message: format!(
+            "Extern data type '{}' requires '{}' to be a built-in data type",
+            identifier, reference
+        ),
+        position: reference.location().map(Position::from),
+        selection: reference.location().map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_miss_extern_cpp(identifier: &Identifier) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0047",
+        message: format!(
+            "Extern data type '{}' is missing the 'cpp' type name option",
+            identifier
+        ),
+        position: identifier.location().map(Position::from),
+        selection: identifier.location().map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+/// Cyclical dependency along the path of data types, the path ends where it starts.
+pub fn err_cyclical_dependency(path: &[String], location: Option<Location>) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0048",
+        message: format!("Cyclical dependency between the types {}", path.join(" -> ")),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
 pub fn err_invalid_expression_operand(
     operator: super::ast::UnaryOperator,
     operands: &super::ast::Value,
+    location: Option<Location>,
 ) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0049",
         message: format!("Invalid {} operation for {}", operator, operands),
-        position: None,
-        selection: None,
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
     }
 }
 
@@ -323,13 +1149,385 @@ pub fn err_invalid_expression_operands(
     operator: super::ast::BinaryOperator,
     operand_1: &super::ast::Value,
     operand_2: &super::ast::Value,
+    location: Option<Location>,
 ) -> Report {
-    Report::Error {
+    Report {
+        severity: Severity::Error,
+        code: "E0050",
         message: format!(
             "Invalid {} operation for {} and {}",
             operator, operand_1, operand_2
         ),
-        position: None,
-        selection: None,
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_unknown_option(
+    name: &str,
+    scope: Scope,
+    location: Option<Location>,
+    similar: Option<&str>,
+) -> Report {
+    // The entry starts with the option name
+    let name_location = location.map(|location| {
+        let start = location.span().0;
+        Location::new(
+            location.line(),
+            location.column(),
+            (start, start + name.len()),
+        )
+    });
+
+    Report {
+        severity: Severity::Error,
+        code: "E0051",
+        message: format!("Unknown {} option '{}'", scope, name),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+    .with_similar_name("an option", similar, name_location)
+}
+
+pub fn err_misplaced_option(
+    name: &str,
+    scope: Scope,
+    scopes: &[Scope],
+    location: Option<Location>,
+) -> Report {
+    let mut applies_to = scopes
+        .iter()
+        .map(|scope| scope.plural())
+        .collect::<Vec<&str>>();
+    let last = applies_to.pop().unwrap_or_default();
+    let applies_to = match applies_to.is_empty() {
+        true => last.to_string(),
+        false => format!("{} and {}", applies_to.join(", "), last),
+    };
+
+    Report {
+        severity: Severity::Error,
+        code: "E0052",
+        message: format!("Option '{}' does not apply to {}", name, scope.plural()),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: vec![Label::new(
+            &format!("note: '{}' applies to {}", name, applies_to),
+            None,
+        )],
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_invalid_option_argument_count(
+    definition: &options::Definition,
+    count: usize,
+    location: Option<Location>,
+) -> Report {
+    let plural = |count: usize| if count == 1 { "argument" } else { "arguments" };
+    let expected = match (definition.min_arguments, definition.max_arguments) {
+        (min, Some(max)) if min == max => format!("{} {}", min, plural(min)),
+        (0, Some(max)) => format!("at most {} {}", max, plural(max)),
+        (min, Some(max)) => format!("{} to {} arguments", min, max),
+        (min, None) => format!("at least {} {}", min, plural(min)),
+    };
+
+    Report {
+        severity: Severity::Error,
+        code: "E0053",
+        message: format!(
+            "Option '{}' takes {}, found {}",
+            definition.name, expected, count
+        ),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_invalid_option_argument_type(
+    name: &str,
+    index: usize,
+    expected: &options::Type,
+    found: &super::ast::Value,
+    location: Option<Location>,
+) -> Report {
+    let found = match found {
+        super::ast::Value::Null => "nothing",
+        super::ast::Value::True | super::ast::Value::False => "boolean",
+        super::ast::Value::Integer(_) => "integer",
+        super::ast::Value::Real(_) => "real",
+        super::ast::Value::Literal(_) => "string",
+    };
+
+    Report {
+        severity: Severity::Error,
+        code: "E0054",
+        message: format!(
+            "Expected {} for argument {} of option '{}', found {}",
+            expected,
+            index + 1,
+            name,
+            found
+        ),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_redefined_option(name: &str, location: Location, first: Option<Location>) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0055",
+        message: format!("Redefinition of option '{}'", name),
+        position: Some(location.into()),
+        selection: Some(location.into()),
+        labels: vec![Label::new("first defined here", first)],
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_unknown_naming_convention(
+    name: &str,
+    location: Option<Location>,
+    similar: Option<&str>,
+) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0056",
+        message: format!("Unknown naming convention '{}'", name),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: vec![Label::new(
+            &format!(
+                "note: the naming conventions are {}",
+                naming::Case::ALL
+                    .iter()
+                    .map(|case| case.name())
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ),
+            None,
+        )],
+        lint: None,
+        fixes: Vec::new(),
+    }
+    // The option location spans the whole entry, so the fix is not applicable
+    .with_similar_name("a naming convention", similar, None)
+}
+
+pub fn err_invalid_message_buffer_size_bits(value: &Value, location: Option<Location>) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0057",
+        message: format!(
+            "Invalid message buffer size bits '{}', expected 16 or 32",
+            value
+        ),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+/// Variable length data of a message that may not fit in its size offset.
+pub fn err_message_buffer_size_overflow(
+    identifier: &Identifier,
+    max_size: usize,
+    size_type: SizeType,
+) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0058",
+        message: format!(
+            "Variable length data of '{}' may be up to {} bytes, more than the {} bytes of a {}-bit message buffer size",
+            identifier,
+            max_size,
+            size_type.max(),
+            size_type.bits()
+        ),
+        position: identifier.location().map(Position::from),
+        selection: identifier.location().map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+    .with_wider_size_type(size_type)
+}
+
+/// Bounded string or array of a field whose length may not fit in a length.
+pub fn err_message_buffer_length_overflow(
+    field: &Field,
+    data_type: &str,
+    max_len: usize,
+    size_type: SizeType,
+) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0059",
+        message: format!(
+            "Length of '{}' in field '{}' may be up to {}, more than a {}-bit message buffer length holds",
+            data_type,
+            field.identifier(),
+            max_len,
+            size_type.bits()
+        ),
+        position: field.reference().location().map(Position::from),
+        selection: field.reference().location().map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+    .with_wider_size_type(size_type)
+}
+
+pub fn err_division_by_zero(
+    operator: super::ast::BinaryOperator,
+    dividend: &super::ast::Value,
+    location: Location,
+) -> Report {
+    let message = match operator {
+        super::ast::BinaryOperator::Modulo => format!(
+            "Attempt to calculate the remainder of '{}' with a divisor of zero",
+            dividend
+        ),
+        _ => format!("Attempt to divide '{}' by zero", dividend),
+    };
+
+    Report {
+        severity: Severity::Error,
+        code: "E0060",
+        message,
+        position: Some(location.into()),
+        selection: Some(location.into()),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_integer_overflow(
+    expression: &str,
+    integer_type: &str,
+    location: Option<Location>,
+) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0061",
+        message: format!(
+            "Integer overflow, '{}' does not fit in '{}'",
+            expression, integer_type
+        ),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+pub fn err_non_finite_real(expression: &str, location: Option<Location>) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0062",
+        message: format!("'{}' is not a finite number", expression),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+/// Option arguments are folded at compile time, so they can not name variables or call
+/// functions.
+pub fn err_unevaluable_expression(expression: &str, location: Option<Location>) -> Report {
+    Report {
+        severity: Severity::Error,
+        code: "E0066",
+        message: format!("'{}' is not a constant expression", expression),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: None,
+        fixes: Vec::new(),
+    }
+}
+
+// ---- Semantic Analysis Warnings ----
+
+pub fn warn_deprecated_data_type(
+    data_type: &str,
+    field: &Field,
+    deprecated_data_type: &str,
+    message: &str,
+) -> Report {
+    Report {
+        severity: Severity::Warning,
+        code: "W0001",
+        message: if message.is_empty() {
+            format!(
+                "Field '{}' of '{}' uses deprecated data type '{}'",
+                field.identifier(),
+                data_type,
+                deprecated_data_type
+            )
+        } else {
+            format!(
+                "Field '{}' of '{}' uses deprecated data type '{}': {}",
+                field.identifier(),
+                data_type,
+                deprecated_data_type,
+                message
+            )
+        },
+        position: field.reference().location().map(Position::from),
+        selection: field.reference().location().map(Selection::from),
+        labels: Vec::new(),
+        lint: Some(&lint::DEPRECATED),
+        fixes: Vec::new(),
+    }
+}
+
+pub fn warn_empty_body(kind: &str, identifier: &Identifier) -> Report {
+    Report {
+        severity: Severity::Warning,
+        code: "W0002",
+        message: format!("{} '{}' has no fields", kind, identifier),
+        position: identifier.location().map(Position::from),
+        selection: identifier.location().map(Selection::from),
+        labels: Vec::new(),
+        lint: Some(&lint::EMPTY_BODY),
+        fixes: Vec::new(),
+    }
+}
+
+pub fn warn_unknown_lint(name: &str, location: Option<Location>, similar: Option<&str>) -> Report {
+    Report {
+        severity: Severity::Warning,
+        code: "W0003",
+        message: format!("Unknown lint '{}'", name),
+        position: location.map(Position::from),
+        selection: location.map(Selection::from),
+        labels: Vec::new(),
+        lint: Some(&lint::UNKNOWN_LINTS),
+        fixes: Vec::new(),
     }
+    // The option location spans all arguments, so the fix is not applicable
+    .with_similar_name("a lint", similar, None)
 }
@@ -0,0 +1,31 @@
+//! Long-form explanations of the diagnostic codes, embedded in the binary.
+//!
+//! Codes are never reused, explanations of codes the compiler no longer emits
+//! stay in the list with a note.
+
+macro_rules! error_codes {
+    ($($code:ident,)*) => {
+        pub const ERROR_CODES: &[(&str, &str)] = &[
+            $((stringify!($code), include_str!(concat!(stringify!($code), ".md"))),)*
+        ];
+    };
+}
+
+error_codes! {
+    E0001, E0002, E0003, E0004, E0005, E0006, E0007, E0008, E0009, E0010,
+    E0011, E0012, E0013, E0014, E0015, E0016, E0017, E0018, E0019, E0020,
+    E0021, E0022, E0023, E0024, E0025, E0026, E0027, E0028, E0029, E0030,
+    E0031, E0032, E0033, E0034, E0035, E0036, E0037, E0038, E0039, E0040,
+    E0041, E0042, E0043, E0044, E0045, E0046, E0047, E0048, E0049, E0050,
+    E0051, E0052, E0053, E0054, E0055, E0056, E0057, E0058, E0059, E0060,
+    E0061, E0062, E0063, E0064, E0065, E0066,
+    W0001, W0002, W0003,
+}
+
+/// Returns the explanation of a code like `E0012`, ignoring its case.
+pub fn explain(code: &str) -> Option<&'static str> {
+    ERROR_CODES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(code))
+        .map(|(_, explanation)| *explanation)
+}
@@ -0,0 +1,179 @@
+use std::fmt::{Formatter, Result};
+
+use crate::compiler::ast::*;
+
+pub fn uses_fixed_containers(file: &File) -> bool {
+    file.data_types()
+        .iter()
+        .any(|data_type| match data_type.kind() {
+            DataTypeKind::BoundedString(_) => true,
+            DataTypeKind::Array(array) => array.max_len().is_some(),
+            _ => false,
+        })
+}
+
+pub fn uses_array(file: &File) -> bool {
+    file.data_types()
+        .iter()
+        .any(|data_type| matches!(data_type.kind(), DataTypeKind::Array(_)))
+}
+
+pub fn uses_vector(file: &File) -> bool {
+    file.data_types()
+        .iter()
+        .any(|data_type| match data_type.kind() {
+            DataTypeKind::Array(array) => array.max_len().is_none(),
+            _ => false,
+        })
+}
+
+pub fn uses_box(file: &File) -> bool {
+    file.data_types()
+        .iter()
+        .any(|data_type| matches!(data_type.kind(), DataTypeKind::Box(_)))
+}
+
+/// Returns whether the data type holds a box, which can be moved but not copied.
+pub fn is_move_only(file: &File, data_type: &DataType) -> bool {
+    let mut visited = vec![false; file.data_types().len()];
+    let mut stack = vec![data_type];
+
+    while let Some(data_type) = stack.pop() {
+        let references = match data_type.kind() {
+            DataTypeKind::Box(_) => return true,
+            DataTypeKind::Array(array) => vec![array.reference()],
+            DataTypeKind::Structure(structure) => structure
+                .fields()
+                .iter()
+                .map(|field| field.reference())
+                .collect(),
+            DataTypeKind::Variant(variant) => variant
+                .fields()
+                .iter()
+                .map(|field| field.reference())
+                .collect(),
+            _ => continue,
+        };
+
+        for id in references.into_iter().filter_map(Reference::get_id) {
+            if !std::mem::replace(&mut visited[id], true) {
+                stack.extend(file.data_type(id));
+            }
+        }
+    }
+
+    false
+}
+
+/// Generates heap-free string and vector types with a fixed capacity.
+///
+/// The definitions are guarded so that several generated headers can be
+/// included in the same translation unit.
+pub fn generate_hpp_fixed_containers(f: &mut Formatter<'_>) -> Result {
+    write!(f, "#ifndef PBC_FIXED_CONTAINERS\n")?;
+    write!(f, "#define PBC_FIXED_CONTAINERS\n")?;
+    write!(f, "\n")?;
+
+    // Fixed string
+    write!(f, "template<size_t N>\n")?;
+    write!(f, "class FixedString\n")?;
+    write!(f, "{{\n")?;
+    write!(f, "private:\n")?;
+    write!(f, "\tstd::array<char, N> _data{{}};\n")?;
+    write!(f, "\tsize_t _size = 0;\n")?;
+    write!(f, "\n")?;
+    write!(f, "public:\n")?;
+    write!(f, "\tFixedString() = default;\n")?;
+    write!(
+        f,
+        "\tFixedString(std::string_view value) {{ this->assign(value); }}\n"
+    )?;
+    write!(f, "\n")?;
+    write!(f, "\t// Returns false if the value exceeds the capacity\n")?;
+    write!(f, "\tbool assign(std::string_view value)\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\tif (value.size() > N) return false;\n")?;
+    write!(
+        f,
+        "\t\tstd::memcpy(this->_data.data(), value.data(), value.size());\n"
+    )?;
+    write!(f, "\t\tthis->_size = value.size();\n")?;
+    write!(f, "\t\treturn true;\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "\n")?;
+    write!(
+        f,
+        "\tconst char* data() const {{ return this->_data.data(); }}\n"
+    )?;
+    write!(f, "\tsize_t size() const {{ return this->_size; }}\n")?;
+    write!(f, "\tbool empty() const {{ return this->_size == 0; }}\n")?;
+    write!(f, "\tstatic constexpr size_t capacity() {{ return N; }}\n")?;
+    write!(
+        f,
+        "\tconst char* begin() const {{ return this->_data.data(); }}\n"
+    )?;
+    write!(
+        f,
+        "\tconst char* end() const {{ return this->_data.data() + this->_size; }}\n"
+    )?;
+    write!(f, "\toperator std::string_view() const {{ return std::string_view(this->_data.data(), this->_size); }}\n")?;
+    write!(f, "}};\n")?;
+    write!(f, "\n")?;
+
+    // Fixed vector
+    write!(f, "template<typename T, size_t N>\n")?;
+    write!(f, "class FixedVector\n")?;
+    write!(f, "{{\n")?;
+    write!(f, "private:\n")?;
+    write!(f, "\tstd::array<T, N> _items{{}};\n")?;
+    write!(f, "\tsize_t _size = 0;\n")?;
+    write!(f, "\n")?;
+    write!(f, "public:\n")?;
+    write!(f, "\t// Returns false if the vector is full\n")?;
+    write!(f, "\tbool push_back(const T& item)\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\tif (this->_size == N) return false;\n")?;
+    write!(f, "\t\tthis->_items[this->_size++] = item;\n")?;
+    write!(f, "\t\treturn true;\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "\n")?;
+    write!(f, "\t// Returns false if the size exceeds the capacity\n")?;
+    write!(f, "\tbool resize(size_t size)\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\tif (size > N) return false;\n")?;
+    write!(f, "\t\tthis->_size = size;\n")?;
+    write!(f, "\t\treturn true;\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "\n")?;
+    write!(f, "\tvoid clear() {{ this->_size = 0; }}\n")?;
+    write!(f, "\tsize_t size() const {{ return this->_size; }}\n")?;
+    write!(f, "\tbool empty() const {{ return this->_size == 0; }}\n")?;
+    write!(f, "\tstatic constexpr size_t capacity() {{ return N; }}\n")?;
+    write!(
+        f,
+        "\tT& operator[](size_t index) {{ return this->_items[index]; }}\n"
+    )?;
+    write!(
+        f,
+        "\tconst T& operator[](size_t index) const {{ return this->_items[index]; }}\n"
+    )?;
+    write!(f, "\tT* begin() {{ return this->_items.data(); }}\n")?;
+    write!(
+        f,
+        "\tT* end() {{ return this->_items.data() + this->_size; }}\n"
+    )?;
+    write!(
+        f,
+        "\tconst T* begin() const {{ return this->_items.data(); }}\n"
+    )?;
+    write!(
+        f,
+        "\tconst T* end() const {{ return this->_items.data() + this->_size; }}\n"
+    )?;
+    write!(f, "}};\n")?;
+    write!(f, "\n")?;
+
+    write!(f, "#endif\n")?;
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+/// Returns the `[[deprecated]]` attribute prefix for a deprecation message.
+pub fn stringify_hpp_deprecated(message: Option<&String>) -> String {
+    match message {
+        Some(message) if message.is_empty() => "[[deprecated]] ".to_string(),
+        Some(message) => format!(
+            "[[deprecated(\"{}\")]] ",
+            super::literal::stringify_hpp_literal(message)
+        ),
+        None => String::new(),
+    }
+}
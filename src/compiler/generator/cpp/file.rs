@@ -7,6 +7,66 @@ pub fn generate_hpp_file(f: &mut Formatter<'_>, file: &File) -> Result {
     write!(f, "#include \"message_buffer.hpp\"\n")?;
     write!(f, "#include <rapidjson/document.h>\n")?;
     write!(f, "#include <array>\n")?;
+    if !file.services().is_empty() {
+        write!(f, "#include <functional>\n")?;
+    }
+    if super::validate::uses_pattern(file) {
+        write!(f, "#include <regex>\n")?;
+    }
+
+    // Headers of extern data types
+    {
+        let mut has_extern = false;
+        let mut includes = Vec::new();
+
+        for data_type in file.data_types() {
+            if let DataTypeKind::Extern(extern_data_type) = data_type.kind() {
+                has_extern = true;
+
+                if let Some(include) = &extern_data_type.extern_options().cpp_include {
+                    if !includes.contains(&include) {
+                        includes.push(include);
+                    }
+                }
+            }
+        }
+
+        if has_extern || super::container::uses_array(file) || super::container::uses_box(file) {
+            write!(f, "#include <memory>\n")?;
+        }
+        for include in includes {
+            // System headers are given with their angle brackets
+            if include.starts_with('<') {
+                write!(f, "#include {include}\n")?;
+            } else {
+                write!(f, "#include \"{include}\"\n")?;
+            }
+        }
+    }
+    if super::container::uses_array(file) {
+        write!(f, "#include <new>\n")?;
+    }
+    if super::container::uses_vector(file) {
+        write!(f, "#include <vector>\n")?;
+    }
+    let uses_fixed_containers = super::container::uses_fixed_containers(file);
+    let uses_primitives = super::primitive::uses_primitives(file);
+    if uses_fixed_containers || uses_primitives {
+        write!(f, "#include <cstdint>\n")?;
+        write!(f, "#include <cstring>\n")?;
+        if uses_primitives {
+            write!(f, "#include <string>\n")?;
+        }
+        write!(f, "#include <string_view>\n")?;
+    }
+    if uses_fixed_containers {
+        write!(f, "\n")?;
+        super::container::generate_hpp_fixed_containers(f)?;
+    }
+    if uses_primitives {
+        write!(f, "\n")?;
+        super::primitive::generate_hpp_primitives(f, file)?;
+    }
     write!(f, "\n")?;
 
     let mut indent = String::new();
@@ -14,13 +74,10 @@ pub fn generate_hpp_file(f: &mut Formatter<'_>, file: &File) -> Result {
     let mut namespaces = Vec::new();
 
     if let Some(opt) = file.option("cpp_namespace") {
-        match opt.as_slice() {
-            [Expression::Value(Value::Literal(value))] => {
-                for namespace in value.split("::") {
-                    namespaces.push(namespace);
-                }
+        if let [Expression::Value(Value::Literal(value))] = opt.as_slice() {
+            for namespace in value.split("::") {
+                namespaces.push(namespace);
             }
-            _ => {}
         };
     }
 
@@ -30,18 +87,7 @@ pub fn generate_hpp_file(f: &mut Formatter<'_>, file: &File) -> Result {
         indent.push('\t');
     }
 
-    let ordered_data_types = {
-        let mut data_types = file
-            .data_types()
-            .iter()
-            .map(|a| (a, a.max_rank()))
-            .collect::<Vec<(&DataType, usize)>>();
-
-        data_types.sort_by(|(_, a), (_, b)| b.cmp(a));
-        data_types
-    };
-
-    for (data_type, _) in &ordered_data_types {
+    for data_type in file.ordered_data_types() {
         match data_type.kind() {
             DataTypeKind::Structure(structure) => {
                 super::structure::generate_hpp_structure_declaration(f, &mut indent, structure)?;
@@ -53,9 +99,11 @@ pub fn generate_hpp_file(f: &mut Formatter<'_>, file: &File) -> Result {
         }
     }
 
+    super::recursive::generate_hpp_declarations(f, file, &indent)?;
+
     write!(f, "\n")?;
 
-    for (data_type, _) in &ordered_data_types {
+    for data_type in file.ordered_data_types() {
         match data_type.kind() {
             DataTypeKind::Structure(structure) => {
                 super::structure::generate_hpp_structure(f, file, &mut indent, data_type, structure)?;
@@ -68,6 +116,13 @@ pub fn generate_hpp_file(f: &mut Formatter<'_>, file: &File) -> Result {
         }
     }
 
+    super::recursive::generate_hpp_definitions(f, file, &mut indent)?;
+
+    for service in file.services() {
+        super::service::generate_hpp_service(f, file, &mut indent, service)?;
+        write!(f, "\n")?;
+    }
+
     // Leave namespaces
     for _ in &namespaces {
         indent.pop();
@@ -0,0 +1,22 @@
+use std::fmt::{Formatter, Result};
+
+use crate::compiler::ast::*;
+
+pub fn generate_hpp_schema_hash(
+    f: &mut Formatter<'_>,
+    indent: &str,
+    data_type: &DataType,
+) -> Result {
+    write!(
+        f,
+        "{indent}// Fingerprint of the wire shape, to compare with the one of a peer\n"
+    )?;
+    write!(
+        f,
+        "{indent}static constexpr uint64_t SchemaHash = 0x{:016x}ull;\n",
+        data_type.fingerprint()
+    )?;
+    write!(f, "\n")?;
+
+    Ok(())
+}
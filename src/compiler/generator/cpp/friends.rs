@@ -2,20 +2,26 @@ use std::fmt::{Formatter, Result};
 
 use crate::compiler::ast::*;
 
-pub fn generate_hpp_friends(f: &mut Formatter<'_>, file: &File, indent: &mut String, rank: usize) -> Result {
-    for data_type in file.data_types() {
-        if data_type.max_rank() < rank {
-            match data_type.kind() {
-                DataTypeKind::Structure(structure) => {
-                    write!(f, "{indent}friend class {};\n", structure.identifier())?;
-                }
-                DataTypeKind::Variant(variant) => {
-                    write!(f, "{indent}friend class {};\n", variant.identifier())?;
-                }
-                _ => {}
+pub fn generate_hpp_friends(f: &mut Formatter<'_>, file: &File, indent: &mut String, data_type: &DataType) -> Result {
+    // Data types declared later may contain this one and read its fields
+    let later_data_types = file
+        .ordered_data_types()
+        .skip_while(|other| !std::ptr::eq(*other, data_type))
+        .skip(1);
+
+    for data_type in later_data_types {
+        match data_type.kind() {
+            DataTypeKind::Structure(structure) => {
+                write!(f, "{indent}friend class {};\n", structure.identifier())?;
+            }
+            DataTypeKind::Variant(variant) => {
+                write!(f, "{indent}friend class {};\n", variant.identifier())?;
             }
+            _ => {}
         }
     }
 
+    super::recursive::generate_hpp_friends(f, file, indent)?;
+
     Ok(())
 }
@@ -23,6 +23,12 @@ pub fn generate_hpp_structure_reader(
         reader::generate_hpp_structure_reader(f, file, 1, indent, "(*this)", structure)?;
         write!(f, "\n")?;
 
+        // Validate
+        if structure.structure_options().validate {
+            write!(f, "{indent}if (this->Validate() != nullptr) return false;\n")?;
+            write!(f, "\n")?;
+        }
+
         write!(f, "{indent}return true;\n")?;
 
         indent.pop();
@@ -77,6 +83,12 @@ pub fn generate_hpp_variant_reader(
         reader::generate_hpp_variant_reader(f, file, 1, indent, "(*this)", variant)?;
         write!(f, "\n")?;
 
+        // Validate
+        if variant.variant_options().validate {
+            write!(f, "{indent}if (this->Validate() != nullptr) return false;\n")?;
+            write!(f, "\n")?;
+        }
+
         write!(f, "{indent}return true;\n")?;
 
         indent.pop();
@@ -111,3 +123,72 @@ pub fn generate_hpp_variant_writer(
 
     Ok(())
 }
+
+/// Signatures of the functions converting a recursive data type
+pub fn stringify_hpp_signatures(name: &str) -> [String; 2] {
+    [
+        format!("bool JsonRead(const rapidjson::Value& _temp_0, {name}& _value)"),
+        format!("void JsonWrite(rapidjson::Value& _temp_0, rapidjson::Document::AllocatorType& _allocator, const {name}& _value)"),
+    ]
+}
+
+pub fn generate_hpp_functions(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    name: &str,
+    data_type: &DataType,
+) -> Result {
+    let [reader, writer] = stringify_hpp_signatures(name);
+
+    // Reader
+    {
+        write!(f, "{indent}inline {reader}\n")?;
+        write!(f, "{indent}{{\n")?;
+
+        indent.push('\t');
+
+        write!(f, "{indent}if (!_temp_0.IsObject()) return false;\n")?;
+        match data_type.kind() {
+            DataTypeKind::Structure(structure) => {
+                reader::generate_hpp_structure_reader(f, file, 1, indent, "_value", structure)?;
+            }
+            DataTypeKind::Variant(variant) => {
+                reader::generate_hpp_variant_reader(f, file, 1, indent, "_value", variant)?;
+            }
+            _ => {}
+        }
+        write!(f, "{indent}return true;\n")?;
+
+        indent.pop();
+
+        write!(f, "{indent}}}\n")?;
+        write!(f, "\n")?;
+    }
+
+    // Writer
+    {
+        write!(f, "{indent}inline {writer}\n")?;
+        write!(f, "{indent}{{\n")?;
+
+        indent.push('\t');
+
+        write!(f, "{indent}_temp_0.SetObject();\n")?;
+        match data_type.kind() {
+            DataTypeKind::Structure(structure) => {
+                writer::generate_hpp_structure_writer(f, file, 1, indent, "_value", structure)?;
+            }
+            DataTypeKind::Variant(variant) => {
+                writer::generate_hpp_variant_writer(f, file, 1, indent, "_value", variant)?;
+            }
+            _ => {}
+        }
+
+        indent.pop();
+
+        write!(f, "{indent}}}\n")?;
+        write!(f, "\n")?;
+    }
+
+    Ok(())
+}
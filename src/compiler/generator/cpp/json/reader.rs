@@ -20,170 +20,41 @@ fn generate_hpp_field_reader(
             parent_depth = (depth - 1),
             field = field.identifier(),
         )?;
-        write!(
-            f,
-            "{indent}if (_temp_it_{depth} == _temp_{parent_depth}.MemberEnd()) return false;\n",
-            parent_depth = (depth - 1),
-        )?;
+
+        // Deprecated fields are accepted but optional
+        let optional = field.field_options().deprecated.is_some();
+        if optional {
+            write!(
+                f,
+                "{indent}if (_temp_it_{depth} != _temp_{parent_depth}.MemberEnd())\n",
+                parent_depth = (depth - 1),
+            )?;
+            write!(f, "{indent}{{\n")?;
+
+            indent.push('\t');
+        } else {
+            write!(
+                f,
+                "{indent}if (_temp_it_{depth} == _temp_{parent_depth}.MemberEnd()) return false;\n",
+                parent_depth = (depth - 1),
+            )?;
+        }
 
         if let Some(id) = field.reference().get_id() {
             if let Some(data_type) = file.data_type(id) {
-                match data_type.kind() {
-                    DataTypeKind::Boolean => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsBool()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = _temp_it_{depth}->value.GetBool();\n"
-                        )?;
-                    }
-                    DataTypeKind::Int8 => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsInt()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = (int8_t)_temp_it_{depth}->value.GetInt();\n"
-                        )?;
-                    }
-                    DataTypeKind::Int16 => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsInt()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = (int16_t)_temp_it_{depth}->value.GetInt();\n"
-                        )?;
-                    }
-                    DataTypeKind::Int32 => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsInt()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = _temp_it_{depth}->value.GetInt();\n"
-                        )?;
-                    }
-                    DataTypeKind::Int64 => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsInt64()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = _temp_it_{depth}->value.GetInt64();\n"
-                        )?;
-                    }
-                    DataTypeKind::UInt8 => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsUint()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = (uint8_t)_temp_it_{depth}->value.GetUint();\n"
-                        )?;
-                    }
-                    DataTypeKind::UInt16 => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsUint()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = (uint16_t)_temp_it_{depth}->value.GetUint();\n"
-                        )?;
-                    }
-                    DataTypeKind::UInt32 => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsUint()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = _temp_it_{depth}->value.GetUint();\n"
-                        )?;
-                    }
-                    DataTypeKind::UInt64 => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsUint64()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = _temp_it_{depth}->value.GetUint64();\n"
-                        )?;
-                    }
-                    DataTypeKind::Single => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsFloat()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = _temp_it_{depth}->value.GetFloat();\n"
-                        )?;
-                    }
-                    DataTypeKind::Double => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsDouble()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = _temp_it_{depth}->value.GetDouble();\n"
-                        )?;
-                    }
-                    DataTypeKind::String => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsString()) return false;\n"
-                        )?;
-                        write!(
-                            f,
-                            "{indent}{reference} = std::string_view(_temp_it_{depth}->value.GetString(), _temp_it_{depth}->value.GetStringLength());\n"
-                        )?;
-                    }
-                    DataTypeKind::Structure(structure) => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsObject()) return false;\n"
-                        )?;
-                        write!(f, "{indent}const rapidjson::Value& _temp_{depth} = _temp_it_{depth}->value;\n")?;
-                        generate_hpp_structure_reader(
-                            f,
-                            file,
-                            depth + 1,
-                            indent,
-                            reference,
-                            structure,
-                        )?;
-                    }
-                    DataTypeKind::Variant(variant) => {
-                        write!(
-                            f,
-                            "{indent}if (!_temp_it_{depth}->value.IsObject()) return false;\n"
-                        )?;
-                        write!(f, "{indent}const rapidjson::Value& _temp_{depth} = _temp_it_{depth}->value;\n")?;
-                        generate_hpp_variant_reader(
-                            f,
-                            file,
-                            depth + 1,
-                            indent,
-                            reference,
-                            variant,
-                        )?;
-                    }
-                    DataTypeKind::Alias(_) => {}
-                }
+                let value = format!("_temp_it_{depth}->value");
+                generate_hpp_data_type_reader(
+                    f, file, depth, indent, reference, &value, data_type,
+                )?;
             }
         }
 
+        if optional {
+            indent.pop();
+
+            write!(f, "{indent}}}\n")?;
+        }
+
         indent.pop();
     }
 
@@ -192,6 +63,268 @@ fn generate_hpp_field_reader(
     Ok(())
 }
 
+fn generate_hpp_data_type_reader(
+    f: &mut Formatter<'_>,
+    file: &File,
+    depth: usize,
+    indent: &mut String,
+    reference: &str,
+    value: &str,
+    data_type: &DataType,
+) -> Result {
+    match data_type.kind() {
+        DataTypeKind::Boolean => {
+            write!(f, "{indent}if (!{value}.IsBool()) return false;\n")?;
+            write!(f, "{indent}{reference} = {value}.GetBool();\n")?;
+        }
+        DataTypeKind::Int8 => {
+            write!(f, "{indent}if (!{value}.IsInt()) return false;\n")?;
+            write!(f, "{indent}{reference} = (int8_t){value}.GetInt();\n")?;
+        }
+        DataTypeKind::Int16 => {
+            write!(f, "{indent}if (!{value}.IsInt()) return false;\n")?;
+            write!(f, "{indent}{reference} = (int16_t){value}.GetInt();\n")?;
+        }
+        DataTypeKind::Int32 => {
+            write!(f, "{indent}if (!{value}.IsInt()) return false;\n")?;
+            write!(f, "{indent}{reference} = {value}.GetInt();\n")?;
+        }
+        DataTypeKind::Int64 => {
+            write!(f, "{indent}if (!{value}.IsInt64()) return false;\n")?;
+            write!(f, "{indent}{reference} = {value}.GetInt64();\n")?;
+        }
+        DataTypeKind::UInt8 => {
+            write!(f, "{indent}if (!{value}.IsUint()) return false;\n")?;
+            write!(f, "{indent}{reference} = (uint8_t){value}.GetUint();\n")?;
+        }
+        DataTypeKind::UInt16 => {
+            write!(f, "{indent}if (!{value}.IsUint()) return false;\n")?;
+            write!(f, "{indent}{reference} = (uint16_t){value}.GetUint();\n")?;
+        }
+        DataTypeKind::UInt32 => {
+            write!(f, "{indent}if (!{value}.IsUint()) return false;\n")?;
+            write!(f, "{indent}{reference} = {value}.GetUint();\n")?;
+        }
+        DataTypeKind::UInt64 => {
+            write!(f, "{indent}if (!{value}.IsUint64()) return false;\n")?;
+            write!(f, "{indent}{reference} = {value}.GetUint64();\n")?;
+        }
+        DataTypeKind::Int128 => {
+            write!(f, "{indent}if (!{value}.IsString()) return false;\n")?;
+            write!(
+                f,
+                "{indent}if (!ParseInt128(std::string_view({value}.GetString(), {value}.GetStringLength()), {reference})) return false;\n"
+            )?;
+        }
+        DataTypeKind::UInt128 => {
+            write!(f, "{indent}if (!{value}.IsString()) return false;\n")?;
+            write!(
+                f,
+                "{indent}if (!ParseUInt128(std::string_view({value}.GetString(), {value}.GetStringLength()), {reference})) return false;\n"
+            )?;
+        }
+        DataTypeKind::Half => {
+            write!(f, "{indent}if (!{value}.IsFloat()) return false;\n")?;
+            write!(f, "{indent}{reference} = Float16({value}.GetFloat());\n")?;
+        }
+        DataTypeKind::Single => {
+            write!(f, "{indent}if (!{value}.IsFloat()) return false;\n")?;
+            write!(f, "{indent}{reference} = {value}.GetFloat();\n")?;
+        }
+        DataTypeKind::Double => {
+            write!(f, "{indent}if (!{value}.IsDouble()) return false;\n")?;
+            write!(f, "{indent}{reference} = {value}.GetDouble();\n")?;
+        }
+        DataTypeKind::Decimal(scale) => {
+            write!(f, "{indent}if (!{value}.IsString()) return false;\n")?;
+            write!(
+                f,
+                "{indent}if (!Decimal<{scale}>::Parse(std::string_view({value}.GetString(), {value}.GetStringLength()), {reference})) return false;\n"
+            )?;
+        }
+        DataTypeKind::Char => {
+            write!(f, "{indent}if (!{value}.IsString()) return false;\n")?;
+            write!(
+                f,
+                "{indent}if (!DecodeUtf8Char(std::string_view({value}.GetString(), {value}.GetStringLength()), {reference})) return false;\n"
+            )?;
+        }
+        DataTypeKind::String => {
+            write!(f, "{indent}if (!{value}.IsString()) return false;\n")?;
+            write!(
+                            f,
+                            "{indent}{reference} = std::string_view({value}.GetString(), {value}.GetStringLength());\n"
+                        )?;
+        }
+        DataTypeKind::BoundedString(_) => {
+            write!(f, "{indent}if (!{value}.IsString()) return false;\n")?;
+            write!(
+                f,
+                "{indent}if (!{reference}.assign(std::string_view({value}.GetString(), {value}.GetStringLength()))) return false;\n"
+            )?;
+        }
+        DataTypeKind::Array(array) => {
+            generate_hpp_array_reader(f, file, depth, indent, reference, value, array)?;
+        }
+        DataTypeKind::Box(boxed) => {
+            generate_hpp_box_reader(f, file, depth, indent, reference, value, boxed)?;
+        }
+        DataTypeKind::Structure(_) | DataTypeKind::Variant(_) if data_type.is_recursive() => {
+            write!(f, "{indent}if (!JsonRead({value}, {reference})) return false;\n")?;
+        }
+        DataTypeKind::Structure(structure) => {
+            write!(f, "{indent}if (!{value}.IsObject()) return false;\n")?;
+            write!(
+                f,
+                "{indent}const rapidjson::Value& _temp_{depth} = {value};\n"
+            )?;
+            generate_hpp_structure_reader(f, file, depth + 1, indent, reference, structure)?;
+        }
+        DataTypeKind::Variant(variant) => {
+            write!(f, "{indent}if (!{value}.IsObject()) return false;\n")?;
+            write!(
+                f,
+                "{indent}const rapidjson::Value& _temp_{depth} = {value};\n"
+            )?;
+            generate_hpp_variant_reader(f, file, depth + 1, indent, reference, variant)?;
+        }
+        DataTypeKind::Extern(extern_data_type) => {
+            generate_hpp_extern_reader(f, file, depth, indent, reference, value, extern_data_type)?;
+        }
+        DataTypeKind::Alias(_) => {}
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_array_reader(
+    f: &mut Formatter<'_>,
+    file: &File,
+    depth: usize,
+    indent: &mut String,
+    reference: &str,
+    value: &str,
+    array: &Array,
+) -> Result {
+    if let Some(id) = array.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            write!(f, "{indent}if (!{value}.IsArray()) return false;\n")?;
+            if let Some(max_len) = array.max_len() {
+                write!(f, "{indent}if ({value}.Size() > {max_len}) return false;\n")?;
+            }
+            write!(f, "{indent}{reference}.resize({value}.Size());\n")?;
+
+            write!(
+                f,
+                "{indent}for (rapidjson::SizeType _i_{depth} = 0; _i_{depth} < {value}.Size(); ++_i_{depth})\n"
+            )?;
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                let reference = format!("{reference}[_i_{depth}]");
+                let value = format!("{value}[_i_{depth}]");
+                generate_hpp_data_type_reader(
+                    f,
+                    file,
+                    depth + 1,
+                    indent,
+                    &reference,
+                    &value,
+                    data_type,
+                )?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_box_reader(
+    f: &mut Formatter<'_>,
+    file: &File,
+    depth: usize,
+    indent: &mut String,
+    reference: &str,
+    value: &str,
+    boxed: &Boxed,
+) -> Result {
+    if let Some(id) = boxed.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            // Empty boxes are null
+            write!(f, "{indent}if ({value}.IsNull())\n")?;
+            write!(f, "{indent}\t{reference}.reset();\n")?;
+            write!(f, "{indent}else\n")?;
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(
+                    f,
+                    "{indent}{reference} = std::make_unique<{}>();\n",
+                    super::super::reference::stringify_hpp_data_type(file, data_type)
+                )?;
+
+                let reference = format!("(*{reference})");
+                generate_hpp_data_type_reader(f, file, depth, indent, &reference, value, data_type)?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_extern_reader(
+    f: &mut Formatter<'_>,
+    file: &File,
+    depth: usize,
+    indent: &mut String,
+    reference: &str,
+    value: &str,
+    extern_data_type: &Extern,
+) -> Result {
+    if let Some(id) = extern_data_type.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(
+                    f,
+                    "{indent}{} _wire;\n",
+                    super::super::reference::stringify_hpp_reference(
+                        file,
+                        extern_data_type.reference()
+                    )
+                )?;
+                generate_hpp_data_type_reader(f, file, depth, indent, "_wire", value, data_type)?;
+                write!(
+                    f,
+                    "{indent}{}(_wire, {reference});\n",
+                    extern_data_type.extern_options().cpp_from_wire
+                )?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
+        }
+    }
+
+    Ok(())
+}
+
 pub fn generate_hpp_structure_reader(
     f: &mut Formatter<'_>,
     file: &File,
@@ -263,21 +396,19 @@ pub fn generate_hpp_variant_reader(
             if let Some(id) = field.reference().get_id() {
                 if let Some(data_type) = file.data_type(id) {
                     match data_type.kind() {
-                        DataTypeKind::String => {
-                            write!(f, "{indent}{reference} = std::string_view();\n")?;
-                        }
-                        DataTypeKind::Structure(structure) => {
-                            write!(
-                                f,
-                                "{indent}{reference} = {}();\n",
-                                structure.identifier()
-                            )?;
-                        }
-                        DataTypeKind::Variant(variant) => {
+                        // The storage of the previous case was destroyed, so the new case
+                        // is constructed in place rather than assigned
+                        DataTypeKind::String
+                        | DataTypeKind::BoundedString(_)
+                        | DataTypeKind::Array(_)
+                        | DataTypeKind::Box(_)
+                        | DataTypeKind::Extern(_)
+                        | DataTypeKind::Structure(_)
+                        | DataTypeKind::Variant(_) => {
                             write!(
                                 f,
-                                "{indent}{reference} = {}();\n",
-                                variant.identifier()
+                                "{indent}new (&{reference}) {}();\n",
+                                super::super::reference::stringify_hpp_data_type(file, data_type)
                             )?;
                         }
                         _ => {}
@@ -2,6 +2,36 @@ use std::fmt::{Formatter, Result};
 
 use crate::compiler::ast::*;
 
+/// Parent JSON value a written value is added to
+#[derive(Clone, Copy)]
+enum Target<'a> {
+    /// Named member of the parent object
+    Member(&'a str),
+    /// Element of the parent array
+    Element,
+}
+
+fn generate_hpp_add_value(
+    f: &mut Formatter<'_>,
+    depth: usize,
+    indent: &str,
+    target: Target,
+    value: &str,
+) -> Result {
+    match target {
+        Target::Member(name) => write!(
+            f,
+            "{indent}_temp_{parent_depth}.AddMember(\"{name}\", {value}, _allocator);\n",
+            parent_depth = depth - 1,
+        ),
+        Target::Element => write!(
+            f,
+            "{indent}_temp_{parent_depth}.PushBack({value}, _allocator);\n",
+            parent_depth = depth - 1,
+        ),
+    }
+}
+
 fn generate_hpp_field_writer(
     f: &mut Formatter<'_>,
     file: &File,
@@ -12,86 +42,294 @@ fn generate_hpp_field_writer(
 ) -> Result {
     if let Some(id) = field.reference().get_id() {
         if let Some(data_type) = file.data_type(id) {
-            match data_type.kind() {
-                DataTypeKind::Boolean |
-                DataTypeKind::Int8 |
-                DataTypeKind::Int16 |
-                DataTypeKind::Int32 |
-                DataTypeKind::Int64 |
-                DataTypeKind::UInt8 |
-                DataTypeKind::UInt16 |
-                DataTypeKind::UInt32 |
-                DataTypeKind::UInt64 |
-                DataTypeKind::Single |
-                DataTypeKind::Double => {
-                    write!(
-                        f, 
-                        "{indent}_temp_{parent_depth}.AddMember(\"{field}\", rapidjson::Value({reference}), _allocator);\n", 
-                        parent_depth = depth - 1, 
-                        field = field.identifier()
-                    )?;
-                }
-                DataTypeKind::String => {
-                    write!(
-                        f, 
-                        "{indent}_temp_{parent_depth}.AddMember(\"{field}\", rapidjson::Value({reference}.data(), {reference}.size(), _allocator), _allocator);\n", 
-                        parent_depth = depth - 1, 
-                        field = field.identifier(),
+            generate_hpp_data_type_writer(
+                f,
+                file,
+                depth,
+                indent,
+                reference,
+                Target::Member(field.identifier().get()),
+                data_type,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_data_type_writer(
+    f: &mut Formatter<'_>,
+    file: &File,
+    depth: usize,
+    indent: &mut String,
+    reference: &str,
+    target: Target,
+    data_type: &DataType,
+) -> Result {
+    match data_type.kind() {
+        DataTypeKind::Boolean |
+        DataTypeKind::Int8 |
+        DataTypeKind::Int16 |
+        DataTypeKind::Int32 |
+        DataTypeKind::Int64 |
+        DataTypeKind::UInt8 |
+        DataTypeKind::UInt16 |
+        DataTypeKind::UInt32 |
+        DataTypeKind::UInt64 |
+        DataTypeKind::Single |
+        DataTypeKind::Double => {
+            let value = format!("rapidjson::Value({reference})");
+            generate_hpp_add_value(f, depth, indent, target, &value)?;
+        }
+        DataTypeKind::Half => {
+            let value = format!("rapidjson::Value((float){reference})");
+            generate_hpp_add_value(f, depth, indent, target, &value)?;
+        }
+        DataTypeKind::Int128 |
+        DataTypeKind::UInt128 |
+        DataTypeKind::Decimal(_) |
+        DataTypeKind::Char => {
+            // Written as strings since JSON numbers can't hold them exactly
+            let text = match data_type.kind() {
+                DataTypeKind::Int128 => format!("Int128ToString({reference})"),
+                DataTypeKind::UInt128 => format!("UInt128ToString({reference})"),
+                DataTypeKind::Char => format!("EncodeUtf8({reference})"),
+                _ => format!("{reference}.ToString()"),
+            };
+
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(f, "{indent}std::string _text_{depth} = {text};\n")?;
+
+                let value = format!("rapidjson::Value(_text_{depth}.data(), _text_{depth}.size(), _allocator)");
+                generate_hpp_add_value(f, depth, indent, target, &value)?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
+        }
+        DataTypeKind::String | DataTypeKind::BoundedString(_) => {
+            let value =
+                format!("rapidjson::Value({reference}.data(), {reference}.size(), _allocator)");
+            generate_hpp_add_value(f, depth, indent, target, &value)?;
+        }
+        DataTypeKind::Array(array) => {
+            generate_hpp_array_writer(f, file, depth, indent, reference, target, array)?;
+        }
+        DataTypeKind::Box(boxed) => {
+            generate_hpp_box_writer(f, file, depth, indent, reference, target, boxed)?;
+        }
+        DataTypeKind::Structure(_) | DataTypeKind::Variant(_) if data_type.is_recursive() => {
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(f, "{indent}rapidjson::Value _temp_{depth};\n")?;
+                write!(f, "{indent}JsonWrite(_temp_{depth}, _allocator, {reference});\n")?;
+
+                generate_hpp_add_value(f, depth, indent, target, &format!("_temp_{depth}"))?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
+        }
+        DataTypeKind::Structure(structure) => {
+            write!(f, "{indent}{{\n")?;
+        
+            {
+                indent.push('\t');
+            
+                write!(f, "{indent}rapidjson::Value _temp_{depth} = rapidjson::Value(rapidjson::kObjectType);\n")?;
+
+                generate_hpp_structure_writer(
+                    f,
+                    file,
+                    depth + 1,
+                    indent,
+                    reference,
+                    structure,
+                )?;
+
+                generate_hpp_add_value(f, depth, indent, target, &format!("_temp_{depth}"))?;
+
+                indent.pop();
+            }
+            
+            write!(f, "{indent}}}\n")?;
+        }
+        DataTypeKind::Variant(variant) => {
+            write!(f, "{indent}{{\n")?;
+        
+            {
+                indent.push('\t');
+            
+                write!(f, "{indent}rapidjson::Value _temp_{depth} = rapidjson::Value(rapidjson::kObjectType);\n")?;
+
+                generate_hpp_variant_writer(f, file, depth + 1, indent, reference, variant)?;
+
+                generate_hpp_add_value(f, depth, indent, target, &format!("_temp_{depth}"))?;
+
+                indent.pop();
+            }
+            
+            write!(f, "{indent}}}\n")?;
+        }
+        DataTypeKind::Extern(extern_data_type) => {
+            generate_hpp_extern_writer(
+                f,
+                file,
+                depth,
+                indent,
+                reference,
+                target,
+                extern_data_type,
+            )?;
+        }
+        DataTypeKind::Alias(_) => {}
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_array_writer(
+    f: &mut Formatter<'_>,
+    file: &File,
+    depth: usize,
+    indent: &mut String,
+    reference: &str,
+    target: Target,
+    array: &Array,
+) -> Result {
+    if let Some(id) = array.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(f, "{indent}rapidjson::Value _temp_{depth} = rapidjson::Value(rapidjson::kArrayType);\n")?;
+
+                write!(f, "{indent}for (const auto& _item_{depth} : {reference})\n")?;
+                write!(f, "{indent}{{\n")?;
+
+                {
+                    indent.push('\t');
+
+                    let reference = format!("_item_{depth}");
+                    generate_hpp_data_type_writer(
+                        f,
+                        file,
+                        depth + 1,
+                        indent,
+                        &reference,
+                        Target::Element,
+                        data_type,
                     )?;
+
+                    indent.pop();
                 }
-                DataTypeKind::Structure(structure) => {
-                    write!(f, "{indent}{{\n")?;
-                
-                    {
-                        indent.push('\t');
-                    
-                        write!(f, "{indent}rapidjson::Value _temp_{depth} = rapidjson::Value(rapidjson::kObjectType);\n")?;
-
-                        generate_hpp_structure_writer(
-                            f,
-                            file,
-                            depth + 1,
-                            indent,
-                            reference,
-                            structure,
-                        )?;
-
-                        write!(
-                            f, 
-                            "{indent}_temp_{parent_depth}.AddMember(\"{field}\", _temp_{depth}, _allocator);\n", 
-                            parent_depth = depth - 1, 
-                            field = field.identifier()
-                        )?;
-
-                        indent.pop();
-                    }
-                    
-                    write!(f, "{indent}}}\n")?;
-                }
-                DataTypeKind::Variant(variant) => {
-                    write!(f, "{indent}{{\n")?;
-                
-                    {
-                        indent.push('\t');
-                    
-                        write!(f, "{indent}rapidjson::Value _temp_{depth} = rapidjson::Value(rapidjson::kObjectType);\n")?;
-
-                        generate_hpp_variant_writer(f, file, depth + 1, indent, reference, variant)?;
-
-                        write!(
-                            f, 
-                            "{indent}_temp_{parent_depth}.AddMember(\"{field}\", _temp_{depth}, _allocator);\n", 
-                            parent_depth = depth - 1, 
-                            field = field.identifier()
-                        )?;
-
-                        indent.pop();
-                    }
-                    
-                    write!(f, "{indent}}}\n")?;
-                }
-                DataTypeKind::Alias(_) => {}
+
+                write!(f, "{indent}}}\n")?;
+
+                generate_hpp_add_value(f, depth, indent, target, &format!("_temp_{depth}"))?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_box_writer(
+    f: &mut Formatter<'_>,
+    file: &File,
+    depth: usize,
+    indent: &mut String,
+    reference: &str,
+    target: Target,
+    boxed: &Boxed,
+) -> Result {
+    if let Some(id) = boxed.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            write!(f, "{indent}if ({reference})\n")?;
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                let reference = format!("(*{reference})");
+                generate_hpp_data_type_writer(f, file, depth, indent, &reference, target, data_type)?;
+
+                indent.pop();
             }
+
+            write!(f, "{indent}}}\n")?;
+
+            // Empty boxes are null
+            write!(f, "{indent}else\n")?;
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                generate_hpp_add_value(
+                    f,
+                    depth,
+                    indent,
+                    target,
+                    "rapidjson::Value(rapidjson::kNullType)",
+                )?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_extern_writer(
+    f: &mut Formatter<'_>,
+    file: &File,
+    depth: usize,
+    indent: &mut String,
+    reference: &str,
+    target: Target,
+    extern_data_type: &Extern,
+) -> Result {
+    if let Some(id) = extern_data_type.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(
+                    f,
+                    "{indent}{} _wire = {}({reference});\n",
+                    super::super::reference::stringify_hpp_reference(
+                        file,
+                        extern_data_type.reference()
+                    ),
+                    extern_data_type.extern_options().cpp_to_wire
+                )?;
+                generate_hpp_data_type_writer(f, file, depth, indent, "_wire", target, data_type)?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
         }
     }
 
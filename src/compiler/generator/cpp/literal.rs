@@ -0,0 +1,24 @@
+use super::super::super::ast::unescape_literal;
+
+/// Returns a schema literal as the contents of a C++ string literal.
+///
+/// Schema literals keep their escape sequences and may span lines, so they are resolved
+/// and escaped again the way C++ reads them.
+pub fn stringify_hpp_literal(literal: &str) -> String {
+    let mut output = String::with_capacity(literal.len());
+
+    for c in unescape_literal(literal).chars() {
+        match c {
+            '\\' => output.push_str("\\\\"),
+            '"' => output.push_str("\\\""),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            // Octal escapes end after three digits, unlike hexadecimal ones
+            c if c.is_control() => output.push_str(&format!("\\{:03o}", c as u32)),
+            c => output.push(c),
+        }
+    }
+
+    output
+}
@@ -5,10 +5,38 @@ use std::fmt::{Formatter, Result};
 
 use crate::compiler::ast::*;
 
+/// Integer type of the size offset and of the lengths
+pub fn stringify_hpp_size_type(file: &File) -> &'static str {
+    match file.file_options().message_buffer_size_type {
+        SizeType::UInt16 => "uint16_t",
+        SizeType::UInt32 => "uint32_t",
+    }
+}
+
+pub fn generate_hpp_max_size(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &str,
+    data_type: &DataType,
+) -> Result {
+    if let Some(max_size) = data_type.layout().size().max {
+        write!(f, "\n")?;
+        write!(f, "{indent}// Maximum size of the serialized message buffer\n")?;
+        write!(
+            f,
+            "{indent}static constexpr size_t MaxSize = {};\n",
+            file.file_options().message_buffer_size_type.size() + max_size
+        )?;
+    }
+
+    Ok(())
+}
+
 pub fn generate_hpp_structure_reader(
     f: &mut Formatter<'_>,
     file: &File,
     indent: &mut String,
+    data_type: &DataType,
     structure: &Structure,
 ) -> Result {
     write!(
@@ -29,14 +57,16 @@ pub fn generate_hpp_structure_reader(
         // Read size offset
         write!(
             f,
-            "{indent}uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);\n"
+            "{indent}{size_type} _size_offset = *({size_type}*)_buffer; _buffer += sizeof({size_type});\n",
+            size_type = stringify_hpp_size_type(file)
         )?;
 
         // Check buffer size
         write!(
             f,
-            "{indent}if(_message_buffer.GetSize() != (2 + {} + _size_offset))\n",
-            structure.min_size()
+            "{indent}if(_message_buffer.GetSize() != ({} + {} + _size_offset))\n",
+            file.file_options().message_buffer_size_type.size(),
+            data_type.layout().size().min
         )?;
         write!(f, "{indent}\treturn false;\n")?;
         write!(f, "\n")?;
@@ -44,6 +74,12 @@ pub fn generate_hpp_structure_reader(
         reader::generate_hpp_structure_reader(f, file, indent, "(*this)", structure)?;
         write!(f, "\n")?;
 
+        // Validate
+        if structure.structure_options().validate {
+            write!(f, "{indent}if (this->Validate() != nullptr) return false;\n")?;
+            write!(f, "\n")?;
+        }
+
         write!(f, "{indent}return true;\n")?;
 
         indent.pop();
@@ -58,22 +94,25 @@ pub fn generate_hpp_structure_writer(
     f: &mut Formatter<'_>,
     file: &File,
     indent: &mut String,
+    data_type: &DataType,
     structure: &Structure,
 ) -> Result {
-    write!(f, "{indent}void Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)\n")?;
+    write!(f, "{indent}bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)\n")?;
     write!(f, "{indent}{{\n")?;
 
     {
         indent.push('\t');
 
-        write!(f, "{indent}uint16_t _size_offset = 0;\n")?;
+        write!(f, "{indent}size_t _size_offset = 0;\n")?;
         writer::generate_hpp_structure_size_offset(f, file, indent, "(*this)", structure)?;
+        generate_hpp_size_offset_check(f, file, indent, data_type)?;
 
         // Set buffer size
         write!(
             f,
-            "{indent}_message_buffer.SetSize(2 + {} + _size_offset);\n",
-            structure.min_size()
+            "{indent}_message_buffer.SetSize({} + {} + _size_offset);\n",
+            file.file_options().message_buffer_size_type.size(),
+            data_type.layout().size().min
         )?;
         write!(f, "\n")?;
 
@@ -86,10 +125,14 @@ pub fn generate_hpp_structure_writer(
         // Write size offset
         write!(
             f,
-            "{indent}*(uint16_t*)_buffer = _size_offset; _buffer += sizeof(uint16_t);\n"
+            "{indent}*({size_type}*)_buffer = ({size_type})_size_offset; _buffer += sizeof({size_type});\n",
+            size_type = stringify_hpp_size_type(file)
         )?;
 
         writer::generate_hpp_structure_writer(f, file, indent, "(*this)", structure)?;
+        write!(f, "\n")?;
+
+        write!(f, "{indent}return true;\n")?;
 
         indent.pop();
     }
@@ -103,6 +146,7 @@ pub fn generate_hpp_variant_reader(
     f: &mut Formatter<'_>,
     file: &File,
     indent: &mut String,
+    data_type: &DataType,
     variant: &Variant,
 ) -> Result {
     write!(f, "{indent}bool Deserialize(server::scripting::sdk::MessageBuffer& _message_buffer)\n")?;
@@ -120,14 +164,16 @@ pub fn generate_hpp_variant_reader(
         // Read size offset
         write!(
             f,
-            "{indent}uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);\n"
+            "{indent}{size_type} _size_offset = *({size_type}*)_buffer; _buffer += sizeof({size_type});\n",
+            size_type = stringify_hpp_size_type(file)
         )?;
 
         // Check buffer size
         write!(
             f,
-            "{indent}if(_message_buffer.GetSize() != (2 + {} + _size_offset))\n",
-            variant.min_size()
+            "{indent}if(_message_buffer.GetSize() != ({} + {} + _size_offset))\n",
+            file.file_options().message_buffer_size_type.size(),
+            data_type.layout().size().min
         )?;
         write!(f, "{indent}\treturn false;\n")?;
         write!(f, "\n")?;
@@ -135,6 +181,12 @@ pub fn generate_hpp_variant_reader(
         reader::generate_hpp_variant_reader(f, file, indent, "(*this)", variant)?;
         write!(f, "\n")?;
 
+        // Validate
+        if variant.variant_options().validate {
+            write!(f, "{indent}if (this->Validate() != nullptr) return false;\n")?;
+            write!(f, "\n")?;
+        }
+
         write!(f, "{indent}return true;\n")?;
 
         indent.pop();
@@ -149,22 +201,25 @@ pub fn generate_hpp_variant_writer(
     f: &mut Formatter<'_>,
     file: &File,
     indent: &mut String,
+    data_type: &DataType,
     variant: &Variant,
 ) -> Result {
-    write!(f, "{indent}void Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)\n")?;
+    write!(f, "{indent}bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)\n")?;
     write!(f, "{indent}{{\n")?;
 
     {
         indent.push('\t');
 
-        write!(f, "{indent}uint16_t _size_offset = 0;\n")?;
+        write!(f, "{indent}size_t _size_offset = 0;\n")?;
         writer::generate_hpp_variant_size_offset(f, file, indent, "(*this)", variant)?;
+        generate_hpp_size_offset_check(f, file, indent, data_type)?;
 
         // Set buffer size
         write!(
             f,
-            "{indent}_message_buffer.SetSize(2 + {} + _size_offset);\n",
-            variant.min_size()
+            "{indent}_message_buffer.SetSize({} + {} + _size_offset);\n",
+            file.file_options().message_buffer_size_type.size(),
+            data_type.layout().size().min
         )?;
         write!(f, "\n")?;
 
@@ -177,10 +232,14 @@ pub fn generate_hpp_variant_writer(
         // Write size offset
         write!(
             f,
-            "{indent}*(uint16_t*)_buffer = _size_offset; _buffer += sizeof(uint16_t);\n"
+            "{indent}*({size_type}*)_buffer = ({size_type})_size_offset; _buffer += sizeof({size_type});\n",
+            size_type = stringify_hpp_size_type(file)
         )?;
 
         writer::generate_hpp_variant_writer(f, file, indent, "(*this)", variant)?;
+        write!(f, "\n")?;
+
+        write!(f, "{indent}return true;\n")?;
 
         indent.pop();
     }
@@ -189,3 +248,114 @@ pub fn generate_hpp_variant_writer(
 
     Ok(())
 }
+
+/// Fails the serialization when the size offset does not fit in the header, it always
+/// fits if the size of the data type is bounded since larger ones are rejected.
+fn generate_hpp_size_offset_check(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &str,
+    data_type: &DataType,
+) -> Result {
+    if data_type.layout().size().max.is_none() {
+        write!(
+            f,
+            "{indent}if (_size_offset > {}) return false;\n",
+            file.file_options().message_buffer_size_type.max()
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Signatures of the functions serializing a recursive data type
+pub fn stringify_hpp_signatures(name: &str) -> [String; 3] {
+    [
+        format!("bool MessageBufferSizeOffset(size_t& _size_offset, const {name}& _value)"),
+        format!("void MessageBufferWrite(uint8_t*& _buffer, const {name}& _value)"),
+        format!("bool MessageBufferRead(uint8_t*& _buffer, {name}& _value)"),
+    ]
+}
+
+pub fn generate_hpp_functions(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    name: &str,
+    data_type: &DataType,
+) -> Result {
+    let [size_offset, writer, reader] = stringify_hpp_signatures(name);
+
+    // Size offset
+    {
+        write!(f, "{indent}inline {size_offset}\n")?;
+        write!(f, "{indent}{{\n")?;
+
+        indent.push('\t');
+
+        match data_type.kind() {
+            DataTypeKind::Structure(structure) => {
+                writer::generate_hpp_structure_size_offset(f, file, indent, "_value", structure)?;
+            }
+            DataTypeKind::Variant(variant) => {
+                writer::generate_hpp_variant_size_offset(f, file, indent, "_value", variant)?;
+            }
+            _ => {}
+        }
+        write!(f, "{indent}return true;\n")?;
+
+        indent.pop();
+
+        write!(f, "{indent}}}\n")?;
+        write!(f, "\n")?;
+    }
+
+    // Writer
+    {
+        write!(f, "{indent}inline {writer}\n")?;
+        write!(f, "{indent}{{\n")?;
+
+        indent.push('\t');
+
+        match data_type.kind() {
+            DataTypeKind::Structure(structure) => {
+                writer::generate_hpp_structure_writer(f, file, indent, "_value", structure)?;
+            }
+            DataTypeKind::Variant(variant) => {
+                writer::generate_hpp_variant_writer(f, file, indent, "_value", variant)?;
+            }
+            _ => {}
+        }
+
+        indent.pop();
+
+        write!(f, "{indent}}}\n")?;
+        write!(f, "\n")?;
+    }
+
+    // Reader
+    {
+        write!(f, "{indent}inline {reader}\n")?;
+        write!(f, "{indent}{{\n")?;
+
+        indent.push('\t');
+
+        match data_type.kind() {
+            DataTypeKind::Structure(structure) => {
+                reader::generate_hpp_structure_reader(f, file, indent, "_value", structure)?;
+            }
+            DataTypeKind::Variant(variant) => {
+                reader::generate_hpp_variant_reader(f, file, indent, "_value", variant)?;
+            }
+            _ => {}
+        }
+        write!(f, "{indent}return true;\n")?;
+
+        indent.pop();
+
+        write!(f, "{indent}}}\n")?;
+        write!(f, "\n")?;
+    }
+
+    Ok(())
+}
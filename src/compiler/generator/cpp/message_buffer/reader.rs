@@ -11,96 +11,323 @@ pub fn generate_hpp_field_reader(
 ) -> Result {
     if let Some(id) = field.reference().get_id() {
         if let Some(data_type) = file.data_type(id) {
-            match data_type.kind() {
-                DataTypeKind::Boolean => {
-                    write!(
-                        f,
-                        "{indent}{reference} = *(bool*)_buffer; _buffer += sizeof(bool);\n"
-                    )?;
-                }
-                DataTypeKind::Int8 => {
-                    write!(
-                        f,
-                        "{indent}{reference} = *(int8_t*)_buffer; _buffer += sizeof(int8_t);\n"
-                    )?;
-                }
-                DataTypeKind::Int16 => {
-                    write!(
-                        f,
-                        "{indent}{reference} = *(int16_t*)_buffer; _buffer += sizeof(int16_t);\n"
-                    )?;
-                }
-                DataTypeKind::Int32 => {
-                    write!(
-                        f,
-                        "{indent}{reference} = *(int32_t*)_buffer; _buffer += sizeof(int32_t);\n"
-                    )?;
-                }
-                DataTypeKind::Int64 => {
-                    write!(
-                        f,
-                        "{indent}{reference} = *(int64_t*)_buffer; _buffer += sizeof(int64_t);\n"
-                    )?;
-                }
-                DataTypeKind::UInt8 => {
-                    write!(
-                        f,
-                        "{indent}{reference} = *(uint8_t*)_buffer; _buffer += sizeof(uint8_t);\n"
-                    )?;
-                }
-                DataTypeKind::UInt16 => {
-                    write!(
-                        f,
-                        "{indent}{reference} = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);\n"
-                    )?;
-                }
-                DataTypeKind::UInt32 => {
-                    write!(
-                        f,
-                        "{indent}{reference} = *(uint32_t*)_buffer; _buffer += sizeof(uint32_t);\n"
-                    )?;
-                }
-                DataTypeKind::UInt64 => {
-                    write!(
-                        f,
-                        "{indent}{reference} = *(uint64_t*)_buffer; _buffer += sizeof(uint64_t);\n"
-                    )?;
-                }
-                DataTypeKind::Single => {
-                    write!(
+            generate_hpp_data_type_reader(f, file, indent, reference, data_type)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_data_type_reader(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    data_type: &DataType,
+) -> Result {
+    match data_type.kind() {
+        DataTypeKind::Boolean => {
+            write!(
+                f,
+                "{indent}{reference} = *(bool*)_buffer; _buffer += sizeof(bool);\n"
+            )?;
+        }
+        DataTypeKind::Int8 => {
+            write!(
+                f,
+                "{indent}{reference} = *(int8_t*)_buffer; _buffer += sizeof(int8_t);\n"
+            )?;
+        }
+        DataTypeKind::Int16 => {
+            write!(
+                f,
+                "{indent}{reference} = *(int16_t*)_buffer; _buffer += sizeof(int16_t);\n"
+            )?;
+        }
+        DataTypeKind::Int32 => {
+            write!(
+                f,
+                "{indent}{reference} = *(int32_t*)_buffer; _buffer += sizeof(int32_t);\n"
+            )?;
+        }
+        DataTypeKind::Int64 => {
+            write!(
+                f,
+                "{indent}{reference} = *(int64_t*)_buffer; _buffer += sizeof(int64_t);\n"
+            )?;
+        }
+        DataTypeKind::UInt8 => {
+            write!(
+                f,
+                "{indent}{reference} = *(uint8_t*)_buffer; _buffer += sizeof(uint8_t);\n"
+            )?;
+        }
+        DataTypeKind::UInt16 => {
+            write!(
+                f,
+                "{indent}{reference} = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);\n"
+            )?;
+        }
+        DataTypeKind::UInt32 => {
+            write!(
+                f,
+                "{indent}{reference} = *(uint32_t*)_buffer; _buffer += sizeof(uint32_t);\n"
+            )?;
+        }
+        DataTypeKind::UInt64 => {
+            write!(
+                f,
+                "{indent}{reference} = *(uint64_t*)_buffer; _buffer += sizeof(uint64_t);\n"
+            )?;
+        }
+        DataTypeKind::Int128 => {
+            write!(
+                f,
+                "{indent}{reference} = *(__int128*)_buffer; _buffer += sizeof(__int128);\n"
+            )?;
+        }
+        DataTypeKind::UInt128 => {
+            write!(
+                f,
+                "{indent}{reference} = *(unsigned __int128*)_buffer; _buffer += sizeof(unsigned __int128);\n"
+            )?;
+        }
+        DataTypeKind::Half => {
+            write!(
+                f,
+                "{indent}{reference} = Float16::FromBits(*(uint16_t*)_buffer); _buffer += sizeof(uint16_t);\n"
+            )?;
+        }
+        DataTypeKind::Single => {
+            write!(
+                f,
+                "{indent}{reference} = *(float*)_buffer; _buffer += sizeof(float);\n"
+            )?;
+        }
+        DataTypeKind::Double => {
+            write!(
+                f,
+                "{indent}{reference} = *(double*)_buffer; _buffer += sizeof(double);\n"
+            )?;
+        }
+        DataTypeKind::Decimal(scale) => {
+            write!(
+                f,
+                "{indent}{reference} = Decimal<{scale}>::FromUnscaled(*(int64_t*)_buffer); _buffer += sizeof(int64_t);\n"
+            )?;
+        }
+        DataTypeKind::Char => {
+            write!(
+                f,
+                "{indent}{reference} = *(char32_t*)_buffer; _buffer += sizeof(char32_t);\n"
+            )?;
+        }
+        DataTypeKind::String => {
+            write!(f, "{indent}{{\n")?;
+
+            write!(
+                f,
+                "{indent}\t{size_type} _data_size = *({size_type}*)_buffer; _buffer += sizeof({size_type});\n",
+                size_type = super::stringify_hpp_size_type(file)
+            )?;
+            write!(
+                f,
+                "{indent}\tuint8_t* _data = _buffer; _buffer += _data_size;\n",
+            )?;
+            write!(
                         f,
-                        "{indent}{reference} = *(float*)_buffer; _buffer += sizeof(float);\n"
+                        "{indent}\t{reference} = std::string_view((const char*)_data, (size_t)_data_size);\n"
                     )?;
+
+            write!(f, "{indent}}}\n")?;
+        }
+        DataTypeKind::BoundedString(_) => {
+            write!(f, "{indent}{{\n")?;
+
+            write!(
+                f,
+                "{indent}\t{size_type} _data_size = *({size_type}*)_buffer; _buffer += sizeof({size_type});\n",
+                size_type = super::stringify_hpp_size_type(file)
+            )?;
+            write!(
+                f,
+                "{indent}\tuint8_t* _data = _buffer; _buffer += _data_size;\n",
+            )?;
+            write!(
+                f,
+                "{indent}\tif (!{reference}.assign(std::string_view((const char*)_data, (size_t)_data_size))) return false;\n"
+            )?;
+
+            write!(f, "{indent}}}\n")?;
+        }
+        DataTypeKind::Array(array) => {
+            generate_hpp_array_reader(f, file, indent, reference, array)?;
+        }
+        DataTypeKind::Box(boxed) => {
+            generate_hpp_box_reader(f, file, indent, reference, boxed)?;
+        }
+        DataTypeKind::Structure(_) | DataTypeKind::Variant(_) if data_type.is_recursive() => {
+            write!(
+                f,
+                "{indent}if (!MessageBufferRead(_buffer, {reference})) return false;\n"
+            )?;
+        }
+        DataTypeKind::Structure(structure) => {
+            generate_hpp_structure_reader(f, file, indent, reference, structure)?;
+        }
+        DataTypeKind::Variant(variant) => {
+            generate_hpp_variant_reader(f, file, indent, reference, variant)?;
+        }
+        DataTypeKind::Extern(extern_data_type) => {
+            generate_hpp_extern_reader(f, file, indent, reference, extern_data_type)?;
+        }
+        DataTypeKind::Alias(_) => {}
+    }
+
+    Ok(())
+}
+
+pub fn generate_hpp_array_reader(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    array: &Array,
+) -> Result {
+    if let Some(id) = array.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            // Nested arrays are distinguished by their indentation
+            let level = indent.len();
+
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(
+                    f,
+                    "{indent}{size_type} _count_{level} = *({size_type}*)_buffer; _buffer += sizeof({size_type});\n",
+                    size_type = super::stringify_hpp_size_type(file)
+                )?;
+                if let Some(max_len) = array.max_len() {
+                    write!(f, "{indent}if (_count_{level} > {max_len}) return false;\n")?;
                 }
-                DataTypeKind::Double => {
-                    write!(
-                        f,
-                        "{indent}{reference} = *(double*)_buffer; _buffer += sizeof(double);\n"
-                    )?;
+                write!(f, "{indent}{reference}.resize(_count_{level});\n")?;
+
+                write!(
+                    f,
+                    "{indent}for ({size_type} _i_{level} = 0; _i_{level} < _count_{level}; ++_i_{level})\n",
+                    size_type = super::stringify_hpp_size_type(file)
+                )?;
+                write!(f, "{indent}{{\n")?;
+
+                {
+                    indent.push('\t');
+
+                    let reference = format!("{reference}[_i_{level}]");
+                    generate_hpp_data_type_reader(f, file, indent, &reference, data_type)?;
+
+                    indent.pop();
                 }
-                DataTypeKind::String => {
-                    write!(f, "{indent}{{\n")?;
 
-                    write!(f, "{indent}\tuint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);\n",)?;
-                    write!(
-                        f,
-                        "{indent}\tuint8_t* _data = _buffer; _buffer += _data_size;\n",
-                    )?;
+                write!(f, "{indent}}}\n")?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn generate_hpp_box_reader(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    boxed: &Boxed,
+) -> Result {
+    if let Some(id) = boxed.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            // Nested boxes are distinguished by their indentation
+            let level = indent.len();
+
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(
+                    f,
+                    "{indent}bool _present_{level} = *(bool*)_buffer; _buffer += sizeof(bool);\n"
+                )?;
+                write!(f, "{indent}if (_present_{level})\n")?;
+                write!(f, "{indent}{{\n")?;
+
+                {
+                    indent.push('\t');
+
                     write!(
                         f,
-                        "{indent}\t{reference} = std::string_view((const char*)_data, (size_t)_data_size);\n"
+                        "{indent}{reference} = std::make_unique<{}>();\n",
+                        super::super::reference::stringify_hpp_data_type(file, data_type)
                     )?;
 
-                    write!(f, "{indent}}}\n")?;
-                }
-                DataTypeKind::Structure(structure) => {
-                    generate_hpp_structure_reader(f, file, indent, reference, structure)?;
-                }
-                DataTypeKind::Variant(variant) => {
-                    generate_hpp_variant_reader(f, file, indent, reference, variant)?;
+                    let reference = format!("(*{reference})");
+                    generate_hpp_data_type_reader(f, file, indent, &reference, data_type)?;
+
+                    indent.pop();
                 }
-                DataTypeKind::Alias(_) => {}
+
+                write!(f, "{indent}}}\n")?;
+                write!(f, "{indent}else\n")?;
+                write!(f, "{indent}\t{reference}.reset();\n")?;
+
+                indent.pop();
             }
+
+            write!(f, "{indent}}}\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn generate_hpp_extern_reader(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    extern_data_type: &Extern,
+) -> Result {
+    if let Some(id) = extern_data_type.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(
+                    f,
+                    "{indent}{} _wire;\n",
+                    super::super::reference::stringify_hpp_reference(
+                        file,
+                        extern_data_type.reference()
+                    )
+                )?;
+                generate_hpp_data_type_reader(f, file, indent, "_wire", data_type)?;
+                write!(
+                    f,
+                    "{indent}{}(_wire, {reference});\n",
+                    extern_data_type.extern_options().cpp_from_wire
+                )?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
         }
     }
 
@@ -177,24 +404,19 @@ pub fn generate_hpp_variant_reader(
             if let Some(id) = field.reference().get_id() {
                 if let Some(data_type) = file.data_type(id) {
                     match data_type.kind() {
-                        DataTypeKind::String => {
-                            write!(
-                                f,
-                                "{indent}{reference} = std::string_view();\n"
-                            )?;
-                        }
-                        DataTypeKind::Structure(structure) => {
-                            write!(
-                                f,
-                                "{indent}{reference} = {}();\n",
-                                structure.identifier()
-                            )?;
-                        }
-                        DataTypeKind::Variant(variant) => {
+                        // The storage of the previous case was destroyed, so the new case
+                        // is constructed in place rather than assigned
+                        DataTypeKind::String
+                        | DataTypeKind::BoundedString(_)
+                        | DataTypeKind::Array(_)
+                        | DataTypeKind::Box(_)
+                        | DataTypeKind::Extern(_)
+                        | DataTypeKind::Structure(_)
+                        | DataTypeKind::Variant(_) => {
                             write!(
                                 f,
-                                "{indent}{reference} = {}();\n",
-                                variant.identifier()
+                                "{indent}new (&{reference}) {}();\n",
+                                super::super::reference::stringify_hpp_data_type(file, data_type)
                             )?;
                         }
                         _ => {}
@@ -11,19 +11,56 @@ fn generate_hpp_field_size_offset(
 ) -> Result {
     if let Some(id) = field.reference().get_id() {
         if let Some(data_type) = file.data_type(id) {
-            match data_type.kind() {
-                DataTypeKind::String => {
-                    write!(f, "{indent}_size_offset += {reference}.size();\n")?;
-                }
-                DataTypeKind::Structure(structure) => {
-                    generate_hpp_structure_size_offset(f, file, indent, &reference, structure)?;
-                }
-                DataTypeKind::Variant(variant) => {
-                    generate_hpp_variant_size_offset(f, file, indent, &reference, variant)?;
-                }
-                _ => {}
+            generate_hpp_data_type_size_offset(f, file, indent, reference, data_type)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_data_type_size_offset(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    data_type: &DataType,
+) -> Result {
+    match data_type.kind() {
+        DataTypeKind::String | DataTypeKind::BoundedString(_) => {
+            write!(f, "{indent}_size_offset += {reference}.size();\n")?;
+        }
+        DataTypeKind::Array(array) => {
+            generate_hpp_array_size_offset(f, file, indent, reference, array)?;
+        }
+        DataTypeKind::Box(boxed) => {
+            generate_hpp_box_size_offset(f, file, indent, reference, boxed)?;
+        }
+        DataTypeKind::Structure(_) | DataTypeKind::Variant(_) if data_type.is_recursive() => {
+            write!(
+                f,
+                "{indent}if (!MessageBufferSizeOffset(_size_offset, {reference})) return false;\n"
+            )?;
+        }
+        DataTypeKind::Structure(structure) => {
+            generate_hpp_structure_size_offset(f, file, indent, reference, structure)?;
+        }
+        DataTypeKind::Variant(variant) => {
+            generate_hpp_variant_size_offset(f, file, indent, reference, variant)?;
+        }
+        DataTypeKind::Extern(extern_data_type) => {
+            let underlying_id = extern_data_type.reference().get_id();
+            if let Some(DataTypeKind::String | DataTypeKind::BoundedString(_)) = underlying_id
+                .and_then(|id| file.data_type(id))
+                .map(DataType::kind)
+            {
+                write!(
+                    f,
+                    "{indent}_size_offset += {}({reference}).size();\n",
+                    extern_data_type.extern_options().cpp_to_wire
+                )?;
             }
         }
+        _ => {}
     }
 
     Ok(())
@@ -38,91 +75,334 @@ fn generate_hpp_field_writer(
 ) -> Result {
     if let Some(id) = field.reference().get_id() {
         if let Some(data_type) = file.data_type(id) {
-            match data_type.kind() {
-                DataTypeKind::Boolean => {
-                    write!(
-                        f,
-                        "{indent}*(bool*)_buffer = {reference}; _buffer += sizeof(bool);\n"
-                    )?;
-                }
-                DataTypeKind::Int8 => {
-                    write!(
-                        f,
-                        "{indent}*(int8_t*)_buffer = {reference}; _buffer += sizeof(int8_t);\n"
-                    )?;
-                }
-                DataTypeKind::Int16 => {
-                    write!(
-                        f,
-                        "{indent}*(int16_t*)_buffer = {reference}; _buffer += sizeof(int16_t);\n"
-                    )?;
-                }
-                DataTypeKind::Int32 => {
-                    write!(
-                        f,
-                        "{indent}*(int32_t*)_buffer = {reference}; _buffer += sizeof(int32_t);\n"
-                    )?;
-                }
-                DataTypeKind::Int64 => {
-                    write!(
-                        f,
-                        "{indent}*(int64_t*)_buffer = {reference}; _buffer += sizeof(int64_t);\n"
-                    )?;
-                }
-                DataTypeKind::UInt8 => {
-                    write!(
-                        f,
-                        "{indent}*(uint8_t*)_buffer = {reference}; _buffer += sizeof(uint8_t);\n"
-                    )?;
-                }
-                DataTypeKind::UInt16 => {
-                    write!(
-                        f,
-                        "{indent}*(uint16_t*)_buffer = {reference}; _buffer += sizeof(uint16_t);\n"
-                    )?;
-                }
-                DataTypeKind::UInt32 => {
-                    write!(
-                        f,
-                        "{indent}*(uint32_t*)_buffer = {reference}; _buffer += sizeof(uint32_t);\n"
-                    )?;
-                }
-                DataTypeKind::UInt64 => {
-                    write!(
-                        f,
-                        "{indent}*(uint64_t*)_buffer = {reference}; _buffer += sizeof(uint64_t);\n"
-                    )?;
-                }
-                DataTypeKind::Single => {
-                    write!(
-                        f,
-                        "{indent}*(float*)_buffer = {reference}; _buffer += sizeof(float);\n"
-                    )?;
-                }
-                DataTypeKind::Double => {
-                    write!(
-                        f,
-                        "{indent}*(double*)_buffer = {reference}; _buffer += sizeof(double);\n"
-                    )?;
-                }
-                DataTypeKind::String => {
-                    write!(
-                        f,
-                        "{indent}*(uint16_t*)_buffer = {reference}.size(); _buffer += sizeof(uint16_t);\n"
-                    )?;
-                    write!(
+            generate_hpp_data_type_writer(f, file, indent, reference, data_type)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_data_type_writer(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    data_type: &DataType,
+) -> Result {
+    match data_type.kind() {
+        DataTypeKind::Boolean => {
+            write!(
+                f,
+                "{indent}*(bool*)_buffer = {reference}; _buffer += sizeof(bool);\n"
+            )?;
+        }
+        DataTypeKind::Int8 => {
+            write!(
+                f,
+                "{indent}*(int8_t*)_buffer = {reference}; _buffer += sizeof(int8_t);\n"
+            )?;
+        }
+        DataTypeKind::Int16 => {
+            write!(
+                f,
+                "{indent}*(int16_t*)_buffer = {reference}; _buffer += sizeof(int16_t);\n"
+            )?;
+        }
+        DataTypeKind::Int32 => {
+            write!(
+                f,
+                "{indent}*(int32_t*)_buffer = {reference}; _buffer += sizeof(int32_t);\n"
+            )?;
+        }
+        DataTypeKind::Int64 => {
+            write!(
+                f,
+                "{indent}*(int64_t*)_buffer = {reference}; _buffer += sizeof(int64_t);\n"
+            )?;
+        }
+        DataTypeKind::UInt8 => {
+            write!(
+                f,
+                "{indent}*(uint8_t*)_buffer = {reference}; _buffer += sizeof(uint8_t);\n"
+            )?;
+        }
+        DataTypeKind::UInt16 => {
+            write!(
+                f,
+                "{indent}*(uint16_t*)_buffer = {reference}; _buffer += sizeof(uint16_t);\n"
+            )?;
+        }
+        DataTypeKind::UInt32 => {
+            write!(
+                f,
+                "{indent}*(uint32_t*)_buffer = {reference}; _buffer += sizeof(uint32_t);\n"
+            )?;
+        }
+        DataTypeKind::UInt64 => {
+            write!(
+                f,
+                "{indent}*(uint64_t*)_buffer = {reference}; _buffer += sizeof(uint64_t);\n"
+            )?;
+        }
+        DataTypeKind::Int128 => {
+            write!(
+                f,
+                "{indent}*(__int128*)_buffer = {reference}; _buffer += sizeof(__int128);\n"
+            )?;
+        }
+        DataTypeKind::UInt128 => {
+            write!(
+                f,
+                "{indent}*(unsigned __int128*)_buffer = {reference}; _buffer += sizeof(unsigned __int128);\n"
+            )?;
+        }
+        DataTypeKind::Half => {
+            write!(
+                f,
+                "{indent}*(uint16_t*)_buffer = {reference}.Bits(); _buffer += sizeof(uint16_t);\n"
+            )?;
+        }
+        DataTypeKind::Single => {
+            write!(
+                f,
+                "{indent}*(float*)_buffer = {reference}; _buffer += sizeof(float);\n"
+            )?;
+        }
+        DataTypeKind::Double => {
+            write!(
+                f,
+                "{indent}*(double*)_buffer = {reference}; _buffer += sizeof(double);\n"
+            )?;
+        }
+        DataTypeKind::Decimal(_) => {
+            write!(
+                f,
+                "{indent}*(int64_t*)_buffer = {reference}.Unscaled(); _buffer += sizeof(int64_t);\n"
+            )?;
+        }
+        DataTypeKind::Char => {
+            write!(
+                f,
+                "{indent}*(char32_t*)_buffer = {reference}; _buffer += sizeof(char32_t);\n"
+            )?;
+        }
+        DataTypeKind::String | DataTypeKind::BoundedString(_) => {
+            write!(
+                f,
+                "{indent}*({size_type}*)_buffer = ({size_type}){reference}.size(); _buffer += sizeof({size_type});\n",
+                size_type = super::stringify_hpp_size_type(file)
+            )?;
+            write!(
                         f,
                         "{indent}std::memcpy((void*)_buffer, (void*){reference}.data(), {reference}.size()); _buffer += {reference}.size();\n"
                     )?;
+        }
+        DataTypeKind::Array(array) => {
+            generate_hpp_array_writer(f, file, indent, reference, array)?;
+        }
+        DataTypeKind::Box(boxed) => {
+            generate_hpp_box_writer(f, file, indent, reference, boxed)?;
+        }
+        DataTypeKind::Structure(_) | DataTypeKind::Variant(_) if data_type.is_recursive() => {
+            write!(f, "{indent}MessageBufferWrite(_buffer, {reference});\n")?;
+        }
+        DataTypeKind::Structure(structure) => {
+            generate_hpp_structure_writer(f, file, indent, reference, structure)?;
+        }
+        DataTypeKind::Variant(variant) => {
+            generate_hpp_variant_writer(f, file, indent, reference, variant)?;
+        }
+        DataTypeKind::Extern(extern_data_type) => {
+            generate_hpp_extern_writer(f, file, indent, reference, extern_data_type)?;
+        }
+        DataTypeKind::Alias(_) => {}
+    }
+
+    Ok(())
+}
+
+pub fn generate_hpp_array_size_offset(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    array: &Array,
+) -> Result {
+    if let Some(id) = array.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            // Elements of no size do not count towards the size offset, so their number is
+            // checked on its own
+            if array.max_len().is_none() && data_type.layout().size().min == 0 {
+                write!(
+                    f,
+                    "{indent}if ({reference}.size() > {}) return false;\n",
+                    file.file_options().message_buffer_size_type.max()
+                )?;
+            }
+
+            // Encoded size of the elements without their own size offsets
+            write!(
+                f,
+                "{indent}_size_offset += {reference}.size() * {};\n",
+                data_type.layout().size().min
+            )?;
+
+            if !data_type.is_primitive() {
+                // Nested arrays are distinguished by their indentation
+                let level = indent.len();
+
+                write!(f, "{indent}for (const auto& _item_{level} : {reference})\n")?;
+                write!(f, "{indent}{{\n")?;
+
+                {
+                    indent.push('\t');
+
+                    let reference = format!("_item_{level}");
+                    generate_hpp_data_type_size_offset(f, file, indent, &reference, data_type)?;
+
+                    indent.pop();
                 }
-                DataTypeKind::Structure(structure) => {
-                    generate_hpp_structure_writer(f, file, indent, &reference, structure)?;
-                }
-                DataTypeKind::Variant(variant) => {
-                    generate_hpp_variant_writer(f, file, indent, &reference, variant)?;
-                }
-                DataTypeKind::Alias(_) => {}
+
+                write!(f, "{indent}}}\n")?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn generate_hpp_array_writer(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    array: &Array,
+) -> Result {
+    if let Some(id) = array.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            // Nested arrays are distinguished by their indentation
+            let level = indent.len();
+
+            write!(
+                f,
+                "{indent}*({size_type}*)_buffer = ({size_type}){reference}.size(); _buffer += sizeof({size_type});\n",
+                size_type = super::stringify_hpp_size_type(file)
+            )?;
+            write!(f, "{indent}for (const auto& _item_{level} : {reference})\n")?;
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                let reference = format!("_item_{level}");
+                generate_hpp_data_type_writer(f, file, indent, &reference, data_type)?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn generate_hpp_box_size_offset(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    boxed: &Boxed,
+) -> Result {
+    if let Some(id) = boxed.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            // The presence flag is part of the minimal size, the boxed value is not
+            write!(f, "{indent}if ({reference})\n")?;
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(
+                    f,
+                    "{indent}_size_offset += {};\n",
+                    data_type.layout().size().min
+                )?;
+
+                let reference = format!("(*{reference})");
+                generate_hpp_data_type_size_offset(f, file, indent, &reference, data_type)?;
+
+                indent.pop();
             }
+
+            write!(f, "{indent}}}\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn generate_hpp_box_writer(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    boxed: &Boxed,
+) -> Result {
+    if let Some(id) = boxed.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            write!(
+                f,
+                "{indent}*(bool*)_buffer = (bool){reference}; _buffer += sizeof(bool);\n"
+            )?;
+            write!(f, "{indent}if ({reference})\n")?;
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                let reference = format!("(*{reference})");
+                generate_hpp_data_type_writer(f, file, indent, &reference, data_type)?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn generate_hpp_extern_writer(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    extern_data_type: &Extern,
+) -> Result {
+    if let Some(id) = extern_data_type.reference().get_id() {
+        if let Some(data_type) = file.data_type(id) {
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(
+                    f,
+                    "{indent}{} _wire = {}({reference});\n",
+                    super::super::reference::stringify_hpp_reference(
+                        file,
+                        extern_data_type.reference()
+                    ),
+                    extern_data_type.extern_options().cpp_to_wire
+                )?;
+                generate_hpp_data_type_writer(f, file, indent, "_wire", data_type)?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
         }
     }
 
@@ -176,7 +456,19 @@ pub fn generate_hpp_variant_size_offset(
     reference: &str,
     variant: &Variant,
 ) -> Result {
-    write!(f, "{indent}_size_offset += sizeof(uint16_t);\n")?;
+    // The smallest case is part of the minimal size, larger cases add the difference
+    let min_sizes = variant
+        .fields()
+        .iter()
+        .map(|field| {
+            field
+                .reference()
+                .get_id()
+                .and_then(|id| file.data_type(id))
+                .map_or(0, |data_type| data_type.layout().size().min)
+        })
+        .collect::<Vec<usize>>();
+    let min_size = min_sizes.iter().copied().min().unwrap_or(0);
 
     // Start of switch
     {
@@ -188,7 +480,7 @@ pub fn generate_hpp_variant_size_offset(
 
     // Switch cases
     {
-        for field in variant.fields() {
+        for (field, field_min_size) in variant.fields().iter().zip(min_sizes) {
             let reference = format!(
                 "(*({}*){reference}.value.data())",
                 super::super::reference::stringify_hpp_reference(file, field.reference())
@@ -205,6 +497,13 @@ pub fn generate_hpp_variant_size_offset(
             {
                 indent.push('\t');
 
+                if field_min_size > min_size {
+                    write!(
+                        f,
+                        "{indent}_size_offset += {};\n",
+                        field_min_size - min_size
+                    )?;
+                }
                 generate_hpp_field_size_offset(f, file, indent, &reference, field)?;
                 write!(f, "{indent}break;\n")?;
 
@@ -234,6 +533,11 @@ pub fn generate_hpp_variant_writer(
     {
         let reference = format!("{reference}.kind");
 
+        write!(
+            f,
+            "{indent}*(uint16_t*)_buffer = (uint16_t){reference}; _buffer += sizeof(uint16_t);\n"
+        )?;
+
         write!(f, "{indent}switch({reference})\n")?;
         write!(f, "{indent}{{\n")?;
     }
@@ -1,17 +1,68 @@
+mod container;
+mod deprecated;
 mod file;
-mod reference;
+mod fingerprint;
 mod friends;
+mod json;
+mod literal;
+mod message_buffer;
+mod primitive;
+mod recursive;
+mod reference;
+mod service;
 mod structure;
+mod validate;
 mod variant;
-mod message_buffer;
-mod json;
 
 use format::lazy_format;
 
+use super::super::options::{Definition, Scope, Type};
 use super::Generator;
 
 impl Generator {
     pub fn generate_cplusplus(&self) -> String {
-        lazy_format!(|f| file::generate_hpp_file(f, &self.file)).to_string()
+        let code = lazy_format!(|f| file::generate_hpp_file(f, &self.file)).to_string();
+
+        // String literals may span lines of a file with other line endings
+        code.replace("\r\n", "\n").replace('\r', "\n")
     }
 }
+
+/// Options read by the C++ generator.
+pub const OPTIONS: &[Definition] = &[
+    Definition {
+        name: "cpp_namespace",
+        scopes: &[Scope::File],
+        arguments: &[Type::String],
+        min_arguments: 1,
+        max_arguments: Some(1),
+    },
+    Definition {
+        name: "cpp",
+        scopes: &[Scope::Extern],
+        arguments: &[Type::String],
+        min_arguments: 1,
+        max_arguments: Some(1),
+    },
+    Definition {
+        name: "cpp_include",
+        scopes: &[Scope::Extern],
+        arguments: &[Type::String],
+        min_arguments: 1,
+        max_arguments: Some(1),
+    },
+    Definition {
+        name: "cpp_to_wire",
+        scopes: &[Scope::Extern],
+        arguments: &[Type::String],
+        min_arguments: 1,
+        max_arguments: Some(1),
+    },
+    Definition {
+        name: "cpp_from_wire",
+        scopes: &[Scope::Extern],
+        arguments: &[Type::String],
+        min_arguments: 1,
+        max_arguments: Some(1),
+    },
+];
@@ -0,0 +1,482 @@
+use std::fmt::{Formatter, Result};
+
+use crate::compiler::ast::*;
+
+/// Returns whether a data type matching the predicate is referenced by a
+/// field, an array or an extern data type.
+fn uses_data_type(file: &File, predicate: impl Fn(&DataTypeKind) -> bool) -> bool {
+    let is_match = |reference: &Reference| {
+        reference
+            .get_id()
+            .and_then(|id| file.data_type(id))
+            .is_some_and(|data_type| predicate(data_type.kind()))
+    };
+
+    file.data_types()
+        .iter()
+        .any(|data_type| match data_type.kind() {
+            DataTypeKind::Structure(structure) => structure
+                .fields()
+                .iter()
+                .any(|field| is_match(field.reference())),
+            DataTypeKind::Variant(variant) => variant
+                .fields()
+                .iter()
+                .any(|field| is_match(field.reference())),
+            DataTypeKind::Array(array) => is_match(array.reference()),
+            DataTypeKind::Extern(extern_data_type) => is_match(extern_data_type.reference()),
+            _ => false,
+        })
+}
+
+pub fn uses_int128(file: &File) -> bool {
+    uses_data_type(file, |kind| {
+        matches!(kind, DataTypeKind::Int128 | DataTypeKind::UInt128)
+    })
+}
+
+pub fn uses_half(file: &File) -> bool {
+    uses_data_type(file, |kind| matches!(kind, DataTypeKind::Half))
+}
+
+pub fn uses_decimal(file: &File) -> bool {
+    uses_data_type(file, |kind| matches!(kind, DataTypeKind::Decimal(_)))
+}
+
+pub fn uses_char(file: &File) -> bool {
+    uses_data_type(file, |kind| matches!(kind, DataTypeKind::Char))
+}
+
+pub fn uses_primitives(file: &File) -> bool {
+    uses_int128(file) || uses_half(file) || uses_decimal(file) || uses_char(file)
+}
+
+type Generate = fn(&mut Formatter<'_>) -> Result;
+
+/// Generates the support code of the primitive data types without a
+/// standard C++ counterpart.
+///
+/// On the wire every one of them is stored little-endian: 128-bit integers
+/// as 16 bytes, half floats as their IEEE 754 binary16 bits, decimals as
+/// their unscaled 64-bit value and chars as 32-bit unicode scalar values.
+/// In JSON, half floats are numbers while the others are strings.
+///
+/// The definitions are guarded so that several generated headers can be
+/// included in the same translation unit.
+pub fn generate_hpp_primitives(f: &mut Formatter<'_>, file: &File) -> Result {
+    let sections: [(bool, Generate); 4] = [
+        (uses_int128(file), generate_hpp_int128),
+        (uses_half(file), generate_hpp_half),
+        (uses_decimal(file), generate_hpp_decimal),
+        (uses_char(file), generate_hpp_char),
+    ];
+
+    let mut is_first = true;
+    for (is_used, generate) in sections {
+        if !is_used {
+            continue;
+        }
+        if !is_first {
+            write!(f, "\n")?;
+        }
+        generate(f)?;
+        is_first = false;
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_int128(f: &mut Formatter<'_>) -> Result {
+    write!(f, "#ifndef PBC_INT128\n")?;
+    write!(f, "#define PBC_INT128\n")?;
+    write!(f, "\n")?;
+
+    // Formatting
+    write!(
+        f,
+        "inline std::string UInt128ToString(unsigned __int128 value)\n"
+    )?;
+    write!(f, "{{\n")?;
+    write!(f, "\tchar buffer[40];\n")?;
+    write!(f, "\tchar* it = buffer + sizeof(buffer);\n")?;
+    write!(
+        f,
+        "\tdo {{ *--it = (char)('0' + (int)(value % 10)); value /= 10; }} while (value != 0);\n"
+    )?;
+    write!(f, "\treturn std::string(it, buffer + sizeof(buffer));\n")?;
+    write!(f, "}}\n")?;
+    write!(f, "\n")?;
+    write!(f, "inline std::string Int128ToString(__int128 value)\n")?;
+    write!(f, "{{\n")?;
+    write!(
+        f,
+        "\tif (value < 0) return \"-\" + UInt128ToString((unsigned __int128)0 - (unsigned __int128)value);\n"
+    )?;
+    write!(f, "\treturn UInt128ToString((unsigned __int128)value);\n")?;
+    write!(f, "}}\n")?;
+    write!(f, "\n")?;
+
+    // Parsing
+    write!(
+        f,
+        "// Returns false if the text isn't a decimal number in range\n"
+    )?;
+    write!(
+        f,
+        "inline bool ParseUInt128(std::string_view text, unsigned __int128& value)\n"
+    )?;
+    write!(f, "{{\n")?;
+    write!(f, "\tif (text.empty()) return false;\n")?;
+    write!(f, "\tunsigned __int128 result = 0;\n")?;
+    write!(f, "\tfor (char c : text)\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\tif (c < '0' || c > '9') return false;\n")?;
+    write!(f, "\t\tunsigned digit = (unsigned)(c - '0');\n")?;
+    write!(
+        f,
+        "\t\tif (result > (~(unsigned __int128)0 - digit) / 10) return false;\n"
+    )?;
+    write!(f, "\t\tresult = result * 10 + digit;\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "\tvalue = result;\n")?;
+    write!(f, "\treturn true;\n")?;
+    write!(f, "}}\n")?;
+    write!(f, "\n")?;
+    write!(
+        f,
+        "// Returns false if the text isn't a decimal number in range\n"
+    )?;
+    write!(
+        f,
+        "inline bool ParseInt128(std::string_view text, __int128& value)\n"
+    )?;
+    write!(f, "{{\n")?;
+    write!(f, "\tbool negative = !text.empty() && text[0] == '-';\n")?;
+    write!(f, "\tunsigned __int128 magnitude;\n")?;
+    write!(
+        f,
+        "\tif (!ParseUInt128(negative ? text.substr(1) : text, magnitude)) return false;\n"
+    )?;
+    write!(
+        f,
+        "\tif (magnitude > ((unsigned __int128)1 << 127) - (negative ? 0 : 1)) return false;\n"
+    )?;
+    write!(
+        f,
+        "\tvalue = negative ? (__int128)((unsigned __int128)0 - magnitude) : (__int128)magnitude;\n"
+    )?;
+    write!(f, "\treturn true;\n")?;
+    write!(f, "}}\n")?;
+    write!(f, "\n")?;
+
+    write!(f, "#endif\n")?;
+
+    Ok(())
+}
+
+fn generate_hpp_half(f: &mut Formatter<'_>) -> Result {
+    write!(f, "#ifndef PBC_FLOAT16\n")?;
+    write!(f, "#define PBC_FLOAT16\n")?;
+    write!(f, "\n")?;
+
+    write!(f, "// IEEE 754 half precision floating point number\n")?;
+    write!(f, "class Float16\n")?;
+    write!(f, "{{\n")?;
+    write!(f, "private:\n")?;
+    write!(f, "\tuint16_t _bits = 0;\n")?;
+    write!(f, "\n")?;
+    write!(f, "public:\n")?;
+    write!(f, "\tFloat16() = default;\n")?;
+    write!(f, "\tFloat16(float value) : _bits(FromFloat(value)) {{}}\n")?;
+    write!(f, "\n")?;
+    write!(f, "\tstatic Float16 FromBits(uint16_t bits)\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\tFloat16 value;\n")?;
+    write!(f, "\t\tvalue._bits = bits;\n")?;
+    write!(f, "\t\treturn value;\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "\n")?;
+    write!(f, "\tuint16_t Bits() const {{ return this->_bits; }}\n")?;
+    write!(
+        f,
+        "\toperator float() const {{ return ToFloat(this->_bits); }}\n"
+    )?;
+    write!(f, "\n")?;
+    write!(f, "private:\n")?;
+
+    // Rounds to nearest, ties to even
+    write!(f, "\tstatic uint16_t FromFloat(float value)\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\tuint32_t x;\n")?;
+    write!(f, "\t\tstd::memcpy(&x, &value, sizeof(x));\n")?;
+    write!(f, "\t\tuint32_t sign = (x >> 16) & 0x8000;\n")?;
+    write!(f, "\t\tuint32_t exponent = (x >> 23) & 0xff;\n")?;
+    write!(f, "\t\tuint32_t mantissa = x & 0x7fffff;\n")?;
+    write!(
+        f,
+        "\t\tif (exponent == 0xff) return (uint16_t)(sign | 0x7c00 | (mantissa != 0 ? 0x200 : 0));\n"
+    )?;
+    write!(f, "\t\tint32_t e = (int32_t)exponent - 127 + 15;\n")?;
+    write!(f, "\t\tif (e >= 0x1f) return (uint16_t)(sign | 0x7c00);\n")?;
+    write!(f, "\t\tif (e <= 0)\n")?;
+    write!(f, "\t\t{{\n")?;
+    write!(f, "\t\t\tif (e < -10) return (uint16_t)sign;\n")?;
+    write!(f, "\t\t\tmantissa |= 0x800000;\n")?;
+    write!(f, "\t\t\tuint32_t shift = (uint32_t)(14 - e);\n")?;
+    write!(f, "\t\t\tuint32_t bits = mantissa >> shift;\n")?;
+    write!(f, "\t\t\tuint32_t rest = mantissa & ((1u << shift) - 1);\n")?;
+    write!(f, "\t\t\tuint32_t halfway = 1u << (shift - 1);\n")?;
+    write!(
+        f,
+        "\t\t\tif (rest > halfway || (rest == halfway && (bits & 1) != 0)) bits++;\n"
+    )?;
+    write!(f, "\t\t\treturn (uint16_t)(sign | bits);\n")?;
+    write!(f, "\t\t}}\n")?;
+    write!(
+        f,
+        "\t\tuint32_t bits = ((uint32_t)e << 10) | (mantissa >> 13);\n"
+    )?;
+    write!(f, "\t\tuint32_t rest = mantissa & 0x1fff;\n")?;
+    write!(
+        f,
+        "\t\tif (rest > 0x1000 || (rest == 0x1000 && (bits & 1) != 0)) bits++;\n"
+    )?;
+    write!(f, "\t\treturn (uint16_t)(sign | bits);\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "\n")?;
+
+    // Every half precision value is exactly representable
+    write!(f, "\tstatic float ToFloat(uint16_t bits)\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\tuint32_t sign = (uint32_t)(bits & 0x8000) << 16;\n")?;
+    write!(f, "\t\tuint32_t exponent = (bits >> 10) & 0x1f;\n")?;
+    write!(f, "\t\tuint32_t mantissa = bits & 0x3ff;\n")?;
+    write!(f, "\t\tuint32_t x;\n")?;
+    write!(
+        f,
+        "\t\tif (exponent == 0x1f) x = sign | 0x7f800000 | (mantissa << 13);\n"
+    )?;
+    write!(
+        f,
+        "\t\telse if (exponent != 0) x = sign | ((exponent + 112) << 23) | (mantissa << 13);\n"
+    )?;
+    write!(f, "\t\telse if (mantissa == 0) x = sign;\n")?;
+    write!(f, "\t\telse\n")?;
+    write!(f, "\t\t{{\n")?;
+    write!(f, "\t\t\texponent = 113;\n")?;
+    write!(
+        f,
+        "\t\t\twhile ((mantissa & 0x400) == 0) {{ mantissa <<= 1; exponent--; }}\n"
+    )?;
+    write!(
+        f,
+        "\t\t\tx = sign | (exponent << 23) | ((mantissa & 0x3ff) << 13);\n"
+    )?;
+    write!(f, "\t\t}}\n")?;
+    write!(f, "\t\tfloat value;\n")?;
+    write!(f, "\t\tstd::memcpy(&value, &x, sizeof(value));\n")?;
+    write!(f, "\t\treturn value;\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "}};\n")?;
+    write!(f, "\n")?;
+
+    write!(f, "#endif\n")?;
+
+    Ok(())
+}
+
+fn generate_hpp_decimal(f: &mut Formatter<'_>) -> Result {
+    write!(f, "#ifndef PBC_DECIMAL\n")?;
+    write!(f, "#define PBC_DECIMAL\n")?;
+    write!(f, "\n")?;
+
+    write!(
+        f,
+        "// Fixed-point decimal with a fixed number of fractional digits\n"
+    )?;
+    write!(f, "template<size_t Scale>\n")?;
+    write!(f, "class Decimal\n")?;
+    write!(f, "{{\n")?;
+    write!(f, "private:\n")?;
+    write!(f, "\tint64_t _value = 0;\n")?;
+    write!(f, "\n")?;
+    write!(f, "public:\n")?;
+    write!(f, "\tstatic Decimal FromUnscaled(int64_t value)\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\tDecimal decimal;\n")?;
+    write!(f, "\t\tdecimal._value = value;\n")?;
+    write!(f, "\t\treturn decimal;\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "\n")?;
+    write!(f, "\tint64_t Unscaled() const {{ return this->_value; }}\n")?;
+    write!(f, "\n")?;
+
+    // Formatting
+    write!(f, "\t// Always has exactly Scale fractional digits\n")?;
+    write!(f, "\tstd::string ToString() const\n")?;
+    write!(f, "\t{{\n")?;
+    write!(
+        f,
+        "\t\tuint64_t magnitude = this->_value < 0 ? 0 - (uint64_t)this->_value : (uint64_t)this->_value;\n"
+    )?;
+    write!(f, "\t\tstd::string text = std::to_string(magnitude);\n")?;
+    write!(
+        f,
+        "\t\tif (text.size() <= Scale) text.insert(0, Scale + 1 - text.size(), '0');\n"
+    )?;
+    write!(
+        f,
+        "\t\tif (Scale > 0) text.insert(text.size() - Scale, 1, '.');\n"
+    )?;
+    write!(f, "\t\tif (this->_value < 0) text.insert(0, 1, '-');\n")?;
+    write!(f, "\t\treturn text;\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "\n")?;
+
+    // Parsing
+    write!(
+        f,
+        "\t// Returns false if the text isn't a decimal number in range with at most Scale fractional digits\n"
+    )?;
+    write!(
+        f,
+        "\tstatic bool Parse(std::string_view text, Decimal& decimal)\n"
+    )?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\tbool negative = !text.empty() && text[0] == '-';\n")?;
+    write!(f, "\t\tif (negative) text.remove_prefix(1);\n")?;
+    write!(f, "\t\tsize_t point = text.find('.');\n")?;
+    write!(f, "\t\tstd::string_view integer = text.substr(0, point);\n")?;
+    write!(
+        f,
+        "\t\tstd::string_view fraction = point == std::string_view::npos ? std::string_view() : text.substr(point + 1);\n"
+    )?;
+    write!(
+        f,
+        "\t\tif (integer.empty() || fraction.size() > Scale) return false;\n"
+    )?;
+    write!(
+        f,
+        "\t\tif (point != std::string_view::npos && fraction.empty()) return false;\n"
+    )?;
+    write!(f, "\t\tuint64_t magnitude = 0;\n")?;
+    write!(
+        f,
+        "\t\tfor (size_t i = 0; i < integer.size() + Scale; ++i)\n"
+    )?;
+    write!(f, "\t\t{{\n")?;
+    write!(f, "\t\t\tsize_t j = i - integer.size();\n")?;
+    write!(
+        f,
+        "\t\t\tchar c = i < integer.size() ? integer[i] : j < fraction.size() ? fraction[j] : '0';\n"
+    )?;
+    write!(f, "\t\t\tif (c < '0' || c > '9') return false;\n")?;
+    write!(f, "\t\t\tuint64_t digit = (uint64_t)(c - '0');\n")?;
+    write!(
+        f,
+        "\t\t\tif (magnitude > (UINT64_MAX - digit) / 10) return false;\n"
+    )?;
+    write!(f, "\t\t\tmagnitude = magnitude * 10 + digit;\n")?;
+    write!(f, "\t\t}}\n")?;
+    write!(
+        f,
+        "\t\tif (magnitude > (uint64_t)INT64_MAX + (negative ? 1 : 0)) return false;\n"
+    )?;
+    write!(
+        f,
+        "\t\tdecimal._value = negative ? (int64_t)(0 - magnitude) : (int64_t)magnitude;\n"
+    )?;
+    write!(f, "\t\treturn true;\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "}};\n")?;
+    write!(f, "\n")?;
+
+    write!(f, "#endif\n")?;
+
+    Ok(())
+}
+
+fn generate_hpp_char(f: &mut Formatter<'_>) -> Result {
+    write!(f, "#ifndef PBC_CHAR\n")?;
+    write!(f, "#define PBC_CHAR\n")?;
+    write!(f, "\n")?;
+
+    // Encoding
+    write!(f, "inline std::string EncodeUtf8(char32_t value)\n")?;
+    write!(f, "{{\n")?;
+    write!(f, "\tstd::string text;\n")?;
+    write!(f, "\tif (value < 0x80) text += (char)value;\n")?;
+    write!(f, "\telse if (value < 0x800)\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\ttext += (char)(0xc0 | (value >> 6));\n")?;
+    write!(f, "\t\ttext += (char)(0x80 | (value & 0x3f));\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "\telse if (value < 0x10000)\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\ttext += (char)(0xe0 | (value >> 12));\n")?;
+    write!(f, "\t\ttext += (char)(0x80 | ((value >> 6) & 0x3f));\n")?;
+    write!(f, "\t\ttext += (char)(0x80 | (value & 0x3f));\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "\telse\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\ttext += (char)(0xf0 | (value >> 18));\n")?;
+    write!(f, "\t\ttext += (char)(0x80 | ((value >> 12) & 0x3f));\n")?;
+    write!(f, "\t\ttext += (char)(0x80 | ((value >> 6) & 0x3f));\n")?;
+    write!(f, "\t\ttext += (char)(0x80 | (value & 0x3f));\n")?;
+    write!(f, "\t}}\n")?;
+    write!(f, "\treturn text;\n")?;
+    write!(f, "}}\n")?;
+    write!(f, "\n")?;
+
+    // Decoding
+    write!(
+        f,
+        "// Returns false if the text isn't exactly one UTF-8 encoded unicode scalar value\n"
+    )?;
+    write!(
+        f,
+        "inline bool DecodeUtf8Char(std::string_view text, char32_t& value)\n"
+    )?;
+    write!(f, "{{\n")?;
+    write!(f, "\tif (text.empty()) return false;\n")?;
+    write!(f, "\tuint8_t lead = (uint8_t)text[0];\n")?;
+    write!(f, "\tsize_t size;\n")?;
+    write!(f, "\tchar32_t result;\n")?;
+    write!(f, "\tif (lead < 0x80) {{ size = 1; result = lead; }}\n")?;
+    write!(
+        f,
+        "\telse if ((lead & 0xe0) == 0xc0) {{ size = 2; result = lead & 0x1f; }}\n"
+    )?;
+    write!(
+        f,
+        "\telse if ((lead & 0xf0) == 0xe0) {{ size = 3; result = lead & 0x0f; }}\n"
+    )?;
+    write!(
+        f,
+        "\telse if ((lead & 0xf8) == 0xf0) {{ size = 4; result = lead & 0x07; }}\n"
+    )?;
+    write!(f, "\telse return false;\n")?;
+    write!(f, "\tif (text.size() != size) return false;\n")?;
+    write!(f, "\tfor (size_t i = 1; i < size; ++i)\n")?;
+    write!(f, "\t{{\n")?;
+    write!(f, "\t\tuint8_t byte = (uint8_t)text[i];\n")?;
+    write!(f, "\t\tif ((byte & 0xc0) != 0x80) return false;\n")?;
+    write!(f, "\t\tresult = (result << 6) | (byte & 0x3f);\n")?;
+    write!(f, "\t}}\n")?;
+    write!(
+        f,
+        "\tstatic constexpr char32_t min[] = {{ 0, 0, 0x80, 0x800, 0x10000 }};\n"
+    )?;
+    write!(
+        f,
+        "\tif (result < min[size] || result > 0x10ffff || (result >= 0xd800 && result <= 0xdfff)) return false;\n"
+    )?;
+    write!(f, "\tvalue = result;\n")?;
+    write!(f, "\treturn true;\n")?;
+    write!(f, "}}\n")?;
+    write!(f, "\n")?;
+
+    write!(f, "#endif\n")?;
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::fmt::{Formatter, Result};
+
+use crate::compiler::ast::*;
+
+/// Returns the structures and variants that contain themselves.
+///
+/// Their serialization can't be expanded inline, it is generated as free functions
+/// that are declared before the classes and defined after them.
+fn recursive_data_types(file: &File) -> impl Iterator<Item = &DataType> {
+    file.ordered_data_types().filter(|data_type| {
+        data_type.is_recursive()
+            && matches!(
+                data_type.kind(),
+                DataTypeKind::Structure(_) | DataTypeKind::Variant(_)
+            )
+    })
+}
+
+fn stringify_hpp_signatures(file: &File, data_type: &DataType) -> Vec<String> {
+    let name = super::reference::stringify_hpp_data_type(file, data_type);
+
+    let mut signatures = super::message_buffer::stringify_hpp_signatures(&name).to_vec();
+    signatures.extend(super::json::stringify_hpp_signatures(&name));
+    signatures
+}
+
+pub fn generate_hpp_declarations(f: &mut Formatter<'_>, file: &File, indent: &str) -> Result {
+    for data_type in recursive_data_types(file) {
+        for signature in stringify_hpp_signatures(file, data_type) {
+            write!(f, "{indent}inline {signature};\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Generates the friend declarations of the functions, they may read the private
+/// fields of every class since nested data types are expanded inline.
+pub fn generate_hpp_friends(f: &mut Formatter<'_>, file: &File, indent: &str) -> Result {
+    for data_type in recursive_data_types(file) {
+        for signature in stringify_hpp_signatures(file, data_type) {
+            write!(f, "{indent}friend {signature};\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn generate_hpp_definitions(f: &mut Formatter<'_>, file: &File, indent: &mut String) -> Result {
+    for data_type in recursive_data_types(file) {
+        let name = super::reference::stringify_hpp_data_type(file, data_type);
+
+        super::message_buffer::generate_hpp_functions(f, file, indent, &name, data_type)?;
+        super::json::generate_hpp_functions(f, file, indent, &name, data_type)?;
+    }
+
+    Ok(())
+}
@@ -1,27 +1,52 @@
 use crate::compiler::ast::*;
 
-pub fn stringify_hpp_reference<'a>(file: &'a File, reference: &Reference) -> &'a str {
+pub fn stringify_hpp_reference(file: &File, reference: &Reference) -> String {
     if let Some(id) = reference.get_id() {
         if let Some(data_type) = file.data_type(id) {
-            return match data_type.kind() {
-                DataTypeKind::Boolean => "bool",
-                DataTypeKind::Int8 => "int8_t",
-                DataTypeKind::UInt8 => "uint8_t",
-                DataTypeKind::Int16 => "int16_t",
-                DataTypeKind::UInt16 => "uint16_t",
-                DataTypeKind::Int32 => "int32_t",
-                DataTypeKind::UInt32 => "uint32_t",
-                DataTypeKind::Int64 => "int64_t",
-                DataTypeKind::UInt64 => "uint64_t",
-                DataTypeKind::Single => "float",
-                DataTypeKind::Double => "double",
-                DataTypeKind::String => "std::string_view",
-                DataTypeKind::Alias(alias) => alias.identifier().get(),
-                DataTypeKind::Structure(structure) => structure.identifier().get(),
-                DataTypeKind::Variant(variant) => variant.identifier().get(),
-            };
+            return stringify_hpp_data_type(file, data_type);
         }
     }
 
-    return "error";
-}
\ No newline at end of file
+    "error".to_string()
+}
+
+pub fn stringify_hpp_data_type(file: &File, data_type: &DataType) -> String {
+    match data_type.kind() {
+        DataTypeKind::Boolean => "bool".to_string(),
+        DataTypeKind::Int8 => "int8_t".to_string(),
+        DataTypeKind::UInt8 => "uint8_t".to_string(),
+        DataTypeKind::Int16 => "int16_t".to_string(),
+        DataTypeKind::UInt16 => "uint16_t".to_string(),
+        DataTypeKind::Int32 => "int32_t".to_string(),
+        DataTypeKind::UInt32 => "uint32_t".to_string(),
+        DataTypeKind::Int64 => "int64_t".to_string(),
+        DataTypeKind::UInt64 => "uint64_t".to_string(),
+        DataTypeKind::Int128 => "__int128".to_string(),
+        DataTypeKind::UInt128 => "unsigned __int128".to_string(),
+        DataTypeKind::Half => "Float16".to_string(),
+        DataTypeKind::Single => "float".to_string(),
+        DataTypeKind::Double => "double".to_string(),
+        DataTypeKind::Decimal(scale) => format!("Decimal<{scale}>"),
+        DataTypeKind::Char => "char32_t".to_string(),
+        DataTypeKind::String => "std::string_view".to_string(),
+        DataTypeKind::BoundedString(max_len) => format!("FixedString<{max_len}>"),
+        DataTypeKind::Alias(alias) => alias.identifier().get().to_string(),
+        DataTypeKind::Array(array) => match array.max_len() {
+            Some(max_len) => format!(
+                "FixedVector<{}, {max_len}>",
+                stringify_hpp_reference(file, array.reference())
+            ),
+            None => format!(
+                "std::vector<{}>",
+                stringify_hpp_reference(file, array.reference())
+            ),
+        },
+        DataTypeKind::Box(boxed) => format!(
+            "std::unique_ptr<{}>",
+            stringify_hpp_reference(file, boxed.reference())
+        ),
+        DataTypeKind::Extern(extern_data_type) => extern_data_type.extern_options().cpp.clone(),
+        DataTypeKind::Structure(structure) => structure.identifier().get().to_string(),
+        DataTypeKind::Variant(variant) => variant.identifier().get().to_string(),
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt::{Formatter, Result};
+
+use crate::compiler::ast::*;
+
+pub fn generate_hpp_service(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    service: &Service,
+) -> Result {
+    generate_hpp_service_methods(f, indent, service)?;
+    write!(f, "\n")?;
+    generate_hpp_service_server(f, file, indent, service)?;
+    write!(f, "\n")?;
+    generate_hpp_service_client(f, file, indent, service)?;
+
+    Ok(())
+}
+
+fn generate_hpp_service_methods(
+    f: &mut Formatter<'_>,
+    indent: &mut String,
+    service: &Service,
+) -> Result {
+    write!(
+        f,
+        "{indent}enum class {}Method : uint16_t\n",
+        service.identifier()
+    )?;
+    write!(f, "{indent}{{\n")?;
+    for (index, rpc) in service.rpcs().iter().enumerate() {
+        write!(f, "{indent}\t{} = {},\n", rpc.identifier(), index + 1)?;
+    }
+    write!(f, "{indent}}};\n")?;
+
+    Ok(())
+}
+
+fn generate_hpp_service_server(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    service: &Service,
+) -> Result {
+    write!(f, "{indent}class {}Server\n", service.identifier())?;
+    write!(f, "{indent}{{\n")?;
+
+    // Public
+    {
+        write!(f, "{indent}public:\n")?;
+
+        indent.push('\t');
+
+        write!(
+            f,
+            "{indent}virtual ~{}Server() = default;\n",
+            service.identifier()
+        )?;
+        write!(f, "\n")?;
+
+        // Methods
+        for rpc in service.rpcs() {
+            write!(
+                f,
+                "{indent}virtual bool {}(const {}& request, {}& reply) = 0;\n",
+                rpc.identifier(),
+                super::reference::stringify_hpp_reference(file, rpc.request()),
+                super::reference::stringify_hpp_reference(file, rpc.response()),
+            )?;
+        }
+        write!(f, "\n")?;
+
+        // Dispatch
+        write!(f, "{indent}bool Dispatch(uint16_t _method_id, server::scripting::sdk::MessageBuffer& _request_buffer, server::scripting::sdk::MessageBuffer& _reply_buffer)\n")?;
+        write!(f, "{indent}{{\n")?;
+
+        {
+            indent.push('\t');
+
+            write!(
+                f,
+                "{indent}switch(({}Method)_method_id)\n",
+                service.identifier()
+            )?;
+            write!(f, "{indent}{{\n")?;
+
+            for rpc in service.rpcs() {
+                write!(
+                    f,
+                    "{indent}case {}Method::{}: \n",
+                    service.identifier(),
+                    rpc.identifier()
+                )?;
+                write!(f, "{indent}{{\n")?;
+
+                {
+                    indent.push('\t');
+
+                    write!(
+                        f,
+                        "{indent}{} _request;\n",
+                        super::reference::stringify_hpp_reference(file, rpc.request())
+                    )?;
+                    write!(
+                        f,
+                        "{indent}if (!_request.Deserialize(_request_buffer)) return false;\n"
+                    )?;
+                    write!(f, "\n")?;
+
+                    write!(
+                        f,
+                        "{indent}{} _reply;\n",
+                        super::reference::stringify_hpp_reference(file, rpc.response())
+                    )?;
+                    write!(
+                        f,
+                        "{indent}if (!this->{}(_request, _reply)) return false;\n",
+                        rpc.identifier()
+                    )?;
+                    write!(f, "\n")?;
+
+                    write!(
+                        f,
+                        "{indent}if (!_reply.Serialize(_reply_buffer)) return false;\n"
+                    )?;
+                    write!(f, "{indent}return true;\n")?;
+
+                    indent.pop();
+                }
+
+                write!(f, "{indent}}}\n")?;
+            }
+
+            write!(f, "{indent}default: \n")?;
+            write!(f, "{indent}\treturn false;\n")?;
+            write!(f, "{indent}}}\n")?;
+
+            indent.pop();
+        }
+
+        write!(f, "{indent}}}\n")?;
+
+        indent.pop();
+    }
+
+    write!(f, "{indent}}};\n")?;
+
+    Ok(())
+}
+
+fn generate_hpp_service_client(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    service: &Service,
+) -> Result {
+    write!(f, "{indent}class {}Client\n", service.identifier())?;
+    write!(f, "{indent}{{\n")?;
+
+    // Public types
+    {
+        write!(f, "{indent}public:\n")?;
+
+        indent.push('\t');
+
+        write!(
+            f,
+            "{indent}// Sends the request buffer of a method and receives its reply buffer\n"
+        )?;
+        write!(f, "{indent}using Transport = std::function<bool(uint16_t, server::scripting::sdk::MessageBuffer&, server::scripting::sdk::MessageBuffer&)>;\n")?;
+        write!(f, "\n")?;
+
+        indent.pop();
+    }
+
+    // Private
+    {
+        write!(f, "{indent}private:\n")?;
+
+        indent.push('\t');
+
+        write!(f, "{indent}Transport transport;\n")?;
+        write!(f, "\n")?;
+
+        indent.pop();
+    }
+
+    // Public
+    {
+        write!(f, "{indent}public:\n")?;
+
+        indent.push('\t');
+
+        write!(
+            f,
+            "{indent}explicit {}Client(Transport transport) : transport(std::move(transport)) {{}}\n",
+            service.identifier()
+        )?;
+
+        for rpc in service.rpcs() {
+            write!(f, "\n")?;
+
+            write!(
+                f,
+                "{indent}bool {}({}& request, {}& reply)\n",
+                rpc.identifier(),
+                super::reference::stringify_hpp_reference(file, rpc.request()),
+                super::reference::stringify_hpp_reference(file, rpc.response()),
+            )?;
+            write!(f, "{indent}{{\n")?;
+
+            {
+                indent.push('\t');
+
+                write!(
+                    f,
+                    "{indent}server::scripting::sdk::MessageBuffer _request_buffer;\n"
+                )?;
+                write!(
+                    f,
+                    "{indent}if (!request.Serialize(_request_buffer)) return false;\n"
+                )?;
+                write!(f, "\n")?;
+
+                write!(
+                    f,
+                    "{indent}server::scripting::sdk::MessageBuffer _reply_buffer;\n"
+                )?;
+                write!(
+                    f,
+                    "{indent}if (!this->transport((uint16_t){}Method::{}, _request_buffer, _reply_buffer)) return false;\n",
+                    service.identifier(),
+                    rpc.identifier()
+                )?;
+                write!(f, "\n")?;
+
+                write!(f, "{indent}return reply.Deserialize(_reply_buffer);\n")?;
+
+                indent.pop();
+            }
+
+            write!(f, "{indent}}}\n")?;
+        }
+
+        indent.pop();
+    }
+
+    write!(f, "{indent}}};\n")?;
+
+    Ok(())
+}
@@ -29,7 +29,7 @@ pub fn generate_hpp_structure(
         indent.push('\t');
 
         // Friends
-        super::friends::generate_hpp_friends(f, file, indent, data_type.max_rank())?;
+        super::friends::generate_hpp_friends(f, file, indent, data_type)?;
         write!(f, "\n")?;
 
         // Fields
@@ -52,36 +52,71 @@ pub fn generate_hpp_structure(
 
         indent.push('\t');
 
+        // Schema hash
+        super::fingerprint::generate_hpp_schema_hash(f, indent, data_type)?;
+
         // Getter and setter
         for field in structure.fields() {
+            let deprecated = super::deprecated::stringify_hpp_deprecated(
+                field
+                    .field_options()
+                    .deprecated
+                    .as_ref()
+                    .or(structure.structure_options().deprecated.as_ref()),
+            );
+
             write!(
                 f,
-                "{indent}const {}& Get{}() const {{ return this->{}; }}\n",
+                "{indent}{deprecated}const {}& Get{}() const {{ return this->{}; }}\n",
                 super::reference::stringify_hpp_reference(file, field.reference()),
                 field.identifier().get_pascal_case(),
                 field.identifier(),
             )?;
 
-            write!(
-                f,
-                "{indent}{}& Set{field_pascal}(const {}& {field}) {{ this->{field} = {field}; return *this; }}\n",
-                structure.identifier(),
-                super::reference::stringify_hpp_reference(file, field.reference()),
-                field = field.identifier(),
-                field_pascal = field.identifier().get_pascal_case(),
-            )?;
+            let is_move_only = field
+                .reference()
+                .get_id()
+                .and_then(|id| file.data_type(id))
+                .is_some_and(|data_type| super::container::is_move_only(file, data_type));
+
+            if is_move_only {
+                write!(
+                    f,
+                    "{indent}{deprecated}{}& Set{field_pascal}({} {field}) {{ this->{field} = std::move({field}); return *this; }}\n",
+                    structure.identifier(),
+                    super::reference::stringify_hpp_reference(file, field.reference()),
+                    field = field.identifier(),
+                    field_pascal = field.identifier().get_pascal_case(),
+                )?;
+            } else {
+                write!(
+                    f,
+                    "{indent}{deprecated}{}& Set{field_pascal}(const {}& {field}) {{ this->{field} = {field}; return *this; }}\n",
+                    structure.identifier(),
+                    super::reference::stringify_hpp_reference(file, field.reference()),
+                    field = field.identifier(),
+                    field_pascal = field.identifier().get_pascal_case(),
+                )?;
+            }
 
             write!(f, "\n")?;
         }
 
+        // Validation
+        write!(f, "\n")?;
+        super::validate::generate_hpp_structure_validate(f, file, indent, structure)?;
+
         // Message Buffer
+        if structure.structure_options().message_buffer.0 || structure.structure_options().message_buffer.1 {
+            super::message_buffer::generate_hpp_max_size(f, file, indent, data_type)?;
+        }
         if structure.structure_options().message_buffer.0 {
             write!(f, "\n")?;
-            super::message_buffer::generate_hpp_structure_reader(f, file, indent, structure)?;
+            super::message_buffer::generate_hpp_structure_reader(f, file, indent, data_type, structure)?;
         }
         if structure.structure_options().message_buffer.1 {
             write!(f, "\n")?;
-            super::message_buffer::generate_hpp_structure_writer(f, file, indent, structure)?;
+            super::message_buffer::generate_hpp_structure_writer(f, file, indent, data_type, structure)?;
         }
 
         // Json
@@ -0,0 +1,249 @@
+use std::fmt::{Formatter, Result};
+
+use crate::compiler::ast::*;
+
+/// Returns whether a field or any of its nested fields has constraints.
+fn has_field_constraints(file: &File, field: &Field) -> bool {
+    let options = field.field_options();
+    if options.range.is_some()
+        || options.max_len.is_some()
+        || options.non_empty
+        || options.pattern.is_some()
+    {
+        return true;
+    }
+
+    if let Some(data_type) = field.reference().get_id().and_then(|id| file.data_type(id)) {
+        match data_type.kind() {
+            DataTypeKind::Structure(structure) => {
+                return has_fielded_constraints(file, structure);
+            }
+            DataTypeKind::Variant(variant) => {
+                return has_fielded_constraints(file, variant);
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+fn has_fielded_constraints(file: &File, fielded: &dyn Fielded) -> bool {
+    fielded
+        .fields()
+        .iter()
+        .any(|field| has_field_constraints(file, field))
+}
+
+/// Returns whether a regular expression is used by any field.
+pub fn uses_pattern(file: &File) -> bool {
+    file.data_types().iter().any(|data_type| {
+        let fields = match data_type.kind() {
+            DataTypeKind::Structure(structure) => structure.fields(),
+            DataTypeKind::Variant(variant) => variant.fields(),
+            _ => return false,
+        };
+
+        fields
+            .iter()
+            .any(|field| field.field_options().pattern.is_some())
+    })
+}
+
+fn generate_hpp_field_validator(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    path: &str,
+    field: &Field,
+) -> Result {
+    let options = field.field_options();
+
+    if let Some((min, max)) = &options.range {
+        write!(
+            f,
+            "{indent}if ({reference} < {min} || {reference} > {max}) return \"{path}\";\n"
+        )?;
+    }
+
+    if let Some(max_len) = options.max_len {
+        write!(
+            f,
+            "{indent}if ({reference}.size() > {max_len}) return \"{path}\";\n"
+        )?;
+    }
+
+    if options.non_empty {
+        write!(f, "{indent}if ({reference}.empty()) return \"{path}\";\n")?;
+    }
+
+    if let Some(pattern) = &options.pattern {
+        let pattern = super::literal::stringify_hpp_literal(pattern);
+        write!(f, "{indent}{{\n")?;
+        write!(
+            f,
+            "{indent}\tstatic const std::regex _pattern(\"{pattern}\");\n"
+        )?;
+        write!(
+            f,
+            "{indent}\tif (!std::regex_match({reference}.begin(), {reference}.end(), _pattern)) return \"{path}\";\n"
+        )?;
+        write!(f, "{indent}}}\n")?;
+    }
+
+    if let Some(data_type) = field.reference().get_id().and_then(|id| file.data_type(id)) {
+        match data_type.kind() {
+            DataTypeKind::Structure(structure) => {
+                generate_hpp_structure_validator(f, file, indent, reference, path, structure)?;
+            }
+            DataTypeKind::Variant(variant) => {
+                generate_hpp_variant_validator(f, file, indent, reference, path, variant)?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_structure_validator(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    path: &str,
+    structure: &Structure,
+) -> Result {
+    for field in structure.fields() {
+        if !has_field_constraints(file, field) {
+            continue;
+        }
+
+        let reference = format!("{reference}.{}", field.identifier());
+        let path = if path.is_empty() {
+            field.identifier().to_string()
+        } else {
+            format!("{path}.{}", field.identifier())
+        };
+
+        generate_hpp_field_validator(f, file, indent, &reference, &path, field)?;
+    }
+
+    Ok(())
+}
+
+fn generate_hpp_variant_validator(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    reference: &str,
+    path: &str,
+    variant: &Variant,
+) -> Result {
+    if !has_fielded_constraints(file, variant) {
+        return Ok(());
+    }
+
+    // Start of switch
+    write!(f, "{indent}switch({reference}.kind)\n")?;
+    write!(f, "{indent}{{\n")?;
+
+    // Switch cases
+    for field in variant.fields() {
+        if !has_field_constraints(file, field) {
+            continue;
+        }
+
+        let case_reference = format!(
+            "(*({}*){reference}.value.data())",
+            super::reference::stringify_hpp_reference(file, field.reference())
+        );
+        let path = if path.is_empty() {
+            field.identifier().to_string()
+        } else {
+            format!("{path}.{}", field.identifier())
+        };
+
+        write!(
+            f,
+            "{indent}case {}Kind::{}: \n",
+            variant.identifier(),
+            field.identifier().get_pascal_case()
+        )?;
+        write!(f, "{indent}{{\n")?;
+
+        {
+            indent.push('\t');
+
+            generate_hpp_field_validator(f, file, indent, &case_reference, &path, field)?;
+            write!(f, "{indent}break;\n")?;
+
+            indent.pop();
+        }
+
+        write!(f, "{indent}}}\n")?;
+    }
+
+    write!(f, "{indent}default: \n")?;
+    write!(f, "{indent}\tbreak;\n")?;
+
+    // End of switch
+    write!(f, "{indent}}}\n")?;
+
+    Ok(())
+}
+
+pub fn generate_hpp_structure_validate(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    structure: &Structure,
+) -> Result {
+    write!(
+        f,
+        "{indent}// Returns the path of the first invalid field or nullptr\n"
+    )?;
+    write!(f, "{indent}const char* Validate() const\n")?;
+    write!(f, "{indent}{{\n")?;
+
+    {
+        indent.push('\t');
+
+        generate_hpp_structure_validator(f, file, indent, "(*this)", "", structure)?;
+        write!(f, "{indent}return nullptr;\n")?;
+
+        indent.pop();
+    }
+
+    write!(f, "{indent}}}\n")?;
+
+    Ok(())
+}
+
+pub fn generate_hpp_variant_validate(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    variant: &Variant,
+) -> Result {
+    write!(
+        f,
+        "{indent}// Returns the path of the first invalid field or nullptr\n"
+    )?;
+    write!(f, "{indent}const char* Validate() const\n")?;
+    write!(f, "{indent}{{\n")?;
+
+    {
+        indent.push('\t');
+
+        generate_hpp_variant_validator(f, file, indent, "(*this)", "", variant)?;
+        write!(f, "{indent}return nullptr;\n")?;
+
+        indent.pop();
+    }
+
+    write!(f, "{indent}}}\n")?;
+
+    Ok(())
+}
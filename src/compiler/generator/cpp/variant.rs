@@ -42,12 +42,12 @@ pub fn generate_hpp_variant(
         indent.push('\t');
 
         // Friends
-        super::friends::generate_hpp_friends(f, file, indent, data_type.max_rank())?;
+        super::friends::generate_hpp_friends(f, file, indent, data_type)?;
         write!(f, "\n")?;
 
         // Fields
         {
-            write!(f, "{indent}{}Kind kind;\n", variant.identifier())?;
+            write!(f, "{indent}{}Kind kind{{}};\n", variant.identifier())?;
             write!(f, "{indent}std::array<uint8_t, StaticMax<\n")?;
 
             for field in variant.fields() {
@@ -72,6 +72,9 @@ pub fn generate_hpp_variant(
 
         indent.push('\t');
 
+        // Schema hash
+        super::fingerprint::generate_hpp_schema_hash(f, indent, data_type)?;
+
         // Deconstructor
         {
             write!(f, "{indent}~{}()\n", variant.identifier())?;
@@ -108,6 +111,20 @@ pub fn generate_hpp_variant(
                                                 data_type = variant.identifier()
                                             )?;
                                         }
+                                        DataTypeKind::Array(_) | DataTypeKind::Box(_) => {
+                                            write!(
+                                                f,
+                                                "{indent}\t\tstd::destroy_at(({data_type}*)this->value.data());\n",
+                                                data_type = super::reference::stringify_hpp_data_type(file, data_type)
+                                            )?;
+                                        }
+                                        DataTypeKind::Extern(extern_data_type) => {
+                                            write!(
+                                                f,
+                                                "{indent}\t\tstd::destroy_at(({data_type}*)this->value.data());\n",
+                                                data_type = extern_data_type.extern_options().cpp
+                                            )?;
+                                        }
                                         _ => {}
                                     }
                                 }
@@ -124,41 +141,79 @@ pub fn generate_hpp_variant(
             write!(f, "{indent}}}\n")?;
         }
 
+        // Constructors and assignments
+        generate_hpp_variant_constructors(f, file, indent, data_type, variant)?;
+
         // Getter and setter
         write!(
             f,
-            "{indent}const {}Kind& Kind() const {{ return this->kind; }}\n",
+            "{indent}{}const {}Kind& Kind() const {{ return this->kind; }}\n",
+            super::deprecated::stringify_hpp_deprecated(
+                variant.variant_options().deprecated.as_ref()
+            ),
             variant.identifier().get_pascal_case()
         )?;
         write!(f, "\n")?;
 
         for field in variant.fields() {
-            write!(
-                f,
-                "{indent}const {data_type}& Get{field_pascal}() const {{ return *({data_type}*)this->value.data(); }}\n",
-                field_pascal = field.identifier().get_pascal_case(),
-                data_type = super::reference::stringify_hpp_reference(file, field.reference()),
-            )?;
+            let deprecated = super::deprecated::stringify_hpp_deprecated(
+                field
+                    .field_options()
+                    .deprecated
+                    .as_ref()
+                    .or(variant.variant_options().deprecated.as_ref()),
+            );
 
             write!(
                 f,
-                "{indent}{variant}& Set{field_pascal}(const {data_type}& value) {{ this->~{variant}(); this->kind = {variant}Kind::{field_pascal}; *({data_type}*)this->value.data() = value; return *this; }}\n",
-                variant = variant.identifier(),
+                "{indent}{deprecated}const {data_type}& Get{field_pascal}() const {{ return *({data_type}*)this->value.data(); }}\n",
                 field_pascal = field.identifier().get_pascal_case(),
                 data_type = super::reference::stringify_hpp_reference(file, field.reference()),
             )?;
 
+            let is_move_only = field
+                .reference()
+                .get_id()
+                .and_then(|id| file.data_type(id))
+                .is_some_and(|data_type| super::container::is_move_only(file, data_type));
+
+            if is_move_only {
+                // The previous value is destroyed, the new one is constructed in place
+                write!(
+                    f,
+                    "{indent}{deprecated}{variant}& Set{field_pascal}({data_type} value) {{ this->~{variant}(); this->kind = {variant}Kind::{field_pascal}; new (this->value.data()) {data_type}(std::move(value)); return *this; }}\n",
+                    variant = variant.identifier(),
+                    field_pascal = field.identifier().get_pascal_case(),
+                    data_type = super::reference::stringify_hpp_reference(file, field.reference()),
+                )?;
+            } else {
+                write!(
+                    f,
+                    "{indent}{deprecated}{variant}& Set{field_pascal}(const {data_type}& value) {{ this->~{variant}(); this->kind = {variant}Kind::{field_pascal}; new (this->value.data()) {data_type}(value); return *this; }}\n",
+                    variant = variant.identifier(),
+                    field_pascal = field.identifier().get_pascal_case(),
+                    data_type = super::reference::stringify_hpp_reference(file, field.reference()),
+                )?;
+            }
+
             write!(f, "\n")?;
         }
 
+        // Validation
+        write!(f, "\n")?;
+        super::validate::generate_hpp_variant_validate(f, file, indent, variant)?;
+
         // Message Buffer
+        if variant.variant_options().message_buffer.0 || variant.variant_options().message_buffer.1 {
+            super::message_buffer::generate_hpp_max_size(f, file, indent, data_type)?;
+        }
         if variant.variant_options().message_buffer.0 {
             write!(f, "\n")?;
-            super::message_buffer::generate_hpp_variant_reader(f, file, indent, variant)?;
+            super::message_buffer::generate_hpp_variant_reader(f, file, indent, data_type, variant)?;
         }
         if variant.variant_options().message_buffer.1 {
             write!(f, "\n")?;
-            super::message_buffer::generate_hpp_variant_writer(f, file, indent, variant)?;
+            super::message_buffer::generate_hpp_variant_writer(f, file, indent, data_type, variant)?;
         }
 
         // Json
@@ -178,3 +233,75 @@ pub fn generate_hpp_variant(
 
     Ok(())
 }
+
+/// Generates the copy and move operations, the storage only holds the active case so it
+/// is copied or moved in place like the setters do.
+fn generate_hpp_variant_constructors(
+    f: &mut Formatter<'_>,
+    file: &File,
+    indent: &mut String,
+    data_type: &DataType,
+    variant: &Variant,
+) -> Result {
+    let name = variant.identifier();
+
+    write!(f, "{indent}{name}() = default;\n")?;
+
+    // Boxes can not be copied
+    let is_move_only = super::container::is_move_only(file, data_type);
+
+    for is_move in [false, true] {
+        let parameter = match is_move {
+            true => format!("{name}&&"),
+            false => format!("const {name}&"),
+        };
+
+        if !is_move && is_move_only {
+            write!(f, "{indent}{name}({parameter} other) = delete;\n")?;
+            write!(
+                f,
+                "{indent}{name}& operator=({parameter} other) = delete;\n"
+            )?;
+            continue;
+        }
+
+        write!(f, "{indent}{name}({parameter} other) : kind(other.kind)\n")?;
+        write!(f, "{indent}{{\n")?;
+        write!(f, "{indent}\tswitch(kind)\n")?;
+        write!(f, "{indent}\t{{\n")?;
+        for field in variant.fields() {
+            let data_type = super::reference::stringify_hpp_reference(file, field.reference());
+
+            write!(
+                f,
+                "{indent}\tcase {name}Kind::{}: \n",
+                field.identifier().get_pascal_case()
+            )?;
+            let source = match is_move {
+                true => format!("std::move(*({data_type}*)other.value.data())"),
+                false => format!("*(const {data_type}*)other.value.data()"),
+            };
+
+            write!(
+                f,
+                "{indent}\t\tnew (this->value.data()) {data_type}({source});\n"
+            )?;
+            write!(f, "{indent}\t\tbreak;\n")?;
+        }
+        write!(f, "{indent}\t}}\n")?;
+        write!(f, "{indent}}}\n")?;
+
+        let source = match is_move {
+            true => "std::move(other)",
+            false => "other",
+        };
+
+        write!(
+            f,
+            "{indent}{name}& operator=({parameter} other) {{ if (this != &other) {{ this->~{name}(); new (this) {name}({source}); }} return *this; }}\n"
+        )?;
+    }
+    write!(f, "\n")?;
+
+    Ok(())
+}
@@ -1,3 +1,6 @@
+// Generated code is written with explicit newlines, often several per call
+#![allow(clippy::write_with_newline)]
+
 pub mod cpp;
 
 pub struct Generator {
@@ -6,6 +9,6 @@ pub struct Generator {
 
 impl Generator {
     pub fn from(file: super::ast::File) -> Self {
-        Self { file: file }
+        Self { file }
     }
 }
@@ -0,0 +1,84 @@
+//! Byte offset tables of the wire layout, to review the size of messages.
+
+use std::fmt::{Formatter, Result};
+
+use format::lazy_format;
+
+use super::ast::*;
+
+/// Renders the layout of a structure or variant as a table of its fields.
+pub fn to_table(file: &File, data_type: &DataType) -> String {
+    lazy_format!(|f| generate_table(f, file, data_type)).to_string()
+}
+
+fn generate_table(f: &mut Formatter<'_>, file: &File, data_type: &DataType) -> Result {
+    let layout = data_type.layout();
+
+    // The header of a message buffer holds the size offset
+    let header_size = file.file_options().message_buffer_size_type.size();
+
+    let (keyword, fields) = match data_type.kind() {
+        DataTypeKind::Structure(structure) => ("struct", structure.fields()),
+        DataTypeKind::Variant(variant) => ("variant", variant.fields()),
+        _ => ("type", &[][..]),
+    };
+
+    // Rows of offset, size and field
+    let mut rows = Vec::new();
+    if let DataTypeKind::Variant(_) = data_type.kind() {
+        rows.push((
+            stringify_size(Size::fixed(0)),
+            stringify_size(Size::fixed(2)),
+            "(kind)".to_string(),
+        ));
+    }
+    for (field, field_layout) in fields.iter().zip(layout.fields()) {
+        rows.push((
+            stringify_size(field_layout.offset),
+            stringify_size(field_layout.size),
+            format!("{}: {}", field.identifier(), field.reference().get()),
+        ));
+    }
+
+    let offset_width = rows
+        .iter()
+        .map(|row| row.0.len())
+        .max()
+        .unwrap_or(0)
+        .max("offset".len());
+    let size_width = rows
+        .iter()
+        .map(|row| row.1.len())
+        .max()
+        .unwrap_or(0)
+        .max("size".len());
+
+    writeln!(f, "{keyword} {}", data_type.identifier())?;
+    writeln!(f, "  size: {} bytes", stringify_size(layout.size()))?;
+    writeln!(f, "  fixed prefix: {} bytes", layout.prefix_size())?;
+    writeln!(
+        f,
+        "  message buffer: {} bytes",
+        stringify_size(Size::fixed(header_size).then(layout.size()))
+    )?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "  {:<offset_width$}  {:<size_width$}  field",
+        "offset", "size"
+    )?;
+    for (offset, size, field) in rows {
+        writeln!(f, "  {offset:<offset_width$}  {size:<size_width$}  {field}")?;
+    }
+
+    Ok(())
+}
+
+/// Returns `4` for a fixed size, `2-66` for a bounded size and `2+` for an unbounded size.
+fn stringify_size(size: Size) -> String {
+    match size.max {
+        Some(max) if max == size.min => size.min.to_string(),
+        Some(max) => format!("{}-{max}", size.min),
+        None => format!("{}+", size.min),
+    }
+}
@@ -0,0 +1,57 @@
+/// How a lint is reported.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Allow => f.write_str("allow"),
+            Level::Warn => f.write_str("warn"),
+            Level::Deny => f.write_str("deny"),
+        }
+    }
+}
+
+/// Questionable but legal schema construct that is checked by name.
+///
+/// Lint names are stable, they are used by the command line flags and the
+/// `allow` option.
+pub struct Lint {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub default_level: Level,
+}
+
+pub const DEPRECATED: Lint = Lint {
+    name: "deprecated",
+    description: "use of a deprecated data type",
+    default_level: Level::Warn,
+};
+
+pub const EMPTY_BODY: Lint = Lint {
+    name: "empty_body",
+    description: "structure or variant without fields",
+    default_level: Level::Warn,
+};
+
+pub const NAMING_CONVENTION: Lint = Lint {
+    name: "naming_convention",
+    description: "declaration name that does not follow its naming convention",
+    default_level: Level::Deny,
+};
+
+pub const UNKNOWN_LINTS: Lint = Lint {
+    name: "unknown_lints",
+    description: "unknown lint name in an `allow` option",
+    default_level: Level::Warn,
+};
+
+pub const LINTS: &[Lint] = &[DEPRECATED, EMPTY_BODY, NAMING_CONVENTION, UNKNOWN_LINTS];
+
+pub fn find(name: &str) -> Option<&'static Lint> {
+    LINTS.iter().find(|lint| lint.name == name)
+}
@@ -0,0 +1,270 @@
+//! Machine-readable renderings of diagnostic reports for CI and editor integrations.
+
+use super::diagnostic::{self, Report, Severity};
+use super::error_codes;
+use super::parser::line_index::LineIndex;
+
+/// Renders a report as a single line JSON object.
+pub fn to_json(report: &Report, file_name: &str, source_code: &str) -> String {
+    let source = Source::new(source_code);
+
+    let labels = report
+        .labels
+        .iter()
+        .map(|label| {
+            format!(
+                "{{\"message\":{},\"span\":{}}}",
+                quote(&label.message),
+                json_span(&source, &label.position, &label.selection)
+            )
+        })
+        .collect::<Vec<String>>();
+
+    // Only fixes with a selection are machine-applicable
+    let fixes = report
+        .fixes
+        .iter()
+        .filter(|fix| fix.selection.is_some())
+        .map(|fix| {
+            format!(
+                "{{\"message\":{},\"replacement\":{},\"span\":{}}}",
+                quote(&fix.message),
+                quote(&fix.replacement),
+                json_span(&source, &None, &fix.selection)
+            )
+        })
+        .collect::<Vec<String>>();
+
+    format!(
+        "{{\"severity\":{},\"code\":{},\"lint\":{},\"message\":{},\"file\":{},\"span\":{},\"labels\":[{}],\"fixes\":[{}]}}",
+        quote(&report.severity.to_string()),
+        quote(report.code),
+        report.lint.map_or("null".to_string(), |lint| quote(lint.name)),
+        quote(&report.message),
+        quote(file_name),
+        json_span(&source, &report.position, &report.selection),
+        labels.join(","),
+        fixes.join(",")
+    )
+}
+
+/// Renders all reports of a compilation as a SARIF 2.1.0 log.
+pub fn to_sarif(reports: &[Report], file_name: &str, source_code: &str) -> String {
+    let source = Source::new(source_code);
+
+    let rules = error_codes::ERROR_CODES
+        .iter()
+        .map(|(code, explanation)| {
+            format!(
+                "{{\"id\":{},\"shortDescription\":{{\"text\":{}}},\"fullDescription\":{{\"text\":{}}}}}",
+                quote(code),
+                quote(explanation.lines().next().unwrap_or_default()),
+                quote(explanation)
+            )
+        })
+        .collect::<Vec<String>>();
+
+    let uri = file_name.replace('\\', "/");
+    let results = reports
+        .iter()
+        .map(|report| {
+            let mut result = format!(
+                "{{\"ruleId\":{},\"level\":{},\"message\":{{\"text\":{}}}",
+                quote(report.code),
+                quote(sarif_level(report.severity)),
+                quote(&report.message)
+            );
+
+            if let Some(lint) = report.lint {
+                result += &format!(",\"properties\":{{\"lint\":{}}}", quote(lint.name));
+            }
+
+            if let Some(region) = sarif_region(&source, &report.position, &report.selection) {
+                result += &format!(
+                    ",\"locations\":[{{\"physicalLocation\":{}}}]",
+                    physical_location(&uri, &region)
+                );
+            }
+
+            let related = report
+                .labels
+                .iter()
+                .filter_map(|label| {
+                    sarif_region(&source, &label.position, &label.selection)
+                        .map(|region| (label, region))
+                })
+                .enumerate()
+                .map(|(id, (label, region))| {
+                    format!(
+                        "{{\"id\":{id},\"message\":{{\"text\":{}}},\"physicalLocation\":{}}}",
+                        quote(&label.message),
+                        physical_location(&uri, &region)
+                    )
+                })
+                .collect::<Vec<String>>();
+            if !related.is_empty() {
+                result += &format!(",\"relatedLocations\":[{}]", related.join(","));
+            }
+
+            let fixes = report
+                .fixes
+                .iter()
+                .filter_map(|fix| {
+                    let (start, end) = fix.selection.as_ref()?.span();
+                    Some(format!(
+                        "{{\"description\":{{\"text\":{}}},\"artifactChanges\":[{{\"artifactLocation\":{{\"uri\":{}}},\"replacements\":[{{\"deletedRegion\":{{\"byteOffset\":{start},\"byteLength\":{}}},\"insertedContent\":{{\"text\":{}}}}}]}}]}}",
+                        quote(&fix.message),
+                        quote(&uri),
+                        end - start,
+                        quote(&fix.replacement)
+                    ))
+                })
+                .collect::<Vec<String>>();
+            if !fixes.is_empty() {
+                result += &format!(",\"fixes\":[{}]", fixes.join(","));
+            }
+
+            result + "}"
+        })
+        .collect::<Vec<String>>();
+
+    format!(
+        "{{\"$schema\":\"https://json.schemastore.org/sarif-2.1.0.json\",\"version\":\"2.1.0\",\"runs\":[{{\"tool\":{{\"driver\":{{\"name\":\"pbc\",\"version\":{},\"rules\":[{}]}}}},\"columnKind\":\"unicodeCodePoints\",\"results\":[{}]}}]}}",
+        quote(env!("CARGO_PKG_VERSION")),
+        rules.join(","),
+        results.join(",")
+    )
+}
+
+struct Source<'a> {
+    code: &'a str,
+    line_index: LineIndex,
+}
+
+impl<'a> Source<'a> {
+    fn new(code: &'a str) -> Self {
+        Self {
+            code,
+            line_index: LineIndex::new(code),
+        }
+    }
+
+    /// Returns the one-based line and column of the byte offset.
+    fn line_column(&self, offset: usize) -> (usize, usize) {
+        let (line, column) = self.line_index.line_column(self.code, offset);
+        (line + 1, column + 1)
+    }
+}
+
+/// Byte offsets with their one-based lines and columns.
+struct Span {
+    start: usize,
+    end: usize,
+    line_start: usize,
+    column_start: usize,
+    line_end: usize,
+    column_end: usize,
+}
+
+impl Span {
+    fn from(source: &Source, selection: &diagnostic::Selection) -> Self {
+        let (start, end) = selection.span();
+        let (start, end) = (start.min(source.code.len()), end.min(source.code.len()));
+        let (line_start, column_start) = source.line_column(start);
+        let (line_end, column_end) = source.line_column(end);
+
+        Self {
+            start,
+            end,
+            line_start,
+            column_start,
+            line_end,
+            column_end,
+        }
+    }
+}
+
+/// Reports without a selection only know their start, the byte offsets are null.
+fn json_span(
+    source: &Source,
+    position: &Option<diagnostic::Position>,
+    selection: &Option<diagnostic::Selection>,
+) -> String {
+    match (selection, position) {
+        (Some(selection), _) => {
+            let span = Span::from(source, selection);
+            format!(
+                "{{\"byte_start\":{},\"byte_end\":{},\"line_start\":{},\"column_start\":{},\"line_end\":{},\"column_end\":{}}}",
+                span.start, span.end, span.line_start, span.column_start, span.line_end, span.column_end
+            )
+        }
+        (None, Some(position)) => format!(
+            "{{\"byte_start\":null,\"byte_end\":null,\"line_start\":{},\"column_start\":{},\"line_end\":{},\"column_end\":{}}}",
+            position.line + 1,
+            position.column + 1,
+            position.line + 1,
+            position.column + 1
+        ),
+        (None, None) => "null".to_string(),
+    }
+}
+
+fn sarif_region(
+    source: &Source,
+    position: &Option<diagnostic::Position>,
+    selection: &Option<diagnostic::Selection>,
+) -> Option<String> {
+    match (selection, position) {
+        (Some(selection), _) => {
+            let span = Span::from(source, selection);
+            Some(format!(
+                "{{\"startLine\":{},\"startColumn\":{},\"endLine\":{},\"endColumn\":{},\"byteOffset\":{},\"byteLength\":{}}}",
+                span.line_start,
+                span.column_start,
+                span.line_end,
+                span.column_end,
+                span.start,
+                span.end - span.start
+            ))
+        }
+        (None, Some(position)) => Some(format!(
+            "{{\"startLine\":{},\"startColumn\":{}}}",
+            position.line + 1,
+            position.column + 1
+        )),
+        (None, None) => None,
+    }
+}
+
+fn physical_location(uri: &str, region: &str) -> String {
+    format!(
+        "{{\"artifactLocation\":{{\"uri\":{}}},\"region\":{region}}}",
+        quote(uri)
+    )
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+/// Returns the text as a JSON string literal.
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
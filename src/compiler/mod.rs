@@ -1,25 +1,115 @@
 pub mod ast;
 pub mod diagnostic;
+pub mod error_codes;
 pub mod generator;
+pub mod layout;
+pub mod lint;
+pub mod message_format;
+pub mod naming;
+pub mod options;
 pub mod parser;
 pub mod semantic;
+pub mod suggestion;
+
+use std::collections::HashMap;
 
 pub struct Compiler {
     reports: Vec<diagnostic::Report>,
+
+    // Lint levels overriding the defaults
+    lint_levels: HashMap<&'static str, lint::Level>,
+    deny_warnings: bool,
+
+    // Naming conventions that file options may override
+    naming_conventions: naming::Conventions,
+
+    // Options of the compiler and the generators
+    options: Vec<&'static options::Definition>,
 }
 
 impl Compiler {
     pub fn new() -> Self {
-        Compiler {
+        let mut compiler = Compiler {
             reports: Vec::new(),
+
+            lint_levels: HashMap::new(),
+            deny_warnings: false,
+
+            naming_conventions: naming::Conventions::default(),
+
+            options: Vec::new(),
+        };
+
+        compiler.register_options(options::OPTIONS);
+        compiler.register_options(generator::cpp::OPTIONS);
+
+        compiler
+    }
+
+    pub fn set_naming_convention(&mut self, kind: naming::Kind, case: naming::Case) {
+        self.naming_conventions.set(kind, case);
+    }
+
+    #[inline]
+    pub fn naming_conventions(&self) -> &naming::Conventions {
+        &self.naming_conventions
+    }
+
+    /// Makes options known to the semantic analysis, so that they are not reported as unknown.
+    pub fn register_options(&mut self, options: &'static [options::Definition]) {
+        self.options.extend(options);
+    }
+
+    /// Returns the definition of an option written in the scope.
+    pub fn option_definition(
+        &self,
+        name: &str,
+        scope: options::Scope,
+    ) -> Option<&'static options::Definition> {
+        self.options
+            .iter()
+            .find(|option| option.name == name && option.scopes.contains(&scope))
+            .copied()
+    }
+
+    #[inline]
+    pub fn option_definitions(&self) -> &[&'static options::Definition] {
+        self.options.as_slice()
+    }
+
+    /// Overrides the level of a lint, returns false if there is no lint with that name.
+    pub fn set_lint_level(&mut self, name: &str, level: lint::Level) -> bool {
+        match lint::find(name) {
+            Some(lint) => {
+                self.lint_levels.insert(lint.name, level);
+                true
+            }
+            None => false,
         }
     }
 
+    /// Reports every warning as an error.
+    pub fn set_deny_warnings(&mut self, deny_warnings: bool) {
+        self.deny_warnings = deny_warnings;
+    }
+
+    pub fn lint_level(&self, lint: &lint::Lint) -> lint::Level {
+        self.lint_levels
+            .get(lint.name)
+            .copied()
+            .unwrap_or(lint.default_level)
+    }
+
     #[inline]
     pub fn reports(&self) -> &[diagnostic::Report] {
         self.reports.as_slice()
     }
 
+    /// Returns whether any error was reported, warnings do not abort compilation.
+    pub fn has_errors(&self) -> bool {
+        self.reports.iter().any(|report| report.is_error())
+    }
+
     pub fn compile(&mut self, input: &str) -> Option<ast::File> {
         let mut file = ast::File::new("");
 
@@ -32,30 +122,51 @@ impl Compiler {
         file.add_data_type(ast::DataType::from(ast::DataTypeKind::UInt32));
         file.add_data_type(ast::DataType::from(ast::DataTypeKind::Int64));
         file.add_data_type(ast::DataType::from(ast::DataTypeKind::UInt64));
+        file.add_data_type(ast::DataType::from(ast::DataTypeKind::Int128));
+        file.add_data_type(ast::DataType::from(ast::DataTypeKind::UInt128));
+        file.add_data_type(ast::DataType::from(ast::DataTypeKind::Half));
         file.add_data_type(ast::DataType::from(ast::DataTypeKind::Single));
         file.add_data_type(ast::DataType::from(ast::DataTypeKind::Double));
+        file.add_data_type(ast::DataType::from(ast::DataTypeKind::Decimal(4)));
+        file.add_data_type(ast::DataType::from(ast::DataTypeKind::Char));
 
         file.add_data_type(ast::DataType::from(ast::DataTypeKind::String));
 
         file.add_alias(ast::Alias::from("size".to_string(), "int64".to_string()));
         file.add_alias(ast::Alias::from("usize".to_string(), "uint64".to_string()));
+        file.add_alias(ast::Alias::from(
+            "decimal".to_string(),
+            "decimal<4>".to_string(),
+        ));
 
         // Parse
         parser::Parser::from(input).parse(self, &mut file);
-        if !self.reports.is_empty() {
-            return None
+        if self.has_errors() {
+            return None;
         }
 
         // Semantic
         semantic::Semantic::new().analyse(self, &mut file);
-        if !self.reports.is_empty() {
-            return None
+        if self.has_errors() {
+            return None;
         }
 
         Some(file)
     }
 
-    pub fn diagnose(&mut self, report: diagnostic::Report) {
+    pub fn diagnose(&mut self, mut report: diagnostic::Report) {
+        if let Some(lint) = report.lint {
+            match self.lint_level(lint) {
+                lint::Level::Allow => return,
+                lint::Level::Warn => report.severity = diagnostic::Severity::Warning,
+                lint::Level::Deny => report.severity = diagnostic::Severity::Error,
+            }
+        }
+
+        if self.deny_warnings && report.severity == diagnostic::Severity::Warning {
+            report.severity = diagnostic::Severity::Error;
+        }
+
         self.reports.push(report);
     }
 }
@@ -0,0 +1,179 @@
+//! Naming conventions of the declarations, checked by the `naming_convention` lint.
+
+use convert_case::Casing;
+
+/// Naming convention preset.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    Pascal,
+    Camel,
+    Snake,
+    Screaming,
+}
+
+impl Case {
+    pub const ALL: &'static [Case] = &[Case::Pascal, Case::Camel, Case::Snake, Case::Screaming];
+
+    /// Returns the case by the name it is written in, like `snake_case`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().find(|case| case.name() == name).copied()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Case::Pascal => "PascalCase",
+            Case::Camel => "camelCase",
+            Case::Snake => "snake_case",
+            Case::Screaming => "SCREAMING_CASE",
+        }
+    }
+
+    /// Regular expression matching the names in this case, digits are allowed after the
+    /// first character.
+    pub fn pattern(&self) -> &'static str {
+        match self {
+            Case::Pascal => r"^[A-Z][a-z]*(?:[A-Z][a-z]*|[0-9]+)*$",
+            Case::Camel => r"^[a-z]+(?:[A-Z][a-z]*|[0-9]+)*$",
+            Case::Snake => r"^[a-z][a-z0-9]*(?:_[a-z0-9]+)*$",
+            Case::Screaming => r"^[A-Z][A-Z0-9]*(?:_[A-Z0-9]+)*$",
+        }
+    }
+
+    pub fn convert(&self, name: &str) -> String {
+        name.to_case(match self {
+            Case::Pascal => convert_case::Case::Pascal,
+            Case::Camel => convert_case::Case::Camel,
+            Case::Snake => convert_case::Case::Snake,
+            Case::Screaming => convert_case::Case::ScreamingSnake,
+        })
+    }
+}
+
+impl std::fmt::Display for Case {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Kind of declaration that has a naming convention.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Structure,
+    Variant,
+    Extern,
+    Field,
+    Service,
+    Rpc,
+}
+
+impl Kind {
+    pub const ALL: &'static [Kind] = &[
+        Kind::Structure,
+        Kind::Variant,
+        Kind::Extern,
+        Kind::Field,
+        Kind::Service,
+        Kind::Rpc,
+    ];
+
+    /// Returns the name of the file option and configuration key setting the convention.
+    pub fn option_name(&self) -> &'static str {
+        match self {
+            Kind::Structure => "structure_case",
+            Kind::Variant => "variant_case",
+            Kind::Extern => "extern_case",
+            Kind::Field => "field_case",
+            Kind::Service => "service_case",
+            Kind::Rpc => "rpc_case",
+        }
+    }
+
+    pub fn from_option_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|kind| kind.option_name() == name)
+            .copied()
+    }
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::Structure => f.write_str("Structure"),
+            Kind::Variant => f.write_str("Variant"),
+            Kind::Extern => f.write_str("Extern data type"),
+            Kind::Field => f.write_str("Field"),
+            Kind::Service => f.write_str("Service"),
+            Kind::Rpc => f.write_str("Rpc"),
+        }
+    }
+}
+
+/// Naming convention of every kind of declaration, None if any name is accepted.
+#[derive(Clone, Copy)]
+pub struct Conventions {
+    cases: [Option<Case>; Kind::ALL.len()],
+}
+
+impl Default for Conventions {
+    fn default() -> Self {
+        let mut conventions = Self {
+            cases: [None; Kind::ALL.len()],
+        };
+
+        conventions.set(Kind::Structure, Case::Pascal);
+        conventions.set(Kind::Variant, Case::Pascal);
+        conventions.set(Kind::Field, Case::Snake);
+
+        conventions
+    }
+}
+
+impl Conventions {
+    pub fn get(&self, kind: Kind) -> Option<Case> {
+        self.cases[kind as usize]
+    }
+
+    pub fn set(&mut self, kind: Kind, case: Case) {
+        self.cases[kind as usize] = Some(case);
+    }
+}
+
+/// Reads a configuration file of `key = "value"` lines, `#` starts a comment.
+///
+/// The keys are the names of the file options, like `field_case = "camelCase"`.
+pub fn parse_config(text: &str) -> Result<Vec<(Kind, Case)>, String> {
+    let mut conventions = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!("line {}: expected `key = \"value\"`", index + 1));
+        };
+        let (key, value) = (key.trim(), value.trim().trim_matches('"'));
+
+        let Some(kind) = Kind::from_option_name(key) else {
+            return Err(format!("line {}: unknown key '{}'", index + 1, key));
+        };
+        let Some(case) = Case::from_name(value) else {
+            return Err(format!(
+                "line {}: unknown naming convention '{}', expected one of {}",
+                index + 1,
+                value,
+                Case::ALL
+                    .iter()
+                    .map(|case| case.name())
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ));
+        };
+
+        conventions.push((kind, case));
+    }
+
+    Ok(conventions)
+}
@@ -0,0 +1,219 @@
+//! Options known to the compiler, with the declarations they may be written in and
+//! the arguments they take.
+//!
+//! Generators register their own options next to these, see `Compiler::register_options`.
+
+use super::ast::Value;
+
+/// Kind of declaration an option is written in.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    File,
+    Structure,
+    Variant,
+    Field,
+    Extern,
+}
+
+impl Scope {
+    pub fn plural(&self) -> &'static str {
+        match self {
+            Scope::File => "files",
+            Scope::Structure => "structures",
+            Scope::Variant => "variants",
+            Scope::Field => "fields",
+            Scope::Extern => "extern data types",
+        }
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Scope::File => f.write_str("file"),
+            Scope::Structure => f.write_str("structure"),
+            Scope::Variant => f.write_str("variant"),
+            Scope::Field => f.write_str("field"),
+            Scope::Extern => f.write_str("extern"),
+        }
+    }
+}
+
+/// Type of an option argument.
+#[derive(Clone, Copy)]
+pub enum Type {
+    Boolean,
+    Integer,
+    /// Integer or real
+    Number,
+    String,
+    Either(&'static Type, &'static Type),
+}
+
+impl Type {
+    pub fn accepts(&self, value: &Value) -> bool {
+        match self {
+            Type::Boolean => matches!(value, Value::True | Value::False),
+            Type::Integer => matches!(value, Value::Integer(_)),
+            Type::Number => matches!(value, Value::Integer(_) | Value::Real(_)),
+            Type::String => matches!(value, Value::Literal(_)),
+            Type::Either(a, b) => a.accepts(value) || b.accepts(value),
+        }
+    }
+}
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Type::Boolean => f.write_str("boolean"),
+            Type::Integer => f.write_str("integer"),
+            Type::Number => f.write_str("number"),
+            Type::String => f.write_str("string"),
+            Type::Either(a, b) => write!(f, "{} or {}", a, b),
+        }
+    }
+}
+
+/// Option that is checked by name.
+///
+/// `opt name;` has no arguments, options taking none or a single boolean are enabled
+/// that way.
+pub struct Definition {
+    pub name: &'static str,
+    pub scopes: &'static [Scope],
+    /// Types of the arguments, the last one is repeated by variadic options
+    pub arguments: &'static [Type],
+    pub min_arguments: usize,
+    /// None for variadic options
+    pub max_arguments: Option<usize>,
+}
+
+impl Definition {
+    pub fn argument_type(&self, index: usize) -> Option<&Type> {
+        self.arguments.get(index).or_else(|| self.arguments.last())
+    }
+}
+
+pub const ALLOW: Definition = Definition {
+    name: "allow",
+    scopes: &[
+        Scope::File,
+        Scope::Structure,
+        Scope::Variant,
+        Scope::Field,
+        Scope::Extern,
+    ],
+    arguments: &[Type::String],
+    min_arguments: 1,
+    max_arguments: None,
+};
+
+pub const DEPRECATED: Definition = Definition {
+    name: "deprecated",
+    scopes: &[Scope::Structure, Scope::Variant, Scope::Field],
+    arguments: &[Type::Either(&Type::Boolean, &Type::String)],
+    min_arguments: 0,
+    max_arguments: Some(1),
+};
+
+pub const MESSAGE_BUFFER: Definition = Definition {
+    name: "message_buffer",
+    scopes: &[Scope::File, Scope::Structure, Scope::Variant],
+    arguments: &[Type::Boolean, Type::Boolean],
+    min_arguments: 0,
+    max_arguments: Some(2),
+};
+
+/// Number of bits of the size offset and of the lengths, 16 or 32.
+pub const MESSAGE_BUFFER_SIZE_BITS: Definition = Definition {
+    name: "message_buffer_size_bits",
+    scopes: &[Scope::File],
+    arguments: &[Type::Integer],
+    min_arguments: 1,
+    max_arguments: Some(1),
+};
+
+pub const JSON: Definition = Definition {
+    name: "json",
+    scopes: &[Scope::File, Scope::Structure, Scope::Variant],
+    arguments: &[Type::Boolean, Type::Boolean],
+    min_arguments: 0,
+    max_arguments: Some(2),
+};
+
+pub const VALIDATE: Definition = Definition {
+    name: "validate",
+    scopes: &[Scope::File, Scope::Structure, Scope::Variant],
+    arguments: &[Type::Boolean],
+    min_arguments: 0,
+    max_arguments: Some(1),
+};
+
+pub const RANGE: Definition = Definition {
+    name: "range",
+    scopes: &[Scope::Field],
+    arguments: &[Type::Number, Type::Number],
+    min_arguments: 2,
+    max_arguments: Some(2),
+};
+
+pub const MAX_LEN: Definition = Definition {
+    name: "max_len",
+    scopes: &[Scope::Field],
+    arguments: &[Type::Integer],
+    min_arguments: 1,
+    max_arguments: Some(1),
+};
+
+pub const NON_EMPTY: Definition = Definition {
+    name: "non_empty",
+    scopes: &[Scope::Field],
+    arguments: &[Type::Boolean],
+    min_arguments: 0,
+    max_arguments: Some(1),
+};
+
+pub const PATTERN: Definition = Definition {
+    name: "pattern",
+    scopes: &[Scope::Field],
+    arguments: &[Type::String],
+    min_arguments: 1,
+    max_arguments: Some(1),
+};
+
+pub const STRUCTURE_CASE: Definition = naming_convention("structure_case");
+pub const VARIANT_CASE: Definition = naming_convention("variant_case");
+pub const EXTERN_CASE: Definition = naming_convention("extern_case");
+pub const FIELD_CASE: Definition = naming_convention("field_case");
+pub const SERVICE_CASE: Definition = naming_convention("service_case");
+pub const RPC_CASE: Definition = naming_convention("rpc_case");
+
+/// Naming convention of a kind of declaration, like `opt field_case = "camelCase";`.
+const fn naming_convention(name: &'static str) -> Definition {
+    Definition {
+        name,
+        scopes: &[Scope::File],
+        arguments: &[Type::String],
+        min_arguments: 1,
+        max_arguments: Some(1),
+    }
+}
+
+pub const OPTIONS: &[Definition] = &[
+    ALLOW,
+    DEPRECATED,
+    MESSAGE_BUFFER,
+    MESSAGE_BUFFER_SIZE_BITS,
+    JSON,
+    VALIDATE,
+    RANGE,
+    MAX_LEN,
+    NON_EMPTY,
+    PATTERN,
+    STRUCTURE_CASE,
+    VARIANT_CASE,
+    EXTERN_CASE,
+    FIELD_CASE,
+    SERVICE_CASE,
+    RPC_CASE,
+];
@@ -1,5 +1,7 @@
 use logos::Logos;
 
+use super::line_index::LineIndex;
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub enum LexingError {
     ParseIntegerNumberError,
@@ -20,30 +22,16 @@ impl From<std::num::ParseFloatError> for LexingError {
     }
 }
 
-#[derive(Default)]
-pub struct TokenExtras {
-    pub line: usize,
-}
-
 #[derive(logos::Logos, PartialEq, Copy, Clone)]
 #[logos(error = LexingError)]
-#[logos(extras = TokenExtras)]
-#[logos(skip r"[ \t\f]+")]
+#[logos(skip r"[ \t\f\r]+")]
 pub enum TokenKind {
-    #[token("\n", |lex| {
-        lex.extras.line += 1;
-        logos::Skip
-    })]
+    #[token("\n", logos::skip)]
     LineBreak,
 
-    #[regex("//[^\n]*\n", |lex| {
-        lex.extras.line += 1;
-        logos::Skip
-    })]
+    #[regex("//[^\n]*", logos::skip)]
     Comment,
-    #[regex("/\\*([^*]|\\*[^/])*\\*/", |_| {
-        logos::Skip
-    })]
+    #[regex("/\\*[^*]*\\*+([^/*][^*]*\\*+)*/", logos::skip)]
     MultiLineComment,
 
     #[token("&")]
@@ -115,9 +103,9 @@ pub enum TokenKind {
     Identifier,
     #[regex("[0-9]+", |lex| lex.slice().parse())]
     Integer(i64),
-    #[regex("[0-9]+.[0-9]+", |lex| lex.slice().parse())]
+    #[regex("[0-9]+\\.[0-9]+", |lex| lex.slice().parse())]
     Real(f64),
-    #[regex("\"([^\"\\\\]|\\.)*\"")]
+    #[regex("\"([^\"\\\\]|\\\\.)*\"")]
     Literal,
 
     // Keywords
@@ -135,11 +123,19 @@ pub enum TokenKind {
     VarKeyword,
     #[token("opt")]
     OptKeyword,
+    #[token("reserved")]
+    ReservedKeyword,
+    #[token("service")]
+    ServiceKeyword,
+    #[token("rpc")]
+    RpcKeyword,
+    #[token("extern")]
+    ExternKeyword,
 
     NoToken,
 }
 
-impl<'a> std::fmt::Display for TokenKind {
+impl std::fmt::Display for TokenKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             TokenKind::LineBreak => f.write_str("line break"),
@@ -191,6 +187,10 @@ impl<'a> std::fmt::Display for TokenKind {
             TokenKind::VariantKeyword => f.write_str("variant keyword"),
             TokenKind::VarKeyword => f.write_str("var keyword"),
             TokenKind::OptKeyword => f.write_str("opt keyword"),
+            TokenKind::ReservedKeyword => f.write_str("reserved keyword"),
+            TokenKind::ServiceKeyword => f.write_str("service keyword"),
+            TokenKind::RpcKeyword => f.write_str("rpc keyword"),
+            TokenKind::ExternKeyword => f.write_str("extern keyword"),
 
             TokenKind::NoToken => f.write_str("no token"),
         }
@@ -199,7 +199,9 @@ impl<'a> std::fmt::Display for TokenKind {
 
 #[derive(Copy, Clone)]
 pub struct Token<'a> {
+    // Zero-based line and column of the start of the token
     pub line: usize,
+    pub column: usize,
 
     pub span: (usize, usize),
 
@@ -209,21 +211,36 @@ pub struct Token<'a> {
 
 pub struct Lexer<'a> {
     lexer: logos::Lexer<'a, TokenKind>,
+    line_index: LineIndex,
     current: Token<'a>,
+    previous: Token<'a>,
     peeked: Option<Token<'a>>,
+
+    // Input that could not be lexed, it is skipped and reported by the parser
+    errors: Vec<(Token<'a>, LexingError)>,
 }
 
 impl<'a> Lexer<'a> {
     pub fn new(input: &'a str) -> Self {
         let mut lexer = Self {
             lexer: TokenKind::lexer(input),
+            line_index: LineIndex::new(input),
             current: Token {
                 line: 0,
+                column: 0,
+                span: (0, 0),
+                kind: TokenKind::NoToken,
+                slice: "",
+            },
+            previous: Token {
+                line: 0,
+                column: 0,
                 span: (0, 0),
                 kind: TokenKind::NoToken,
                 slice: "",
             },
             peeked: None,
+            errors: Vec::new(),
         };
 
         lexer.current = lexer.raw_next();
@@ -235,7 +252,13 @@ impl<'a> Lexer<'a> {
         self.current
     }
 
+    /// Returns the last consumed token.
+    pub fn previous(&self) -> Token<'a> {
+        self.previous
+    }
+
     pub fn next(&mut self) -> Token<'a> {
+        self.previous = self.current;
         self.current = match self.peeked.take() {
             Some(token) => token,
             None => self.raw_next(),
@@ -256,29 +279,62 @@ impl<'a> Lexer<'a> {
     }
 
     pub fn consume(&mut self) {
+        self.previous = self.current;
         self.current = match self.peeked.take() {
             Some(token) => token,
             None => self.raw_next(),
         };
     }
 
+    /// Consumes the first `>` of a `>>` token and leaves the second one as the current
+    /// token, so nested generic data types can be closed together.
+    pub fn split_shift_right(&mut self) {
+        let token = self.current;
+        if token.kind != TokenKind::ShiftRight {
+            return;
+        }
+
+        let (first, second) = token.slice.split_at(1);
+        self.previous = Token {
+            span: (token.span.0, token.span.0 + 1),
+            kind: TokenKind::RAngle,
+            slice: first,
+            ..token
+        };
+        self.current = Token {
+            column: token.column + 1,
+            span: (token.span.0 + 1, token.span.1),
+            kind: TokenKind::RAngle,
+            slice: second,
+            ..token
+        };
+    }
+
+    /// Returns the lexing errors since the last call, in source order.
+    pub fn take_errors(&mut self) -> Vec<(Token<'a>, LexingError)> {
+        std::mem::take(&mut self.errors)
+    }
+
     fn raw_next(&mut self) -> Token<'a> {
-        match self.lexer.next() {
-            Some(Ok(kind)) => {
-                let span = self.lexer.span();
-                Token {
-                    line: self.lexer.extras.line,
-                    span: (span.start, span.end),
-                    kind: kind,
-                    slice: self.lexer.slice(),
-                }
-            }
-            _ => Token {
-                line: self.lexer.extras.line,
-                span: (0, 0),
+        // Invalid input is skipped so the rest of the file is still parsed
+        loop {
+            let result = self.lexer.next();
+
+            let span = self.lexer.span();
+            let (line, column) = self.line_index.line_column(self.lexer.source(), span.start);
+            let token = Token {
+                line,
+                column,
+                span: (span.start, span.end),
                 kind: TokenKind::NoToken,
                 slice: self.lexer.slice(),
-            },
+            };
+
+            match result {
+                Some(Ok(kind)) => return Token { kind, ..token },
+                Some(Err(error)) => self.errors.push((token, error)),
+                None => return token,
+            }
         }
     }
 }
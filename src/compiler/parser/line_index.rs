@@ -0,0 +1,40 @@
+/// Byte offsets of the line starts of a text, to map offsets to lines and columns.
+///
+/// Lines end at `\n`, so a `\r` of a CRLF line ending belongs to the end of its line.
+/// Columns count characters, a tab is a single column.
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+
+        Self { line_starts }
+    }
+
+    /// Returns the zero-based line containing the byte offset.
+    pub fn line(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|start| *start <= offset) - 1
+    }
+
+    /// Returns the byte offset of the start of the zero-based line.
+    pub fn line_start(&self, line: usize) -> usize {
+        self.line_starts[line.min(self.line_starts.len() - 1)]
+    }
+
+    /// Returns the zero-based line and column of the byte offset.
+    pub fn line_column(&self, text: &str, offset: usize) -> (usize, usize) {
+        let mut offset = offset.min(text.len());
+        while !text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let line = self.line(offset);
+        let column = text[self.line_start(line)..offset].chars().count();
+
+        (line, column)
+    }
+}
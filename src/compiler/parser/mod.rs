@@ -1,4 +1,7 @@
+use std::ops::RangeInclusive;
+
 pub mod lexer;
+pub mod line_index;
 
 use self::lexer::*;
 
@@ -7,32 +10,49 @@ use super::ast::*;
 pub struct Parser<'a> {
     // Input
     lexer: lexer::Lexer<'a>,
+
+    // Errors are suppressed after the first one until the next declaration starts
+    panicking: bool,
 }
 
 impl<'a> Parser<'a> {
     pub fn from(input: &'a str) -> Self {
         Self {
             lexer: Lexer::new(input),
+            panicking: false,
         }
     }
 
     pub fn parse(mut self, compiler: &mut super::Compiler, file: &mut super::ast::File) {
         loop {
+            self.diagnose_lexing_errors(compiler);
+
             let token = self.lexer.current();
             match token.kind {
                 TokenKind::OptKeyword => self.parse_option(compiler, file),
                 TokenKind::StructKeyword => self.parse_structure(compiler, file),
                 TokenKind::VariantKeyword => self.parse_variant(compiler, file),
+                TokenKind::ServiceKeyword => self.parse_service(compiler, file),
+                TokenKind::ExternKeyword => self.parse_extern(compiler, file),
                 TokenKind::NoToken => break,
                 _ => {
                     self.lexer.consume();
-                    compiler.diagnose(super::diagnostic::err_unexp_token(token));
+                    self.diagnose(
+                        compiler,
+                        super::diagnostic::err_unexp_token(token).with_similar_name(
+                            "a keyword",
+                            similar_keyword(token, super::suggestion::TOP_LEVEL_KEYWORDS),
+                            Some(token.into()),
+                        ),
+                    );
+                    self.synchronize(is_declaration_start);
                 }
             }
         }
     }
 
     fn parse_structure(&mut self, compiler: &mut super::Compiler, file: &mut super::ast::File) {
+        self.panicking = false;
         let mut structure = super::ast::Structure::new();
 
         // Skip struct keyword
@@ -44,22 +64,23 @@ impl<'a> Parser<'a> {
             TokenKind::Identifier => {
                 self.lexer.consume();
                 structure.identifier_mut().set(token.slice.to_string());
+                structure.identifier_mut().set_location(token.into());
             }
-            _ => compiler.diagnose(super::diagnostic::err_miss_identifier(token)),
+            _ => self.diagnose(compiler, super::diagnostic::err_miss_identifier(token)),
         };
 
         // Parse body
         let token = self.lexer.current();
         match token.kind {
             TokenKind::LCurly => {
-                self.parse_body(compiler, &mut structure);
+                self.parse_body(compiler, file, &mut structure);
             }
             TokenKind::LAngle | lexer::TokenKind::LBrack | lexer::TokenKind::LParen => {
-                compiler.diagnose(super::diagnostic::err_exp_body_lcurly(token));
-                self.parse_body(compiler, &mut structure);
+                self.diagnose(compiler, super::diagnostic::err_exp_body_lcurly(token));
+                self.parse_body(compiler, file, &mut structure);
             }
             _ => {
-                compiler.diagnose(super::diagnostic::err_miss_body(token));
+                self.diagnose(compiler, super::diagnostic::err_miss_body(token));
             }
         }
 
@@ -67,6 +88,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_variant(&mut self, compiler: &mut super::Compiler, file: &mut super::ast::File) {
+        self.panicking = false;
         let mut variant = super::ast::Variant::new();
 
         // Skip struct keyword
@@ -78,43 +100,340 @@ impl<'a> Parser<'a> {
             lexer::TokenKind::Identifier => {
                 self.lexer.consume();
                 variant.identifier_mut().set(token.slice.to_string());
+                variant.identifier_mut().set_location(token.into());
             }
             lexer::TokenKind::LCurly | lexer::TokenKind::Semicolon => {
-                compiler.diagnose(super::diagnostic::err_miss_identifier(token));
+                self.diagnose(compiler, super::diagnostic::err_miss_identifier(token));
             }
             lexer::TokenKind::TrueKeyword
             | TokenKind::FalseKeyword
             | TokenKind::OptKeyword
             | TokenKind::VarKeyword
             | TokenKind::StructKeyword
-            | TokenKind::VariantKeyword => {
+            | TokenKind::VariantKeyword
+            | TokenKind::ReservedKeyword
+            | TokenKind::ServiceKeyword
+            | TokenKind::RpcKeyword
+            | TokenKind::ExternKeyword => {
                 self.lexer.consume();
-                compiler.diagnose(super::diagnostic::err_exp_identifier(token));
+                self.diagnose(compiler, super::diagnostic::err_exp_identifier(token));
             }
-            _ => compiler.diagnose(super::diagnostic::err_miss_identifier(token)),
+            _ => self.diagnose(compiler, super::diagnostic::err_miss_identifier(token)),
         };
 
         // Parse body
         let token = self.lexer.current();
         match token.kind {
             lexer::TokenKind::LCurly => {
-                self.parse_body(compiler, &mut variant);
+                self.parse_body(compiler, file, &mut variant);
             }
             lexer::TokenKind::LAngle | lexer::TokenKind::LBrack | lexer::TokenKind::LParen => {
-                compiler.diagnose(super::diagnostic::err_exp_body_lcurly(token));
-                self.parse_body(compiler, &mut variant);
+                self.diagnose(compiler, super::diagnostic::err_exp_body_lcurly(token));
+                self.parse_body(compiler, file, &mut variant);
             }
             _ => {
-                compiler.diagnose(super::diagnostic::err_miss_body(token));
+                self.diagnose(compiler, super::diagnostic::err_miss_body(token));
             }
         }
 
         file.add_variant(variant);
     }
 
-    fn parse_body<T: super::ast::Fielded + super::ast::Optioned>(
+    fn parse_extern(&mut self, compiler: &mut super::Compiler, file: &mut super::ast::File) {
+        self.panicking = false;
+        let mut extern_data_type = super::ast::Extern::new();
+
+        // Skip extern keyword
+        self.lexer.consume();
+
+        // Check type
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::Identifier if token.slice == "type" => {
+                self.lexer.consume();
+            }
+            _ => {
+                self.diagnose(
+                    compiler,
+                    super::diagnostic::err_exp_type(token).with_similar_name(
+                        "a keyword",
+                        similar_keyword(token, &["type"]),
+                        Some(token.into()),
+                    ),
+                );
+            }
+        }
+
+        // Parse name
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::Identifier => {
+                self.lexer.consume();
+                extern_data_type
+                    .identifier_mut()
+                    .set(token.slice.to_string());
+                extern_data_type.identifier_mut().set_location(token.into());
+            }
+            _ => self.diagnose(compiler, super::diagnostic::err_miss_identifier(token)),
+        };
+
+        // Check colon
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::Colon => {
+                self.lexer.consume();
+            }
+            TokenKind::Identifier => {
+                self.diagnose(compiler, super::diagnostic::err_miss_colon(token));
+            }
+            _ => {
+                self.diagnose(compiler, super::diagnostic::err_exp_colon(token));
+            }
+        };
+
+        // Parse underlying type
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::Identifier => {
+                self.lexer.consume();
+                extern_data_type
+                    .reference_mut()
+                    .set(token.slice.to_string());
+                extern_data_type.reference_mut().set_location(token.into());
+            }
+            _ => {
+                self.diagnose(compiler, super::diagnostic::err_exp_extern_data_type(token));
+            }
+        };
+
+        // Parse body
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::LCurly => {
+                self.lexer.consume();
+
+                loop {
+                    let token = self.lexer.current();
+                    match token.kind {
+                        TokenKind::OptKeyword => self.parse_option(compiler, &mut extern_data_type),
+                        // Options can be written without the opt keyword
+                        TokenKind::Identifier => {
+                            self.parse_option_entry(compiler, &mut extern_data_type)
+                        }
+                        TokenKind::Semicolon => {
+                            self.lexer.consume();
+                        }
+                        TokenKind::RAngle
+                        | TokenKind::RBrack
+                        | TokenKind::RParen
+                        | TokenKind::NoToken => {
+                            self.lexer.consume();
+                            self.diagnose(compiler, super::diagnostic::err_exp_body_rcurly(token));
+                            break;
+                        }
+                        TokenKind::RCurly => {
+                            self.lexer.consume();
+                            break;
+                        }
+                        TokenKind::StructKeyword
+                        | TokenKind::VariantKeyword
+                        | TokenKind::ServiceKeyword
+                        | TokenKind::ExternKeyword => {
+                            // The body was not closed, the keyword starts the next declaration
+                            self.diagnose(compiler, super::diagnostic::err_exp_body_rcurly(token));
+                            break;
+                        }
+                        _ => {
+                            self.lexer.consume();
+                            self.diagnose(compiler, super::diagnostic::err_unexp_token(token));
+                            self.synchronize(is_statement_start);
+                        }
+                    }
+                }
+            }
+            _ => {
+                self.diagnose(compiler, super::diagnostic::err_miss_body(token));
+            }
+        }
+
+        file.add_extern(extern_data_type);
+    }
+
+    fn parse_service(&mut self, compiler: &mut super::Compiler, file: &mut super::ast::File) {
+        self.panicking = false;
+        let mut service = super::ast::Service::new();
+
+        // Skip service keyword
+        self.lexer.consume();
+
+        // Parse name
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::Identifier => {
+                self.lexer.consume();
+                service.identifier_mut().set(token.slice.to_string());
+                service.identifier_mut().set_location(token.into());
+            }
+            _ => self.diagnose(compiler, super::diagnostic::err_miss_identifier(token)),
+        };
+
+        // Parse body
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::LCurly => {
+                self.lexer.consume();
+
+                loop {
+                    let token = self.lexer.current();
+                    match token.kind {
+                        TokenKind::RpcKeyword => self.parse_rpc(compiler, &mut service),
+                        TokenKind::Semicolon => {
+                            self.lexer.consume();
+                        }
+                        TokenKind::RAngle
+                        | TokenKind::RBrack
+                        | TokenKind::RParen
+                        | TokenKind::NoToken => {
+                            self.lexer.consume();
+                            self.diagnose(compiler, super::diagnostic::err_exp_body_rcurly(token));
+                            break;
+                        }
+                        TokenKind::RCurly => {
+                            self.lexer.consume();
+                            break;
+                        }
+                        TokenKind::StructKeyword
+                        | TokenKind::VariantKeyword
+                        | TokenKind::ServiceKeyword
+                        | TokenKind::ExternKeyword => {
+                            self.diagnose(compiler, super::diagnostic::err_exp_body_rcurly(token));
+                            break;
+                        }
+                        _ => {
+                            self.lexer.consume();
+                            self.diagnose(compiler, super::diagnostic::err_unexp_token(token));
+                            self.synchronize(is_statement_start);
+                        }
+                    }
+                }
+            }
+            _ => {
+                self.diagnose(compiler, super::diagnostic::err_miss_body(token));
+            }
+        }
+
+        file.add_service(service);
+    }
+
+    fn parse_rpc(&mut self, compiler: &mut super::Compiler, service: &mut super::ast::Service) {
+        self.panicking = false;
+        let mut rpc = super::ast::Rpc::new();
+
+        // Skip rpc keyword
+        self.lexer.consume();
+
+        // Parse name
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::Identifier => {
+                self.lexer.consume();
+                rpc.identifier_mut().set(token.slice.to_string());
+                rpc.identifier_mut().set_location(token.into());
+            }
+            _ => self.diagnose(compiler, super::diagnostic::err_miss_identifier(token)),
+        };
+
+        // Parse request type
+        if let Some((request, location)) = self.parse_rpc_data_type(compiler) {
+            rpc.request_mut().set(request);
+            rpc.request_mut().set_location(location);
+        }
+
+        // Check returns
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::Identifier if token.slice == "returns" => {
+                self.lexer.consume();
+            }
+            _ => {
+                self.diagnose(
+                    compiler,
+                    super::diagnostic::err_exp_returns(token).with_similar_name(
+                        "a keyword",
+                        similar_keyword(token, &["returns"]),
+                        Some(token.into()),
+                    ),
+                );
+            }
+        }
+
+        // Parse response type
+        if let Some((response, location)) = self.parse_rpc_data_type(compiler) {
+            rpc.response_mut().set(response);
+            rpc.response_mut().set_location(location);
+        }
+
+        // Check semicolon
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::Semicolon => {
+                self.lexer.consume();
+            }
+            _ => {
+                self.diagnose(compiler, super::diagnostic::err_exp_semicolon(token));
+                self.synchronize(is_statement_start);
+            }
+        };
+
+        service.add_rpc(rpc);
+    }
+
+    /// Parses a parenthesized data type like `(JoinRequest)`.
+    fn parse_rpc_data_type(
         &mut self,
         compiler: &mut super::Compiler,
+    ) -> Option<(String, Location)> {
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::LParen => {
+                self.lexer.consume();
+            }
+            _ => {
+                self.diagnose(compiler, super::diagnostic::err_exp_lparen(token));
+                return None;
+            }
+        }
+
+        let mut data_type = None;
+
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::Identifier => {
+                self.lexer.consume();
+                data_type = Some((token.slice.to_string(), token.into()));
+            }
+            _ => {
+                self.diagnose(compiler, super::diagnostic::err_exp_rpc_data_type(token));
+            }
+        }
+
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::RParen => {
+                self.lexer.consume();
+            }
+            _ => {
+                self.diagnose(compiler, super::diagnostic::err_exp_rparen(token));
+            }
+        }
+
+        data_type
+    }
+
+    fn parse_body<T: super::ast::Fielded + super::ast::Optioned + super::ast::Reserved>(
+        &mut self,
+        compiler: &mut super::Compiler,
+        file: &mut super::ast::File,
         output: &mut T,
     ) {
         // Skip {
@@ -123,8 +442,9 @@ impl<'a> Parser<'a> {
         loop {
             let token = self.lexer.current();
             match token.kind {
-                lexer::TokenKind::VarKeyword => self.parse_field(compiler, output),
+                lexer::TokenKind::VarKeyword => self.parse_field(compiler, file, output),
                 lexer::TokenKind::OptKeyword => self.parse_option(compiler, output),
+                lexer::TokenKind::ReservedKeyword => self.parse_reserved(compiler, output),
                 lexer::TokenKind::Semicolon => {
                     self.lexer.consume();
                 }
@@ -133,16 +453,31 @@ impl<'a> Parser<'a> {
                 | lexer::TokenKind::RParen
                 | lexer::TokenKind::NoToken => {
                     self.lexer.consume();
-                    compiler.diagnose(super::diagnostic::err_exp_body_rcurly(token));
+                    self.diagnose(compiler, super::diagnostic::err_exp_body_rcurly(token));
                     break;
                 }
                 lexer::TokenKind::RCurly => {
                     self.lexer.consume();
                     break;
                 }
+                TokenKind::StructKeyword
+                | TokenKind::VariantKeyword
+                | TokenKind::ServiceKeyword
+                | TokenKind::ExternKeyword => {
+                    self.diagnose(compiler, super::diagnostic::err_exp_body_rcurly(token));
+                    break;
+                }
                 _ => {
                     self.lexer.consume();
-                    compiler.diagnose(super::diagnostic::err_unexp_token(token));
+                    self.diagnose(
+                        compiler,
+                        super::diagnostic::err_unexp_token(token).with_similar_name(
+                            "a keyword",
+                            similar_keyword(token, super::suggestion::BODY_KEYWORDS),
+                            Some(token.into()),
+                        ),
+                    );
+                    self.synchronize(is_statement_start);
                 }
             }
         }
@@ -151,11 +486,14 @@ impl<'a> Parser<'a> {
     fn parse_field(
         &mut self,
         compiler: &mut super::Compiler,
+        file: &mut super::ast::File,
         output: &mut dyn super::ast::Fielded,
     ) {
+        self.panicking = false;
         let mut field = super::ast::Field::new();
 
         // Skip var keyword
+        let start = Location::from(self.lexer.current());
         self.lexer.consume();
 
         // Parse name
@@ -164,21 +502,26 @@ impl<'a> Parser<'a> {
             lexer::TokenKind::Identifier => {
                 self.lexer.consume();
                 field.identifier_mut().set(token.slice.to_string());
+                field.identifier_mut().set_location(token.into());
             }
             lexer::TokenKind::Colon | lexer::TokenKind::Eq => {
-                compiler.diagnose(super::diagnostic::err_miss_identifier(token));
+                self.diagnose(compiler, super::diagnostic::err_miss_identifier(token));
             }
             lexer::TokenKind::TrueKeyword
             | TokenKind::FalseKeyword
             | TokenKind::OptKeyword
             | TokenKind::VarKeyword
             | TokenKind::StructKeyword
-            | TokenKind::VariantKeyword => {
+            | TokenKind::VariantKeyword
+            | TokenKind::ReservedKeyword
+            | TokenKind::ServiceKeyword
+            | TokenKind::RpcKeyword
+            | TokenKind::ExternKeyword => {
                 self.lexer.consume();
-                compiler.diagnose(super::diagnostic::err_exp_identifier(token));
+                self.diagnose(compiler, super::diagnostic::err_exp_identifier(token));
             }
             _ => {
-                compiler.diagnose(super::diagnostic::err_exp_identifier(token));
+                self.diagnose(compiler, super::diagnostic::err_exp_identifier(token));
             }
         };
 
@@ -189,27 +532,294 @@ impl<'a> Parser<'a> {
                 self.lexer.consume();
             }
             lexer::TokenKind::Identifier => {
-                compiler.diagnose(super::diagnostic::err_miss_colon(token));
+                self.diagnose(compiler, super::diagnostic::err_miss_colon(token));
             }
             _ => {
-                compiler.diagnose(super::diagnostic::err_exp_colon(token));
+                self.diagnose(compiler, super::diagnostic::err_exp_colon(token));
             }
         };
 
         // Parse type
+        let type_start = Location::from(self.lexer.current());
+        let Some(reference) = self.parse_field_data_type(compiler, file) else {
+            field.set_location(start.to(self.lexer.previous().into()));
+            self.synchronize(is_statement_start);
+            output.add_field(field);
+            return;
+        };
+        field.reference_mut().set(reference);
+        field
+            .reference_mut()
+            .set_location(type_start.to(self.lexer.previous().into()));
+        field.set_location(start.to(self.lexer.previous().into()));
+
+        // Check semicolon or parse field body
+        let token = self.lexer.current();
+        match token.kind {
+            lexer::TokenKind::Semicolon => {
+                self.lexer.consume();
+            }
+            lexer::TokenKind::LCurly => {
+                self.parse_field_body(compiler, &mut field);
+            }
+            _ => {
+                self.diagnose(compiler, super::diagnostic::err_exp_semicolon(token));
+                self.synchronize(is_statement_start);
+            }
+        };
+
+        output.add_field(field);
+    }
+
+    fn parse_field_data_type(
+        &mut self,
+        compiler: &mut super::Compiler,
+        file: &mut super::ast::File,
+    ) -> Option<String> {
         let token = self.lexer.current();
         match token.kind {
             lexer::TokenKind::Identifier => {
                 self.lexer.consume();
-                field.reference_mut().set(token.slice.to_string());
+
+                // Parse bounded string
+                if token.slice == "string" && self.lexer.current().kind == TokenKind::LAngle {
+                    self.lexer.consume();
+
+                    let max_len = self.parse_bound(compiler, 1..=u32::MAX as i64);
+
+                    self.parse_rangle(compiler)?;
+
+                    return Some(file.add_bounded_string(max_len?));
+                }
+
+                // Parse decimal
+                if token.slice == "decimal" && self.lexer.current().kind == TokenKind::LAngle {
+                    self.lexer.consume();
+
+                    let scale = self.parse_bound(compiler, 0..=18);
+
+                    self.parse_rangle(compiler)?;
+
+                    return Some(file.add_decimal(scale? as u8));
+                }
+
+                // Parse box
+                if token.slice == "box" && self.lexer.current().kind == TokenKind::LAngle {
+                    self.lexer.consume();
+
+                    let element_start = Location::from(self.lexer.current());
+                    let reference = self.parse_field_data_type(compiler, file);
+                    let element_location = element_start.to(self.lexer.previous().into());
+
+                    self.parse_rangle(compiler)?;
+
+                    // Only the first use of a box type is located
+                    let mut boxed = super::ast::Boxed::from(reference?);
+                    boxed.reference_mut().set_location(element_location);
+                    return Some(file.add_box(boxed));
+                }
+
+                Some(token.slice.to_string())
+            }
+            lexer::TokenKind::LBrack => {
+                self.lexer.consume();
+
+                let element_start = Location::from(self.lexer.current());
+                let reference = self.parse_field_data_type(compiler, file);
+                let element_location = element_start.to(self.lexer.previous().into());
+
+                // Parse optional maximum length
+                let mut max_len = Some(None);
+
+                let token = self.lexer.current();
+                if token.kind == TokenKind::Semicolon {
+                    self.lexer.consume();
+
+                    let token = self.lexer.current();
+                    match token.kind {
+                        TokenKind::Identifier if token.slice == "max" => {
+                            self.lexer.consume();
+                        }
+                        _ => {
+                            self.diagnose(compiler, super::diagnostic::err_exp_max(token));
+                            return None;
+                        }
+                    }
+
+                    max_len = self.parse_bound(compiler, 1..=u32::MAX as i64).map(Some);
+                }
+
+                let token = self.lexer.current();
+                match token.kind {
+                    TokenKind::RBrack => {
+                        self.lexer.consume();
+                    }
+                    _ => {
+                        self.diagnose(compiler, super::diagnostic::err_exp_rbrack(token));
+                        return None;
+                    }
+                }
+
+                // Only the first use of an array type is located
+                let mut array = super::ast::Array::from(reference?, max_len?);
+                array.reference_mut().set_location(element_location);
+                Some(file.add_array(array))
             }
             lexer::TokenKind::Semicolon => {
-                compiler.diagnose(super::diagnostic::err_miss_field_type(token));
+                self.diagnose(compiler, super::diagnostic::err_miss_field_type(token));
+                None
             }
             _ => {
-                compiler.diagnose(super::diagnostic::err_exp_field_type(token));
+                self.diagnose(compiler, super::diagnostic::err_exp_field_type(token));
+                None
             }
-        };
+        }
+    }
+
+    /// Parses the `>` that closes a generic data type, a `>>` closes two nested ones.
+    fn parse_rangle(&mut self, compiler: &mut super::Compiler) -> Option<()> {
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::RAngle => {
+                self.lexer.consume();
+                Some(())
+            }
+            TokenKind::ShiftRight => {
+                self.lexer.split_shift_right();
+                Some(())
+            }
+            _ => {
+                self.diagnose(compiler, super::diagnostic::err_exp_rangle(token));
+                None
+            }
+        }
+    }
+
+    fn parse_bound(
+        &mut self,
+        compiler: &mut super::Compiler,
+        range: RangeInclusive<i64>,
+    ) -> Option<usize> {
+        let token = self.lexer.current();
+        match token.kind {
+            TokenKind::Integer(bound) if range.contains(&bound) => {
+                self.lexer.consume();
+                Some(bound as usize)
+            }
+            TokenKind::Integer(_) => {
+                self.lexer.consume();
+                self.diagnose(
+                    compiler,
+                    super::diagnostic::err_exp_bound(token, *range.start(), *range.end()),
+                );
+                None
+            }
+            _ => {
+                self.diagnose(
+                    compiler,
+                    super::diagnostic::err_exp_bound(token, *range.start(), *range.end()),
+                );
+                None
+            }
+        }
+    }
+
+    fn parse_field_body(&mut self, compiler: &mut super::Compiler, field: &mut Field) {
+        // Skip {
+        self.lexer.consume();
+
+        loop {
+            let token = self.lexer.current();
+            match token.kind {
+                lexer::TokenKind::OptKeyword => self.parse_option(compiler, field),
+                lexer::TokenKind::Semicolon => {
+                    self.lexer.consume();
+                }
+                lexer::TokenKind::RCurly => {
+                    self.lexer.consume();
+                    break;
+                }
+                // The field body was not closed, the token starts the next entry
+                kind if kind == lexer::TokenKind::NoToken || is_statement_start(kind) => {
+                    self.diagnose(compiler, super::diagnostic::err_exp_body_rcurly(token));
+                    break;
+                }
+                _ => {
+                    self.lexer.consume();
+                    self.diagnose(compiler, super::diagnostic::err_unexp_token(token));
+                    self.synchronize(is_statement_start);
+                }
+            }
+        }
+    }
+
+    fn parse_reserved(
+        &mut self,
+        compiler: &mut super::Compiler,
+        output: &mut dyn super::ast::Reserved,
+    ) {
+        self.panicking = false;
+
+        // Skip reserved keyword
+        self.lexer.consume();
+
+        loop {
+            // Parse entry
+            let token = self.lexer.current();
+            match token.kind {
+                TokenKind::Integer(start) => {
+                    self.lexer.consume();
+
+                    let mut end = (start, Location::from(token));
+
+                    // Parse range end
+                    let to_token = self.lexer.current();
+                    if to_token.kind == TokenKind::Identifier && to_token.slice == "to" {
+                        self.lexer.consume();
+
+                        let token = self.lexer.current();
+                        match token.kind {
+                            TokenKind::Integer(integer) => {
+                                self.lexer.consume();
+                                end = (integer, token.into());
+                            }
+                            _ => {
+                                self.diagnose(
+                                    compiler,
+                                    super::diagnostic::err_exp_reserved_range_end(token),
+                                );
+                            }
+                        }
+                    }
+
+                    output.add_reservation(Reservation::new(
+                        ReservationKind::Range(start, end.0),
+                        Location::from(token).to(end.1),
+                    ));
+                }
+                TokenKind::Literal => {
+                    self.lexer.consume();
+
+                    let slice = token.slice;
+                    output.add_reservation(Reservation::new(
+                        ReservationKind::Name(slice[1..slice.len() - 1].to_string()),
+                        token.into(),
+                    ));
+                }
+                _ => {
+                    self.diagnose(compiler, super::diagnostic::err_exp_reserved_entry(token));
+                    break;
+                }
+            }
+
+            let token = self.lexer.current();
+            match token.kind {
+                TokenKind::Comma => {
+                    self.lexer.consume();
+                }
+                _ => break,
+            }
+        }
 
         // Check semicolon
         let token = self.lexer.current();
@@ -218,21 +828,26 @@ impl<'a> Parser<'a> {
                 self.lexer.consume();
             }
             _ => {
-                compiler.diagnose(super::diagnostic::err_exp_semicolon(token));
+                self.diagnose(compiler, super::diagnostic::err_exp_semicolon(token));
+                self.synchronize(is_statement_start);
             }
         };
-
-        output.add_field(field);
     }
 
     fn parse_option(&mut self, compiler: &mut super::Compiler, output: &mut dyn Optioned) {
-        let mut name = None;
-        let mut arguments = Vec::new();
-
         // Skip opt keyword
         self.lexer.consume();
 
+        self.parse_option_entry(compiler, output);
+    }
+
+    fn parse_option_entry(&mut self, compiler: &mut super::Compiler, output: &mut dyn Optioned) {
+        self.panicking = false;
+        let mut name = None;
+        let mut arguments = Vec::new();
+
         // Parse name
+        let start = Location::from(self.lexer.current());
         let token = self.lexer.current();
         match token.kind {
             lexer::TokenKind::Identifier => {
@@ -240,19 +855,23 @@ impl<'a> Parser<'a> {
                 name = Some(token.slice.to_string());
             }
             lexer::TokenKind::Colon | lexer::TokenKind::Eq => {
-                compiler.diagnose(super::diagnostic::err_miss_identifier(token));
+                self.diagnose(compiler, super::diagnostic::err_miss_identifier(token));
             }
             lexer::TokenKind::TrueKeyword
             | TokenKind::FalseKeyword
             | TokenKind::OptKeyword
             | TokenKind::VarKeyword
             | TokenKind::StructKeyword
-            | TokenKind::VariantKeyword => {
+            | TokenKind::VariantKeyword
+            | TokenKind::ReservedKeyword
+            | TokenKind::ServiceKeyword
+            | TokenKind::RpcKeyword
+            | TokenKind::ExternKeyword => {
                 self.lexer.consume();
-                compiler.diagnose(super::diagnostic::err_exp_identifier(token));
+                self.diagnose(compiler, super::diagnostic::err_exp_identifier(token));
             }
             _ => {
-                compiler.diagnose(super::diagnostic::err_exp_identifier(token));
+                self.diagnose(compiler, super::diagnostic::err_exp_identifier(token));
             }
         };
 
@@ -285,13 +904,15 @@ impl<'a> Parser<'a> {
                             break;
                         }
                         _ => {
-                            compiler.diagnose(super::diagnostic::err_exp_rparen(token));
+                            self.diagnose(compiler, super::diagnostic::err_exp_rparen(token));
                             break;
                         }
                     }
                 }
             }
             TokenKind::Eq => {
+                self.lexer.consume();
+
                 arguments.push(
                     self.parse_expression(compiler, u8::MAX, |t| t == lexer::TokenKind::Semicolon),
                 );
@@ -305,10 +926,10 @@ impl<'a> Parser<'a> {
             | lexer::TokenKind::Real(_)
             | lexer::TokenKind::Literal
             | lexer::TokenKind::Identifier => {
-                compiler.diagnose(super::diagnostic::err_miss_equal(token));
+                self.diagnose(compiler, super::diagnostic::err_miss_equal(token));
             }
             _ => {
-                compiler.diagnose(super::diagnostic::err_exp_equal(token));
+                self.diagnose(compiler, super::diagnostic::err_exp_equal(token));
             }
         }
 
@@ -319,12 +940,20 @@ impl<'a> Parser<'a> {
                 self.lexer.consume();
             }
             _ => {
-                compiler.diagnose(super::diagnostic::err_exp_semicolon(token));
+                self.diagnose(compiler, super::diagnostic::err_exp_semicolon(token));
+                self.synchronize(is_statement_start);
             }
         };
 
         if let Some(name) = name {
-            output.add_option(name, arguments);
+            let location = start.to(self.lexer.previous().into());
+            if !output.add_option(name.clone(), arguments, location) {
+                compiler.diagnose(super::diagnostic::err_redefined_option(
+                    &name,
+                    location,
+                    output.option_location(&name),
+                ));
+            }
         }
     }
 
@@ -369,7 +998,7 @@ impl<'a> Parser<'a> {
                                 break;
                             }
                             _ => {
-                                compiler.diagnose(super::diagnostic::err_exp_rparen(token));
+                                self.diagnose(compiler, super::diagnostic::err_exp_rparen(token));
                                 break;
                             }
                         }
@@ -404,13 +1033,19 @@ impl<'a> Parser<'a> {
             TokenKind::Literal => {
                 self.lexer.consume();
                 let slice = token.slice;
-                super::ast::Expression::Value(super::ast::Value::Literal(slice[1..slice.len()-1].to_string()))
+                super::ast::Expression::Value(super::ast::Value::Literal(
+                    slice[1..slice.len() - 1].to_string(),
+                ))
             }
 
             TokenKind::Minus => {
                 self.lexer.consume();
                 let expression = self.parse_expression(compiler, 1, is_end);
-                Expression::UnaryOperator(UnaryOperator::Negation, Box::new(expression))
+                Expression::UnaryOperator(
+                    UnaryOperator::Negation,
+                    token.into(),
+                    Box::new(expression),
+                )
             }
             TokenKind::Plus => {
                 self.lexer.consume();
@@ -419,7 +1054,11 @@ impl<'a> Parser<'a> {
             TokenKind::Bang => {
                 self.lexer.consume();
                 let expression = self.parse_expression(compiler, 1, is_end);
-                Expression::UnaryOperator(UnaryOperator::LogicalNot, Box::new(expression))
+                Expression::UnaryOperator(
+                    UnaryOperator::LogicalNot,
+                    token.into(),
+                    Box::new(expression),
+                )
             }
             _ => super::ast::Expression::Value(super::ast::Value::Null),
         };
@@ -446,7 +1085,7 @@ impl<'a> Parser<'a> {
                 TokenKind::Amp2 => (BinaryOperator::LogicalAnd, 6),
                 TokenKind::Pipe2 => (BinaryOperator::LogicalOr, 7),
                 _ => {
-                    compiler.diagnose(super::diagnostic::err_exp_binary_operator(token));
+                    self.diagnose(compiler, super::diagnostic::err_exp_binary_operator(token));
                     break;
                 }
             };
@@ -462,10 +1101,93 @@ impl<'a> Parser<'a> {
             expression = Expression::BinaryOperator(
                 Box::new(expression),
                 operator.0,
+                token.into(),
                 Box::new(right_expression),
             );
         }
 
         expression
     }
+
+    /// Reports a syntax error unless the parser is still recovering from a previous one.
+    fn diagnose(&mut self, compiler: &mut super::Compiler, report: super::diagnostic::Report) {
+        // Skipped input before the error is likely its cause
+        self.diagnose_lexing_errors(compiler);
+
+        if !self.panicking {
+            compiler.diagnose(report);
+        }
+        self.panicking = true;
+    }
+
+    /// Reports the input that the lexer skipped, every one of them since the parser does
+    /// not see them, then recovers like from a syntax error.
+    fn diagnose_lexing_errors(&mut self, compiler: &mut super::Compiler) {
+        for (token, error) in self.lexer.take_errors() {
+            compiler.diagnose(match error {
+                LexingError::Error if token.slice.starts_with('"') => {
+                    super::diagnostic::err_unterminated_literal(token)
+                }
+                LexingError::Error => super::diagnostic::err_invalid_character(token),
+                LexingError::ParseIntegerNumberError | LexingError::ParseRealNumberError => {
+                    super::diagnostic::err_number_out_of_range(token)
+                }
+            });
+            self.panicking = true;
+        }
+    }
+
+    /// Skips tokens until one that satisfies `is_start` outside of nested braces, or a
+    /// semicolon that ends the broken statement.
+    fn synchronize(&mut self, is_start: fn(TokenKind) -> bool) {
+        let mut depth = 0usize;
+
+        loop {
+            let token = self.lexer.current();
+            match token.kind {
+                TokenKind::NoToken => break,
+                kind if depth == 0 && is_start(kind) => break,
+                TokenKind::Semicolon if depth == 0 => {
+                    self.lexer.consume();
+                    break;
+                }
+                TokenKind::LCurly => depth += 1,
+                TokenKind::RCurly => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            self.lexer.consume();
+        }
+    }
+}
+
+/// Returns whether the token starts a declaration at the top level of a file.
+fn is_declaration_start(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::OptKeyword
+            | TokenKind::StructKeyword
+            | TokenKind::VariantKeyword
+            | TokenKind::ServiceKeyword
+            | TokenKind::ExternKeyword
+    )
+}
+
+/// Returns whether the token starts or closes an entry of a body, or starts a new
+/// declaration because the body was not closed.
+fn is_statement_start(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::VarKeyword
+            | TokenKind::ReservedKeyword
+            | TokenKind::RpcKeyword
+            | TokenKind::RCurly
+    ) || is_declaration_start(kind)
+}
+
+/// Returns the keyword that a misspelled identifier token was probably meant to be.
+fn similar_keyword(token: Token, keywords: &[&'static str]) -> Option<&'static str> {
+    match token.kind {
+        TokenKind::Identifier => super::suggestion::similar(token.slice, keywords.iter().copied()),
+        _ => None,
+    }
 }
@@ -0,0 +1,302 @@
+use super::super::ast::*;
+
+use regex::Regex;
+use regex_syntax::ast::{self, Ast, ClassSetBinaryOp, ClassSetItem, Visitor};
+
+/// Kind of value a constraint can be applied to.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    Integer,
+    Real,
+    String,
+    Other,
+}
+
+impl ValueKind {
+    fn from(data_type: &DataType) -> Self {
+        match data_type.kind() {
+            DataTypeKind::Int8
+            | DataTypeKind::UInt8
+            | DataTypeKind::Int16
+            | DataTypeKind::UInt16
+            | DataTypeKind::Int32
+            | DataTypeKind::UInt32
+            | DataTypeKind::Int64
+            | DataTypeKind::UInt64
+            | DataTypeKind::Int128
+            | DataTypeKind::UInt128 => ValueKind::Integer,
+            DataTypeKind::Half | DataTypeKind::Single | DataTypeKind::Double => ValueKind::Real,
+            DataTypeKind::String | DataTypeKind::BoundedString(_) => ValueKind::String,
+            _ => ValueKind::Other,
+        }
+    }
+}
+
+impl super::Semantic {
+    pub(super) fn analyze_constraints(
+        &mut self,
+        compiler: &mut super::super::Compiler,
+        file: &mut File,
+    ) {
+        let value_kinds = file
+            .data_types()
+            .iter()
+            .map(ValueKind::from)
+            .collect::<Vec<ValueKind>>();
+
+        for data_type in file.data_types_mut() {
+            let fields = match data_type.kind_mut() {
+                DataTypeKind::Structure(structure) => structure.fields_mut(),
+                DataTypeKind::Variant(variant) => variant.fields_mut(),
+                _ => continue,
+            };
+
+            for field in fields {
+                let value_kind = field
+                    .reference()
+                    .get_id()
+                    .and_then(|id| value_kinds.get(id))
+                    .copied()
+                    .unwrap_or(ValueKind::Other);
+
+                Self::analyze_field_constraints(compiler, field, value_kind);
+            }
+        }
+    }
+
+    fn analyze_field_constraints(
+        compiler: &mut super::super::Compiler,
+        field: &mut Field,
+        value_kind: ValueKind,
+    ) {
+        // range(min, max)
+        if let Some(opt) = field.option("range") {
+            if value_kind != ValueKind::Integer && value_kind != ValueKind::Real {
+                compiler.diagnose(super::super::diagnostic::err_invalid_constraint_data_type(
+                    "range", field,
+                ));
+            } else {
+                match opt.as_slice() {
+                    [Expression::Value(min), Expression::Value(max)] => {
+                        let bounds = match (min, max) {
+                            (Value::Integer(min), Value::Integer(max)) => {
+                                Some((*min as f64, *max as f64))
+                            }
+                            (Value::Integer(min), Value::Real(max)) => Some((*min as f64, *max)),
+                            (Value::Real(min), Value::Integer(max)) => Some((*min, *max as f64)),
+                            (Value::Real(min), Value::Real(max)) => Some((*min, *max)),
+                            _ => None,
+                        };
+
+                        match bounds {
+                            Some((min_bound, max_bound)) if min_bound <= max_bound => {
+                                field.field_options_mut().range = Some((min.clone(), max.clone()));
+                            }
+                            Some(_) => {
+                                compiler.diagnose(
+                                    super::super::diagnostic::err_invalid_constraint_range(field),
+                                );
+                            }
+                            None => {
+                                compiler.diagnose(
+                                    super::super::diagnostic::err_invalid_constraint_arguments(
+                                        "range", field,
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                    _ => {
+                        compiler.diagnose(
+                            super::super::diagnostic::err_invalid_constraint_arguments(
+                                "range", field,
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        // max_len(length)
+        if let Some(opt) = field.option("max_len") {
+            if value_kind != ValueKind::String {
+                compiler.diagnose(super::super::diagnostic::err_invalid_constraint_data_type(
+                    "max_len", field,
+                ));
+            } else {
+                match opt.as_slice() {
+                    [Expression::Value(Value::Integer(length))] if *length >= 0 => {
+                        field.field_options_mut().max_len = Some(*length as u64);
+                    }
+                    _ => {
+                        compiler.diagnose(
+                            super::super::diagnostic::err_invalid_constraint_arguments(
+                                "max_len", field,
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        // non_empty
+        if let Some(opt) = field.option("non_empty") {
+            if value_kind != ValueKind::String {
+                compiler.diagnose(super::super::diagnostic::err_invalid_constraint_data_type(
+                    "non_empty",
+                    field,
+                ));
+            } else {
+                match opt.as_slice() {
+                    [Expression::Value(value @ (Value::Null | Value::True | Value::False))] => {
+                        field.field_options_mut().non_empty = value.is_true();
+                    }
+                    _ => {
+                        compiler.diagnose(
+                            super::super::diagnostic::err_invalid_constraint_arguments(
+                                "non_empty",
+                                field,
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        // pattern("regex")
+        if let Some(opt) = field.option("pattern") {
+            if value_kind != ValueKind::String {
+                compiler.diagnose(super::super::diagnostic::err_invalid_constraint_data_type(
+                    "pattern", field,
+                ));
+            } else {
+                match opt.as_slice() {
+                    [Expression::Value(Value::Literal(pattern))] => {
+                        // Literals keep their escape sequences, which are reused by the generators
+                        let unescaped = unescape_literal(pattern);
+
+                        // The pattern has to compile here and in the generated code
+                        let result = match Regex::new(&unescaped) {
+                            Ok(_) => check_ecmascript(&unescaped).map_err(str::to_string),
+                            Err(error) => {
+                                // Only keep the description of multi-line syntax errors
+                                let error = error.to_string();
+                                Err(error
+                                    .lines()
+                                    .last()
+                                    .unwrap_or_default()
+                                    .trim_start_matches("error: ")
+                                    .to_string())
+                            }
+                        };
+
+                        match result {
+                            Ok(()) => {
+                                field.field_options_mut().pattern = Some(pattern.clone());
+                            }
+                            Err(error) => {
+                                compiler.diagnose(
+                                    super::super::diagnostic::err_invalid_constraint_pattern(
+                                        field, &error,
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                    _ => {
+                        compiler.diagnose(
+                            super::super::diagnostic::err_invalid_constraint_arguments(
+                                "pattern", field,
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checks that a pattern only uses the syntax shared with the ECMAScript grammar of
+/// `std::regex`, which runs it in the generated code.
+fn check_ecmascript(pattern: &str) -> Result<(), &'static str> {
+    let ast = ast::parse::Parser::new()
+        .parse(pattern)
+        .map_err(|_| "unsupported syntax")?;
+
+    ast::visit(&ast, EcmaScriptSubset)
+}
+
+/// Rejects the constructs of the `regex` crate that `std::regex` does not know.
+struct EcmaScriptSubset;
+
+impl EcmaScriptSubset {
+    fn check_literal(literal: &ast::Literal) -> Result<(), &'static str> {
+        match literal.kind {
+            ast::LiteralKind::HexFixed(ast::HexLiteralKind::UnicodeLong) => {
+                Err("'\\U' escapes are not supported by std::regex")
+            }
+            ast::LiteralKind::HexBrace(_) => Err("braced escapes are not supported by std::regex"),
+            ast::LiteralKind::Special(ast::SpecialLiteralKind::Bell) => {
+                Err("'\\a' escapes are not supported by std::regex")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Visitor for EcmaScriptSubset {
+    type Output = ();
+    type Err = &'static str;
+
+    fn finish(self) -> Result<(), &'static str> {
+        Ok(())
+    }
+
+    fn visit_pre(&mut self, ast: &Ast) -> Result<(), &'static str> {
+        match ast {
+            Ast::Flags(_) => Err("inline flags are not supported by std::regex"),
+            Ast::Literal(literal) => Self::check_literal(literal),
+            Ast::Assertion(assertion) => match assertion.kind {
+                ast::AssertionKind::StartLine
+                | ast::AssertionKind::EndLine
+                | ast::AssertionKind::WordBoundary
+                | ast::AssertionKind::NotWordBoundary => Ok(()),
+                _ => Err("only '^', '$', '\\b' and '\\B' assertions are supported by std::regex"),
+            },
+            Ast::ClassUnicode(_) => Err("Unicode classes are not supported by std::regex"),
+            Ast::Group(group) => match &group.kind {
+                ast::GroupKind::CaptureIndex(_) => Ok(()),
+                ast::GroupKind::CaptureName { .. } => {
+                    Err("named groups are not supported by std::regex")
+                }
+                ast::GroupKind::NonCapturing(flags) if flags.items.is_empty() => Ok(()),
+                ast::GroupKind::NonCapturing(_) => {
+                    Err("inline flags are not supported by std::regex")
+                }
+            },
+            _ => Ok(()),
+        }
+    }
+
+    fn visit_class_set_item_pre(&mut self, item: &ClassSetItem) -> Result<(), &'static str> {
+        match item {
+            ClassSetItem::Literal(literal) => Self::check_literal(literal),
+            ClassSetItem::Range(range) => {
+                Self::check_literal(&range.start)?;
+                Self::check_literal(&range.end)
+            }
+            ClassSetItem::Unicode(_) => Err("Unicode classes are not supported by std::regex"),
+            ClassSetItem::Bracketed(_) => {
+                Err("nested character classes are not supported by std::regex")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn visit_class_set_binary_op_pre(
+        &mut self,
+        _op: &ClassSetBinaryOp,
+    ) -> Result<(), &'static str> {
+        Err("character class operations are not supported by std::regex")
+    }
+}
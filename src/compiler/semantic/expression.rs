@@ -0,0 +1,340 @@
+use super::super::ast::*;
+
+/// Integer data type that constant expressions are folded in, every intermediate result
+/// has to fit in it.
+#[derive(Clone, Copy)]
+pub(super) struct IntegerType {
+    name: &'static str,
+    min: i64,
+    max: i64,
+}
+
+impl IntegerType {
+    /// Type of the integer literals
+    pub const INT64: Self = Self {
+        name: "int64",
+        min: i64::MIN,
+        max: i64::MAX,
+    };
+
+    /// Returns the integer type of a data type, wider integers are folded in 64 bits.
+    pub fn from(data_type: &DataType) -> Option<Self> {
+        let (name, min, max) = match data_type.kind() {
+            DataTypeKind::Int8 => ("int8", i8::MIN as i64, i8::MAX as i64),
+            DataTypeKind::UInt8 => ("uint8", 0, u8::MAX as i64),
+            DataTypeKind::Int16 => ("int16", i16::MIN as i64, i16::MAX as i64),
+            DataTypeKind::UInt16 => ("uint16", 0, u16::MAX as i64),
+            DataTypeKind::Int32 => ("int32", i32::MIN as i64, i32::MAX as i64),
+            DataTypeKind::UInt32 => ("uint32", 0, u32::MAX as i64),
+            DataTypeKind::Int64 => ("int64", i64::MIN, i64::MAX),
+            DataTypeKind::UInt64 => ("uint64", 0, i64::MAX),
+            DataTypeKind::Int128 => ("int128", i64::MIN, i64::MAX),
+            DataTypeKind::UInt128 => ("uint128", 0, i64::MAX),
+            _ => return None,
+        };
+
+        Some(Self { name, min, max })
+    }
+
+    #[inline]
+    fn contains(&self, integer: i64) -> bool {
+        self.min <= integer && integer <= self.max
+    }
+}
+
+/// Folds a constant expression into a value, or returns `None` once an error is reported
+/// so the expressions around it do not report it again.
+pub(super) fn evaluate(
+    compiler: &mut super::super::Compiler,
+    expression: &Expression,
+    location: Option<Location>,
+    integer_type: IntegerType,
+) -> Option<Value> {
+    match expression {
+        Expression::Value(value) => match value {
+            Value::Integer(integer) if !integer_type.contains(*integer) => {
+                compiler.diagnose(super::super::diagnostic::err_integer_overflow(
+                    &integer.to_string(),
+                    integer_type.name,
+                    location,
+                ));
+                None
+            }
+            Value::Real(real) if !real.is_finite() => {
+                compiler.diagnose(super::super::diagnostic::err_non_finite_real(
+                    &real.to_string(),
+                    location,
+                ));
+                None
+            }
+            _ => Some(value.clone()),
+        },
+        Expression::UnaryOperator(op, op_location, expression) => {
+            // A negative literal is a single value, so the smallest value of a narrower
+            // integer type like -128 for int8 can be written. Literals fit in int64 and
+            // their negation can not overflow.
+            if let (UnaryOperator::Negation, Expression::Value(Value::Integer(integer))) =
+                (op, expression.as_ref())
+            {
+                let value = Expression::Value(Value::Integer(-integer));
+                return evaluate(compiler, &value, location, integer_type);
+            }
+
+            let value = evaluate(compiler, expression, location, integer_type)?;
+            match value {
+                Value::Null => Some(Value::Null),
+                Value::True => match op {
+                    UnaryOperator::LogicalNot => Some(Value::False),
+                    _ => {
+                        compiler.diagnose(
+                            super::super::diagnostic::err_invalid_expression_operand(
+                                *op, &value, location,
+                            ),
+                        );
+                        None
+                    }
+                },
+                Value::False => match op {
+                    UnaryOperator::LogicalNot => Some(Value::True),
+                    _ => {
+                        compiler.diagnose(
+                            super::super::diagnostic::err_invalid_expression_operand(
+                                *op, &value, location,
+                            ),
+                        );
+                        None
+                    }
+                },
+                Value::Integer(integer) => match op {
+                    UnaryOperator::Negation => {
+                        match integer
+                            .checked_neg()
+                            .filter(|result| integer_type.contains(*result))
+                        {
+                            Some(result) => Some(Value::Integer(result)),
+                            None => {
+                                compiler.diagnose(super::super::diagnostic::err_integer_overflow(
+                                    &format!("{}{}", op, integer),
+                                    integer_type.name,
+                                    Some(*op_location),
+                                ));
+                                None
+                            }
+                        }
+                    }
+                    _ => {
+                        compiler.diagnose(
+                            super::super::diagnostic::err_invalid_expression_operand(
+                                *op, &value, location,
+                            ),
+                        );
+                        None
+                    }
+                },
+                Value::Real(real) => match op {
+                    UnaryOperator::Negation => Some(Value::Real(-real)),
+                    _ => {
+                        compiler.diagnose(
+                            super::super::diagnostic::err_invalid_expression_operand(
+                                *op, &value, location,
+                            ),
+                        );
+                        None
+                    }
+                },
+                Value::Literal(_) => {
+                    compiler.diagnose(super::super::diagnostic::err_invalid_expression_operand(
+                        *op, &value, location,
+                    ));
+                    None
+                }
+            }
+        }
+        Expression::BinaryOperator(expression_1, op, op_location, expression_2) => {
+            // Both operands are evaluated so the errors of each are reported
+            let value_1 = evaluate(compiler, expression_1, location, integer_type);
+            let value_2 = evaluate(compiler, expression_2, location, integer_type);
+            let (value_1, value_2) = (value_1?, value_2?);
+
+            let operands = Operands {
+                op: *op,
+                op_location: *op_location,
+                location,
+                value_1: &value_1,
+                value_2: &value_2,
+            };
+
+            match (&value_1, &value_2) {
+                (Value::Null, Value::Null) => Some(Value::Null),
+                (Value::False, Value::False) => match op {
+                    BinaryOperator::LogicalAnd => Some(Value::False),
+                    BinaryOperator::LogicalOr => Some(Value::False),
+                    _ => operands.invalid(compiler),
+                },
+                (Value::True, Value::False) => match op {
+                    BinaryOperator::LogicalAnd => Some(Value::False),
+                    BinaryOperator::LogicalOr => Some(Value::True),
+                    _ => operands.invalid(compiler),
+                },
+                (Value::False, Value::True) => match op {
+                    BinaryOperator::LogicalAnd => Some(Value::False),
+                    BinaryOperator::LogicalOr => Some(Value::True),
+                    _ => operands.invalid(compiler),
+                },
+                (Value::True, Value::True) => match op {
+                    BinaryOperator::LogicalAnd => Some(Value::True),
+                    BinaryOperator::LogicalOr => Some(Value::True),
+                    _ => operands.invalid(compiler),
+                },
+                (Value::Integer(integer_1), Value::Integer(integer_2)) => {
+                    operands.integer(compiler, integer_type, *integer_1, *integer_2)
+                }
+                (Value::Real(real_1), Value::Real(real_2)) => {
+                    operands.real(compiler, *real_1, *real_2)
+                }
+                (Value::Integer(integer), Value::Real(real)) => {
+                    operands.real(compiler, *integer as f64, *real)
+                }
+                (Value::Real(real), Value::Integer(integer)) => {
+                    operands.real(compiler, *real, *integer as f64)
+                }
+                (Value::Literal(literal_1), Value::Literal(literal_2)) => match op {
+                    BinaryOperator::Addition => {
+                        Some(Value::Literal(format!("{}{}", literal_1, literal_2)))
+                    }
+                    _ => operands.invalid(compiler),
+                },
+                (Value::Literal(literal), _) => match op {
+                    BinaryOperator::Addition => {
+                        Some(Value::Literal(format!("{}{}", literal, value_2)))
+                    }
+                    _ => operands.invalid(compiler),
+                },
+                (_, Value::Literal(literal)) => match op {
+                    BinaryOperator::Addition => {
+                        Some(Value::Literal(format!("{}{}", value_1, literal)))
+                    }
+                    _ => operands.invalid(compiler),
+                },
+                _ => operands.invalid(compiler),
+            }
+        }
+        Expression::Variable(_) | Expression::Call(..) => {
+            compiler.diagnose(super::super::diagnostic::err_unevaluable_expression(
+                &expression.to_string(),
+                location,
+            ));
+            None
+        }
+    }
+}
+
+/// Evaluated operands of a binary operator.
+struct Operands<'a> {
+    op: BinaryOperator,
+    op_location: Location,
+    /// Location of the whole option entry
+    location: Option<Location>,
+    value_1: &'a Value,
+    value_2: &'a Value,
+}
+
+impl Operands<'_> {
+    fn invalid(&self, compiler: &mut super::super::Compiler) -> Option<Value> {
+        compiler.diagnose(super::super::diagnostic::err_invalid_expression_operands(
+            self.op,
+            self.value_1,
+            self.value_2,
+            self.location,
+        ));
+        None
+    }
+
+    fn stringify(&self) -> String {
+        format!("{} {} {}", self.value_1, self.op, self.value_2)
+    }
+
+    fn integer(
+        &self,
+        compiler: &mut super::super::Compiler,
+        integer_type: IntegerType,
+        integer_1: i64,
+        integer_2: i64,
+    ) -> Option<Value> {
+        let result = match self.op {
+            BinaryOperator::Addition => integer_1.checked_add(integer_2),
+            BinaryOperator::Subtraction => integer_1.checked_sub(integer_2),
+            BinaryOperator::Multiplication => integer_1.checked_mul(integer_2),
+            BinaryOperator::Division | BinaryOperator::Modulo if integer_2 == 0 => {
+                compiler.diagnose(super::super::diagnostic::err_division_by_zero(
+                    self.op,
+                    self.value_1,
+                    self.op_location,
+                ));
+                return None;
+            }
+            BinaryOperator::Division => integer_1.checked_div(integer_2),
+            BinaryOperator::Modulo => integer_1.checked_rem(integer_2),
+            BinaryOperator::Equal => return Some(Value::from(integer_1 == integer_2)),
+            BinaryOperator::NotEqual => return Some(Value::from(integer_1 != integer_2)),
+            BinaryOperator::GreaterThan => return Some(Value::from(integer_1 > integer_2)),
+            BinaryOperator::GreaterThanEqual => return Some(Value::from(integer_1 >= integer_2)),
+            BinaryOperator::LessThan => return Some(Value::from(integer_1 < integer_2)),
+            BinaryOperator::LessThanEqual => return Some(Value::from(integer_1 <= integer_2)),
+            _ => return self.invalid(compiler),
+        };
+
+        match result.filter(|result| integer_type.contains(*result)) {
+            Some(result) => Some(Value::Integer(result)),
+            None => {
+                compiler.diagnose(super::super::diagnostic::err_integer_overflow(
+                    &self.stringify(),
+                    integer_type.name,
+                    Some(self.op_location),
+                ));
+                None
+            }
+        }
+    }
+
+    fn real(
+        &self,
+        compiler: &mut super::super::Compiler,
+        real_1: f64,
+        real_2: f64,
+    ) -> Option<Value> {
+        let result = match self.op {
+            BinaryOperator::Addition => real_1 + real_2,
+            BinaryOperator::Subtraction => real_1 - real_2,
+            BinaryOperator::Multiplication => real_1 * real_2,
+            BinaryOperator::Division | BinaryOperator::Modulo if real_2 == 0.0 => {
+                compiler.diagnose(super::super::diagnostic::err_division_by_zero(
+                    self.op,
+                    self.value_1,
+                    self.op_location,
+                ));
+                return None;
+            }
+            BinaryOperator::Division => real_1 / real_2,
+            BinaryOperator::Modulo => real_1 % real_2,
+            BinaryOperator::Equal => return Some(Value::from(real_1 == real_2)),
+            BinaryOperator::NotEqual => return Some(Value::from(real_1 != real_2)),
+            BinaryOperator::GreaterThan => return Some(Value::from(real_1 > real_2)),
+            BinaryOperator::GreaterThanEqual => return Some(Value::from(real_1 >= real_2)),
+            BinaryOperator::LessThan => return Some(Value::from(real_1 < real_2)),
+            BinaryOperator::LessThanEqual => return Some(Value::from(real_1 <= real_2)),
+            _ => return self.invalid(compiler),
+        };
+
+        // Operands are finite, so an infinite result is an overflow
+        if result.is_finite() {
+            Some(Value::Real(result))
+        } else {
+            compiler.diagnose(super::super::diagnostic::err_non_finite_real(
+                &self.stringify(),
+                Some(self.op_location),
+            ));
+            None
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! Fingerprints of the wire shape of the data types.
+//!
+//! Only what changes the encoding is hashed: the order and the types of the fields, the
+//! order of the variant cases, the bounds and the message buffer size type. Names,
+//! comments, formatting and options of the generated code are left out.
+
+use super::super::ast::*;
+
+/// 64-bit FNV-1a, unlike the standard hashers it is the same on every platform and
+/// every version of the compiler.
+struct Hasher(u64);
+
+impl Hasher {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl super::Semantic {
+    /// Computes the fingerprint of every data type.
+    pub(super) fn analyze_fingerprints(&mut self, file: &mut File) {
+        let size_type = file.file_options().message_buffer_size_type;
+
+        // Links to the data types encoded inside of each data type
+        let mut graph = super::graph::Graph::new(file.data_types().len());
+        for (src_id, data_type) in file.data_types().iter().enumerate() {
+            for reference in references(data_type) {
+                if let Some(dst_id) = reference.get_id() {
+                    graph.add_link(src_id, dst_id);
+                }
+            }
+        }
+
+        // Components come after the ones they depend on, so the shapes outside of a
+        // component are known before it is walked
+        let mut component_ids = vec![0; graph.len()];
+        let mut shapes = vec![0; graph.len()];
+        let mut numbers = vec![None; graph.len()];
+        for (component_id, component) in graph.components().iter().enumerate() {
+            for id in component {
+                component_ids[*id] = component_id;
+            }
+
+            let component_shapes = component
+                .iter()
+                .map(|id| Walk::new(file, &component_ids, &shapes, &mut numbers, *id).shape())
+                .collect::<Vec<u64>>();
+            for (id, shape) in component.iter().zip(component_shapes) {
+                shapes[*id] = shape;
+            }
+        }
+
+        for (data_type, shape) in file.data_types_mut().iter_mut().zip(shapes) {
+            let mut hasher = Hasher::new();
+            hasher.write_u64(shape);
+            hasher.write_u64(size_type.bits() as u64);
+            data_type.set_fingerprint(hasher.finish());
+        }
+    }
+}
+
+/// Returns the references of the data types encoded inside of a data type.
+fn references(data_type: &DataType) -> Vec<&Reference> {
+    match data_type.kind() {
+        DataTypeKind::Array(array) => vec![array.reference()],
+        DataTypeKind::Box(boxed) => vec![boxed.reference()],
+        DataTypeKind::Extern(extern_data_type) => vec![extern_data_type.reference()],
+        DataTypeKind::Structure(structure) => {
+            structure.fields().iter().map(Field::reference).collect()
+        }
+        DataTypeKind::Variant(variant) => variant.fields().iter().map(Field::reference).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Depth-first walk of the component of a data type that hashes its shape.
+///
+/// Data types of the component are numbered in the order they are first visited and
+/// hashed as their number when they are visited again, so the walk only depends on the
+/// data type it starts from and mutually recursive data types have the same shape in
+/// any declaration order. Data types of other components are hashed as their shape.
+struct Walk<'a> {
+    file: &'a File,
+    component_ids: &'a [usize],
+    shapes: &'a [u64],
+    root_id: usize,
+    /// Numbers of the visited data types, shared between walks and cleared after each
+    numbers: &'a mut [Option<usize>],
+    visited_ids: Vec<usize>,
+    hasher: Hasher,
+}
+
+impl<'a> Walk<'a> {
+    fn new(
+        file: &'a File,
+        component_ids: &'a [usize],
+        shapes: &'a [u64],
+        numbers: &'a mut [Option<usize>],
+        root_id: usize,
+    ) -> Self {
+        Self {
+            file,
+            component_ids,
+            shapes,
+            root_id,
+            numbers,
+            visited_ids: Vec::new(),
+            hasher: Hasher::new(),
+        }
+    }
+
+    fn shape(mut self) -> u64 {
+        self.visit(self.root_id);
+        for id in self.visited_ids {
+            self.numbers[id] = None;
+        }
+        self.hasher.finish()
+    }
+
+    fn visit(&mut self, mut id: usize) {
+        // Encoded as the data type it is converted to
+        while let DataTypeKind::Extern(extern_data_type) = self.file.data_types()[id].kind() {
+            match extern_data_type.reference().get_id() {
+                Some(dst_id) => id = dst_id,
+                None => {
+                    self.hasher.write(b"?");
+                    return;
+                }
+            }
+        }
+
+        if self.component_ids[id] != self.component_ids[self.root_id] {
+            self.hasher.write(b"=");
+            self.hasher.write_u64(self.shapes[id]);
+            return;
+        }
+
+        if let Some(number) = self.numbers[id] {
+            self.hasher.write(b"^");
+            self.hasher.write_u64(number as u64);
+            return;
+        }
+        self.numbers[id] = Some(self.visited_ids.len());
+        self.visited_ids.push(id);
+
+        let data_type = &self.file.data_types()[id];
+        match data_type.kind() {
+            DataTypeKind::Array(array) => {
+                match array.max_len() {
+                    Some(max_len) => {
+                        self.hasher.write(b"[max]");
+                        self.hasher.write_u64(max_len as u64);
+                    }
+                    None => self.hasher.write(b"[]"),
+                }
+                self.visit_reference(array.reference());
+            }
+            DataTypeKind::Box(boxed) => {
+                self.hasher.write(b"box");
+                self.visit_reference(boxed.reference());
+            }
+            DataTypeKind::Structure(structure) => {
+                self.hasher.write(b"struct");
+                self.hasher.write_u64(structure.fields().len() as u64);
+                for field in structure.fields() {
+                    self.visit_reference(field.reference());
+                }
+            }
+            // Kinds are the positions of the fields
+            DataTypeKind::Variant(variant) => {
+                self.hasher.write(b"variant");
+                self.hasher.write_u64(variant.fields().len() as u64);
+                for field in variant.fields() {
+                    self.visit_reference(field.reference());
+                }
+            }
+            // Primitives, strings and aliases are named after their encoding
+            _ => self.hasher.write(data_type.identifier().get().as_bytes()),
+        }
+    }
+
+    fn visit_reference(&mut self, reference: &Reference) {
+        match reference.get_id() {
+            Some(id) => self.visit(id),
+            None => self.hasher.write(b"?"),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Dependency graph of the data types.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// Directed graph where a link goes from a data type to a data type it depends on.
+///
+/// Nodes are the ids of the data types, links are kept in the order they were added so
+/// every traversal is the same on every run.
+pub struct Graph {
+    links: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    pub fn new(len: usize) -> Self {
+        Self {
+            links: vec![Vec::new(); len],
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.links.len()
+    }
+
+    #[inline]
+    pub fn links(&self, id: usize) -> &[usize] {
+        &self.links[id]
+    }
+
+    #[inline]
+    pub fn add_link(&mut self, src_id: usize, dst_id: usize) {
+        self.links[src_id].push(dst_id);
+    }
+
+    /// Returns the strongly connected components with Tarjan's algorithm.
+    ///
+    /// A component comes after every component it depends on, so the components are in
+    /// topological order. The ids inside a component are sorted.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        const UNVISITED: usize = usize::MAX;
+
+        let mut index = vec![UNVISITED; self.len()];
+        let mut low_link = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_index = 0;
+
+        for root_id in 0..self.len() {
+            if index[root_id] != UNVISITED {
+                continue;
+            }
+
+            // Explicit call stack of the nodes with the position of their next link
+            let mut calls = vec![(root_id, 0usize)];
+            index[root_id] = next_index;
+            low_link[root_id] = next_index;
+            next_index += 1;
+            stack.push(root_id);
+            on_stack[root_id] = true;
+
+            while let Some((id, link)) = calls.last_mut() {
+                let id = *id;
+
+                if let Some(dst_id) = self.links[id].get(*link).copied() {
+                    *link += 1;
+
+                    if index[dst_id] == UNVISITED {
+                        index[dst_id] = next_index;
+                        low_link[dst_id] = next_index;
+                        next_index += 1;
+                        stack.push(dst_id);
+                        on_stack[dst_id] = true;
+
+                        calls.push((dst_id, 0));
+                    } else if on_stack[dst_id] {
+                        low_link[id] = low_link[id].min(index[dst_id]);
+                    }
+
+                    continue;
+                }
+
+                calls.pop();
+                if let Some((parent_id, _)) = calls.last() {
+                    low_link[*parent_id] = low_link[*parent_id].min(low_link[id]);
+                }
+
+                // The node is the root of a component
+                if low_link[id] == index[id] {
+                    let mut component = Vec::new();
+
+                    while let Some(member_id) = stack.pop() {
+                        on_stack[member_id] = false;
+                        component.push(member_id);
+
+                        if member_id == id {
+                            break;
+                        }
+                    }
+
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    /// Returns the ids of the nodes with the dependencies of a node before it.
+    ///
+    /// Ties are broken by declaration order, a component comes as soon as its dependencies
+    /// are placed but never before a ready component with a smaller id, so the order only
+    /// changes where a dependency requires it.
+    pub fn order(&self, components: &[Vec<usize>]) -> Vec<usize> {
+        let mut component_ids = vec![0; self.len()];
+        for (component_id, component) in components.iter().enumerate() {
+            for id in component {
+                component_ids[*id] = component_id;
+            }
+        }
+
+        // Links between the components, a component depends on each other one only once
+        let mut links = Vec::new();
+        for (src_id, dst_ids) in self.links.iter().enumerate() {
+            for dst_id in dst_ids {
+                if component_ids[src_id] != component_ids[*dst_id] {
+                    links.push((component_ids[src_id], component_ids[*dst_id]));
+                }
+            }
+        }
+        links.sort_unstable();
+        links.dedup();
+
+        let mut dependents = vec![Vec::new(); components.len()];
+        let mut pending = vec![0; components.len()];
+        for (src_id, dst_id) in links {
+            dependents[dst_id].push(src_id);
+            pending[src_id] += 1;
+        }
+
+        let mut ready = components
+            .iter()
+            .enumerate()
+            .filter(|(component_id, _)| pending[*component_id] == 0)
+            .map(|(component_id, component)| Reverse((component[0], component_id)))
+            .collect::<BinaryHeap<Reverse<(usize, usize)>>>();
+
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(Reverse((_, component_id))) = ready.pop() {
+            order.extend_from_slice(&components[component_id]);
+
+            for dependent_id in &dependents[component_id] {
+                pending[*dependent_id] -= 1;
+                if pending[*dependent_id] == 0 {
+                    ready.push(Reverse((components[*dependent_id][0], *dependent_id)));
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Returns whether the nodes of the component depend on themselves.
+    pub fn is_cyclic(&self, component: &[usize]) -> bool {
+        match component {
+            [id] => self.links[*id].contains(id),
+            _ => !component.is_empty(),
+        }
+    }
+
+    /// Returns the shortest cycle from the node back to itself inside its component,
+    /// starting with the node.
+    pub fn cycle(&self, component: &[usize], start_id: usize) -> Vec<usize> {
+        let mut previous = vec![None; self.len()];
+        let mut queue = VecDeque::from([start_id]);
+
+        while let Some(id) = queue.pop_front() {
+            for &dst_id in &self.links[id] {
+                if dst_id == start_id {
+                    let mut path = vec![id];
+                    while let Some(previous_id) = previous[*path.last().unwrap()] {
+                        path.push(previous_id);
+                    }
+                    path.reverse();
+
+                    return path;
+                }
+
+                if component.contains(&dst_id) && previous[dst_id].is_none() {
+                    previous[dst_id] = Some(id);
+                    queue.push_back(dst_id);
+                }
+            }
+        }
+
+        vec![start_id]
+    }
+}
@@ -0,0 +1,268 @@
+use super::super::ast::*;
+
+impl super::Semantic {
+    /// Computes the wire layout of every data type as encoded by the generated message
+    /// buffer code.
+    pub(super) fn analyze_layouts(&mut self, file: &mut File) {
+        let mut layouts = vec![None; file.data_types().len()];
+        for id in 0..layouts.len() {
+            layout(file, id, &mut layouts);
+        }
+
+        for (data_type, layout) in file.data_types_mut().iter_mut().zip(layouts) {
+            if let Some(layout) = layout {
+                data_type.set_layout(layout);
+            }
+        }
+    }
+
+    /// Checks that the sizes and lengths of the messages fit in the size type of the
+    /// message buffers, larger ones would be truncated.
+    ///
+    /// Unbounded data types are checked by the generated code instead.
+    pub(super) fn analyze_message_buffer_limits(
+        &mut self,
+        compiler: &mut super::super::Compiler,
+        file: &File,
+    ) {
+        let size_type = file.file_options().message_buffer_size_type;
+
+        let is_message = |data_type: &DataType| match data_type.kind() {
+            DataTypeKind::Structure(structure) => {
+                let (reader, writer) = structure.structure_options().message_buffer;
+                reader || writer
+            }
+            DataTypeKind::Variant(variant) => {
+                let (reader, writer) = variant.variant_options().message_buffer;
+                reader || writer
+            }
+            _ => false,
+        };
+
+        // Nested data types are encoded inline in the messages
+        let mut is_encoded = vec![false; file.data_types().len()];
+        let mut stack = file
+            .data_types()
+            .iter()
+            .enumerate()
+            .filter(|(_, data_type)| is_message(data_type))
+            .map(|(id, _)| id)
+            .collect::<Vec<usize>>();
+        while let Some(id) = stack.pop() {
+            if is_encoded[id] {
+                continue;
+            }
+            is_encoded[id] = true;
+
+            let references = match file.data_types()[id].kind() {
+                DataTypeKind::Array(array) => vec![array.reference()],
+                DataTypeKind::Box(boxed) => vec![boxed.reference()],
+                DataTypeKind::Extern(extern_data_type) => vec![extern_data_type.reference()],
+                DataTypeKind::Structure(structure) => {
+                    structure.fields().iter().map(Field::reference).collect()
+                }
+                DataTypeKind::Variant(variant) => {
+                    variant.fields().iter().map(Field::reference).collect()
+                }
+                _ => Vec::new(),
+            };
+            stack.extend(references.into_iter().filter_map(Reference::get_id));
+        }
+
+        // Lengths of the bounded strings and arrays of the fields
+        let mut has_length_overflow = vec![false; file.data_types().len()];
+        for (id, data_type) in file.data_types().iter().enumerate() {
+            if !is_encoded[id] {
+                continue;
+            }
+
+            let fields = match data_type.kind() {
+                DataTypeKind::Structure(structure) => structure.fields(),
+                DataTypeKind::Variant(variant) => variant.fields(),
+                _ => continue,
+            };
+
+            for field in fields {
+                // Arrays, boxes and extern data types are followed to their elements
+                let mut reference_id = field.reference().get_id();
+                while let Some(data_type) = reference_id.and_then(|id| file.data_type(id)) {
+                    let max_len = match data_type.kind() {
+                        DataTypeKind::BoundedString(max_len) => Some(*max_len),
+                        DataTypeKind::Array(array) => array.max_len(),
+                        _ => None,
+                    };
+                    if let Some(max_len) = max_len.filter(|max_len| *max_len > size_type.max()) {
+                        compiler.diagnose(
+                            super::super::diagnostic::err_message_buffer_length_overflow(
+                                field,
+                                data_type.identifier().get(),
+                                max_len,
+                                size_type,
+                            ),
+                        );
+                        has_length_overflow[id] = true;
+                        break;
+                    }
+
+                    reference_id = match data_type.kind() {
+                        DataTypeKind::Array(array) => array.reference().get_id(),
+                        DataTypeKind::Box(boxed) => boxed.reference().get_id(),
+                        DataTypeKind::Extern(extern_data_type) => {
+                            extern_data_type.reference().get_id()
+                        }
+                        _ => None,
+                    };
+                }
+            }
+        }
+
+        // Size offsets of the messages, a length that is too long is reported on its own
+        for (id, data_type) in file.data_types().iter().enumerate() {
+            if !is_message(data_type) || has_length_overflow[id] {
+                continue;
+            }
+
+            let size = data_type.layout().size();
+            if let Some(max) = size.max {
+                if max - size.min > size_type.max() {
+                    compiler.diagnose(super::super::diagnostic::err_message_buffer_size_overflow(
+                        data_type.identifier(),
+                        max - size.min,
+                        size_type,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Returns the size and prefix size of a data type and memoizes its layout, data types
+/// in progress are treated as unbounded.
+fn layout(file: &File, id: usize, layouts: &mut [Option<Layout>]) -> (Size, usize) {
+    if let Some(layout) = &layouts[id] {
+        return (layout.size(), layout.prefix_size());
+    }
+    layouts[id] = Some(Layout::new(Size::unbounded(0), 0, Vec::new()));
+
+    // Strings and arrays start with their length
+    let length_size = file.file_options().message_buffer_size_type.size();
+
+    let mut reference_layout = |reference: &Reference| match reference.get_id() {
+        Some(id) => layout(file, id, layouts),
+        None => (Size::unbounded(0), 0),
+    };
+
+    let data_type = &file.data_types()[id];
+    let result = match data_type.kind() {
+        DataTypeKind::Boolean | DataTypeKind::Int8 | DataTypeKind::UInt8 => {
+            Layout::new(Size::fixed(1), 1, Vec::new())
+        }
+        DataTypeKind::Int16 | DataTypeKind::UInt16 | DataTypeKind::Half => {
+            Layout::new(Size::fixed(2), 2, Vec::new())
+        }
+        DataTypeKind::Int32 | DataTypeKind::UInt32 | DataTypeKind::Single | DataTypeKind::Char => {
+            Layout::new(Size::fixed(4), 4, Vec::new())
+        }
+        DataTypeKind::Int64
+        | DataTypeKind::UInt64
+        | DataTypeKind::Double
+        | DataTypeKind::Decimal(_) => Layout::new(Size::fixed(8), 8, Vec::new()),
+        DataTypeKind::Int128 | DataTypeKind::UInt128 => {
+            Layout::new(Size::fixed(16), 16, Vec::new())
+        }
+        // Length followed by the bytes
+        DataTypeKind::String => Layout::new(Size::unbounded(length_size), length_size, Vec::new()),
+        DataTypeKind::BoundedString(max_len) => Layout::new(
+            Size::fixed(length_size).then(Size::fixed(1).repeat(*max_len)),
+            length_size,
+            Vec::new(),
+        ),
+        DataTypeKind::Alias(_) => Layout::new(Size::unbounded(0), 0, Vec::new()),
+        DataTypeKind::Extern(extern_data_type) => {
+            let (size, prefix_size) = reference_layout(extern_data_type.reference());
+
+            Layout::new(size, prefix_size, Vec::new())
+        }
+        // Length followed by the elements, unbounded arrays are not followed since they
+        // may be recursive
+        DataTypeKind::Array(array) => match array.max_len() {
+            Some(max_len) => {
+                let (element_size, _) = reference_layout(array.reference());
+
+                Layout::new(
+                    Size::fixed(length_size).then(element_size.repeat(max_len)),
+                    length_size,
+                    Vec::new(),
+                )
+            }
+            None => Layout::new(Size::unbounded(length_size), length_size, Vec::new()),
+        },
+        // Presence flag followed by the value if there is one, a recursive box may be
+        // nested any number of times
+        DataTypeKind::Box(boxed) => {
+            let size = if data_type.is_recursive() {
+                Size::unbounded(1)
+            } else {
+                let (element_size, _) = reference_layout(boxed.reference());
+
+                Size::fixed(1).then(element_size.or(Size::fixed(0)))
+            };
+
+            Layout::new(size, 1, Vec::new())
+        }
+        // Fields one after the other
+        DataTypeKind::Structure(structure) => {
+            let mut offset = Size::fixed(0);
+            let mut prefix_size = 0usize;
+            let mut is_prefix = true;
+            let mut fields = Vec::new();
+
+            for field in structure.fields() {
+                let (size, field_prefix_size) = reference_layout(field.reference());
+
+                fields.push(FieldLayout { offset, size });
+
+                // The prefix ends within the first field of variable size
+                if is_prefix {
+                    prefix_size = prefix_size.saturating_add(field_prefix_size);
+                    is_prefix = size.is_fixed();
+                }
+
+                offset = offset.then(size);
+            }
+
+            Layout::new(offset, prefix_size, fields)
+        }
+        // Kind followed by the field of that kind
+        DataTypeKind::Variant(variant) => {
+            let offset = Size::fixed(2);
+            let mut cases: Option<(Size, usize)> = None;
+            let mut fields = Vec::new();
+
+            for field in variant.fields() {
+                let (size, prefix_size) = reference_layout(field.reference());
+
+                fields.push(FieldLayout { offset, size });
+
+                cases = Some(match cases {
+                    Some((cases_size, cases_prefix_size)) => {
+                        (cases_size.or(size), cases_prefix_size.min(prefix_size))
+                    }
+                    None => (size, prefix_size),
+                });
+            }
+
+            let (cases_size, cases_prefix_size) = cases.unwrap_or((Size::fixed(0), 0));
+
+            Layout::new(
+                offset.then(cases_size),
+                2usize.saturating_add(cases_prefix_size),
+                fields,
+            )
+        }
+    };
+
+    let size = (result.size(), result.prefix_size());
+    layouts[id] = Some(result);
+    size
+}
@@ -0,0 +1,83 @@
+use super::super::ast::*;
+use super::super::lint::{self, Lint};
+
+/// Returns the lint names of an `allow` option like `opt allow("deprecated", "empty_body");`.
+fn allowed_lints(optioned: &dyn Optioned) -> Vec<&str> {
+    match optioned.option("allow") {
+        Some(arguments) => arguments
+            .iter()
+            .filter_map(|argument| match argument {
+                Expression::Value(Value::Literal(name)) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Returns whether the lint is allowed by any of the enclosing scopes.
+pub(super) fn is_allowed(lint: &Lint, scopes: &[&dyn Optioned]) -> bool {
+    scopes
+        .iter()
+        .any(|scope| allowed_lints(*scope).contains(&lint.name))
+}
+
+impl super::Semantic {
+    pub(super) fn analyze_lints(&mut self, compiler: &mut super::super::Compiler, file: &File) {
+        Self::analyze_allowed_lints(compiler, file, &[file]);
+
+        for data_type in file.data_types() {
+            let (kind, fielded, optioned): (&str, &dyn Fielded, &dyn Optioned) =
+                match data_type.kind() {
+                    DataTypeKind::Structure(structure) => ("Structure", structure, structure),
+                    DataTypeKind::Variant(variant) => ("Variant", variant, variant),
+                    DataTypeKind::Extern(extern_data_type) => {
+                        Self::analyze_allowed_lints(
+                            compiler,
+                            extern_data_type,
+                            &[extern_data_type, file],
+                        );
+                        continue;
+                    }
+                    _ => continue,
+                };
+
+            Self::analyze_allowed_lints(compiler, optioned, &[optioned, file]);
+            for field in fielded.fields() {
+                Self::analyze_allowed_lints(compiler, field, &[field, optioned, file]);
+            }
+
+            if fielded.fields().is_empty() && !is_allowed(&lint::EMPTY_BODY, &[optioned, file]) {
+                compiler.diagnose(super::super::diagnostic::warn_empty_body(
+                    kind,
+                    data_type.identifier(),
+                ));
+            }
+        }
+    }
+
+    /// Checks the names of an `allow` option, the scopes are the option's own and the
+    /// enclosing ones so `unknown_lints` can be allowed next to the names it reports.
+    fn analyze_allowed_lints(
+        compiler: &mut super::super::Compiler,
+        optioned: &dyn Optioned,
+        scopes: &[&dyn Optioned],
+    ) {
+        if is_allowed(&lint::UNKNOWN_LINTS, scopes) {
+            return;
+        }
+
+        for name in allowed_lints(optioned) {
+            if lint::find(name).is_none() {
+                compiler.diagnose(super::super::diagnostic::warn_unknown_lint(
+                    name,
+                    optioned.option_location("allow"),
+                    super::super::suggestion::similar(
+                        name,
+                        lint::LINTS.iter().map(|lint| lint.name),
+                    ),
+                ));
+            }
+        }
+    }
+}
@@ -1,17 +1,17 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+mod constraint;
+mod expression;
+mod fingerprint;
+mod graph;
+mod layout;
+mod lint;
+mod naming;
+mod options;
+mod service;
+
+use std::collections::HashMap;
 
 use super::ast::*;
 
-use regex::Regex;
-
-struct Node {
-    pub is_alias: bool,
-    pub links: Vec<usize>,
-
-    pub dfs_visited_mark: bool,
-    pub dfs_mark: bool,
-}
-
 pub struct Semantic {}
 
 impl Semantic {
@@ -22,298 +22,317 @@ impl Semantic {
     pub fn analyse(mut self, compiler: &mut super::Compiler, file: &mut File) {
         self.analyze_dependencies(compiler, file);
         self.analyze_options(compiler, file);
+        self.analyze_layouts(file);
+        self.analyze_fingerprints(file);
+        self.analyze_message_buffer_limits(compiler, file);
+        self.analyze_naming(compiler, file);
+        self.analyze_constraints(compiler, file);
+        self.analyze_services(compiler, file);
+        self.analyze_deprecations(compiler, file);
+        self.analyze_lints(compiler, file);
     }
 
     fn analyze_dependencies(&mut self, compiler: &mut super::Compiler, file: &mut File) {
-        let data_type_identifier_regex =
-            Regex::new(r"^[A-Z][a-z]*(?:[A-Z][a-z]*|[0-9]+)*$").unwrap();
-        let field_identifier_regex = Regex::new(r"^[a-z][a-z0-9]*(?:_[a-z0-9]+)*$").unwrap();
-
         let mut data_type_names = HashMap::new();
 
-        let mut nodes = Vec::with_capacity(file.data_types().len());
+        let mut graph = graph::Graph::new(file.data_types().len());
 
-        // Add nodes
+        // Add data type names
         for (id, data_type) in file.data_types().iter().enumerate() {
             // Add data type name
             if let Some(name) = data_type.identifier().get_opt() {
-                if data_type_names.insert(name.clone(), id).is_some() {
-                    compiler.diagnose(super::diagnostic::err_redefined_data_type(name));
-                }
-
-                // Check case
-                match data_type.kind() {
-                    DataTypeKind::Structure(_) | DataTypeKind::Variant(_) => {
-                        if !data_type_identifier_regex.is_match(name) {
-                            compiler
-                                .diagnose(super::diagnostic::err_invalid_data_type_identifer(name));
-                        }
-                    }
-                    _ => {}
+                if let Some(first_id) = data_type_names.insert(name.clone(), id) {
+                    compiler.diagnose(super::diagnostic::err_redefined_data_type(
+                        data_type.identifier(),
+                        file.data_types()[first_id].identifier(),
+                    ));
                 }
             }
 
+            // Check redefinitions inside structures and variants
             match data_type.kind() {
-                DataTypeKind::Boolean
-                | DataTypeKind::Int8
-                | DataTypeKind::UInt8
-                | DataTypeKind::Int16
-                | DataTypeKind::UInt16
-                | DataTypeKind::Int32
-                | DataTypeKind::UInt32
-                | DataTypeKind::Int64
-                | DataTypeKind::UInt64
-                | DataTypeKind::Single
-                | DataTypeKind::Double
-                | DataTypeKind::String => {
-                    nodes.push(Node {
-                        is_alias: false,
-                        links: Vec::new(),
-                        dfs_visited_mark: false,
-                        dfs_mark: false,
-                    });
-                }
                 DataTypeKind::Structure(structure) => {
-                    nodes.push(Node {
-                        is_alias: false,
-                        links: Vec::new(),
-                        dfs_visited_mark: false,
-                        dfs_mark: false,
-                    });
-
-                    // Check redefinition inside structure
-                    let mut field_names = HashSet::new();
-
-                    for field in structure.fields() {
-                        if let Some(name) = field.identifier().get_opt() {
-                            if !field_names.insert(name.clone()) {
-                                compiler.diagnose(super::diagnostic::err_redefined_field(name));
-                            }
-
-                            // Check case
-                            if !field_identifier_regex.is_match(name) {
-                                compiler.diagnose(
-                                    super::diagnostic::err_invalid_data_type_identifer(name),
-                                );
-                            }
-                        }
-                    }
+                    self.analyze_field_redefinitions(compiler, structure.fields());
+                    self.analyze_reservations(
+                        compiler,
+                        structure.fields(),
+                        structure.reservations(),
+                    );
                 }
                 DataTypeKind::Variant(variant) => {
-                    nodes.push(Node {
-                        is_alias: false,
-                        links: Vec::new(),
-                        dfs_visited_mark: false,
-                        dfs_mark: false,
-                    });
-
-                    // Check redefinition inside variant
-                    let mut field_names = HashSet::new();
-
-                    for field in variant.fields() {
-                        if let Some(name) = field.identifier().get_opt() {
-                            if !field_names.insert(name.clone()) {
-                                compiler.diagnose(super::diagnostic::err_redefined_field(name));
-                            }
-
-                            // Check case
-                            if !field_identifier_regex.is_match(name) {
-                                compiler.diagnose(
-                                    super::diagnostic::err_invalid_data_type_identifer(name),
-                                );
-                            }
-                        }
-                    }
-                }
-                DataTypeKind::Alias(_) => {
-                    nodes.push(Node {
-                        is_alias: true,
-                        links: Vec::new(),
-                        dfs_visited_mark: false,
-                        dfs_mark: false,
-                    });
+                    self.analyze_field_redefinitions(compiler, variant.fields());
+                    self.analyze_reservations(compiler, variant.fields(), variant.reservations());
                 }
+                _ => {}
             }
         }
 
         // Add links
-        for (src_id, data_type) in file.data_types().iter().enumerate() {
-            match data_type.kind() {
-                DataTypeKind::Structure(structure) => {
-                    for field in structure.fields() {
-                        if let Some(reference) = field.reference().get_opt() {
-                            if let Some(dst_id) = data_type_names.get(reference) {
-                                nodes[src_id].links.push(*dst_id);
-                            } else {
-                                compiler.diagnose(super::diagnostic::err_undeclared_data_type(
-                                    reference,
-                                ));
-                            }
-                        }
-                    }
+        for (src_id, data_type) in file.data_types_mut().iter_mut().enumerate() {
+            let references = match data_type.kind_mut() {
+                DataTypeKind::Structure(structure) => structure
+                    .fields_mut()
+                    .iter_mut()
+                    .map(Field::reference_mut)
+                    .collect(),
+                DataTypeKind::Variant(variant) => variant
+                    .fields_mut()
+                    .iter_mut()
+                    .map(Field::reference_mut)
+                    .collect(),
+                DataTypeKind::Alias(alias) => vec![alias.reference_mut()],
+                DataTypeKind::Array(array) => vec![array.reference_mut()],
+                DataTypeKind::Box(boxed) => vec![boxed.reference_mut()],
+                DataTypeKind::Extern(extern_data_type) => vec![extern_data_type.reference_mut()],
+                _ => continue,
+            };
+
+            for reference in references {
+                if let Some(dst_id) = Self::resolve_reference(compiler, &data_type_names, reference)
+                {
+                    graph.add_link(src_id, dst_id);
                 }
-                DataTypeKind::Variant(variant) => {
-                    for field in variant.fields() {
-                        if let Some(reference) = field.reference().get_opt() {
-                            if let Some(dst_id) = data_type_names.get(reference) {
-                                nodes[src_id].links.push(*dst_id);
-                            } else {
-                                compiler.diagnose(super::diagnostic::err_undeclared_data_type(
-                                    reference,
-                                ));
-                            }
+            }
+        }
+
+        // Resolve underlying data types of extern data types before their layout is needed
+        {
+            let mut resolved = Vec::new();
+
+            for (src_id, data_type) in file.data_types().iter().enumerate() {
+                if let DataTypeKind::Extern(extern_data_type) = data_type.kind() {
+                    if let Some(dst_id) = graph.links(src_id).first() {
+                        let dst_id = Self::resolve_alias(file, &graph, *dst_id);
+
+                        let dst_data_type = &file.data_types()[dst_id];
+                        if dst_data_type.is_built_in() {
+                            resolved.push((src_id, dst_id));
+                        } else {
+                            compiler.diagnose(super::diagnostic::err_invalid_extern_data_type(
+                                extern_data_type.identifier().get(),
+                                extern_data_type.reference(),
+                            ));
                         }
                     }
                 }
-                DataTypeKind::Alias(alias) => {
-                    if let Some(reference) = alias.reference().get_opt() {
-                        if let Some(dst_id) = data_type_names.get(reference) {
-                            nodes[src_id].links.push(*dst_id);
-                        } else {
-                            compiler
-                                .diagnose(super::diagnostic::err_undeclared_data_type(reference));
-                        }
+            }
+
+            for (src_id, dst_id) in resolved {
+                if let Some(DataTypeKind::Extern(extern_data_type)) = file
+                    .data_type_mut(src_id)
+                    .map(|data_type| data_type.kind_mut())
+                {
+                    extern_data_type.reference_mut().set_id(dst_id);
+                }
+            }
+        }
+
+        // Find recursive data types, cycles through a box or an unbounded array are
+        // allowed since the recursion ends with an empty one
+        for component in graph.components() {
+            if graph.is_cyclic(&component) {
+                for id in component {
+                    if let Some(data_type) = file.data_type_mut(id) {
+                        data_type.set_recursive(true);
                     }
                 }
-                _ => {}
             }
         }
 
-        // Check for cyclical dependencies
+        // Check for cyclical dependencies and order the data types, recursive links
+        // of indirect data types are not dependencies since the pointed data type is
+        // declared beforehand
         {
-            let mut stack: Vec<usize> = Vec::with_capacity(nodes.len());
-            let mut queue: VecDeque<(usize, usize)> = VecDeque::with_capacity(nodes.len());
-
-            #[inline(always)]
-            fn update_data_type(
-                compiler: &mut super::Compiler,
-                file: &mut File,
-                rank: usize,
-                id: usize,
-                parent_id: usize,
-            ) {
-                // Update data type
-                let mut size = 0;
-
-                if let Some(data_type) = file.data_type_mut(id) {
-                    data_type.update_max_rank(rank);
-
-                    size = data_type.size();
-                } else {
-                    compiler.diagnose(super::diagnostic::internal_error("Invalid data type id"));
+            let mut dependencies = graph::Graph::new(graph.len());
+            for (src_id, data_type) in file.data_types().iter().enumerate() {
+                let is_indirect = match data_type.kind() {
+                    DataTypeKind::Array(array) => array.max_len().is_none(),
+                    DataTypeKind::Box(_) => true,
+                    _ => false,
+                };
+
+                if !(is_indirect && data_type.is_recursive()) {
+                    for dst_id in graph.links(src_id) {
+                        dependencies.add_link(src_id, *dst_id);
+                    }
                 }
+            }
 
-                // Update parent data type
-                if let Some(data_type) = file.data_type_mut(parent_id) {
-                    match data_type.kind_mut() {
-                        DataTypeKind::Structure(structure) => {
-                            structure.update_min_size(size);
-                        }
-                        DataTypeKind::Variant(variant) => {
-                            variant.update_min_size(size);
-                        }
-                        _ => {}
+            let components = dependencies.components();
+
+            // Each cycle is reported once from the first declared data type that is
+            // written in the file, in declaration order
+            let mut cycles = components
+                .iter()
+                .filter(|component| dependencies.is_cyclic(component))
+                .map(|component| {
+                    let start_id = component
+                        .iter()
+                        .copied()
+                        .find(|id| file.data_types()[*id].identifier().location().is_some())
+                        .unwrap_or(component[0]);
+
+                    (start_id, component)
+                })
+                .collect::<Vec<(usize, &Vec<usize>)>>();
+            cycles.sort_by_key(|(start_id, _)| *start_id);
+
+            for (start_id, component) in cycles {
+                let mut path = dependencies
+                    .cycle(component, start_id)
+                    .into_iter()
+                    .map(|id| file.data_types()[id].identifier().get().to_string())
+                    .collect::<Vec<String>>();
+                path.push(file.data_types()[start_id].identifier().get().to_string());
+
+                compiler.diagnose(super::diagnostic::err_cyclical_dependency(
+                    &path,
+                    file.data_types()[start_id].identifier().location(),
+                ));
+            }
+
+            file.set_order(dependencies.order(&components));
+        }
+
+        // Resolve references through aliases
+        {
+            let mut resolved = Vec::new();
+
+            for (src_id, data_type) in file.data_types().iter().enumerate() {
+                let references = match data_type.kind() {
+                    DataTypeKind::Structure(structure) => structure
+                        .fields()
+                        .iter()
+                        .map(|field| field.reference())
+                        .collect(),
+                    DataTypeKind::Variant(variant) => variant
+                        .fields()
+                        .iter()
+                        .map(|field| field.reference())
+                        .collect(),
+                    DataTypeKind::Array(array) => vec![array.reference()],
+                    DataTypeKind::Box(boxed) => vec![boxed.reference()],
+                    _ => continue,
+                };
+
+                for (index, reference) in references.into_iter().enumerate() {
+                    if let Some(dst_id) = reference.get_id() {
+                        resolved.push((src_id, index, Self::resolve_alias(file, &graph, dst_id)));
                     }
-                } else {
-                    compiler.diagnose(super::diagnostic::internal_error("Invalid data type id"));
                 }
             }
 
-            loop {
-                if let Some((id, rank)) = queue.pop_front() {
-                    // Unmark
-                    if rank <= stack.len() {
-                        for rank in (rank - 1..stack.len()).rev() {
-                            let id = stack[rank];
-                            nodes[id].dfs_mark = false;
-
-                            if rank > 0 {
-                                update_data_type(compiler, file, rank, id, stack[rank - 1]);
-                            }
-                        }
+            for (src_id, index, dst_id) in resolved {
+                let reference = match file
+                    .data_type_mut(src_id)
+                    .map(|data_type| data_type.kind_mut())
+                {
+                    Some(DataTypeKind::Structure(structure)) => structure
+                        .field_mut(index)
+                        .map(|field| field.reference_mut()),
+                    Some(DataTypeKind::Variant(variant)) => {
+                        variant.field_mut(index).map(|field| field.reference_mut())
+                    }
+                    Some(DataTypeKind::Array(array)) => Some(array.reference_mut()),
+                    Some(DataTypeKind::Box(boxed)) => Some(boxed.reference_mut()),
+                    _ => None,
+                };
 
-                        stack.resize(rank - 1, 0usize);
+                match reference {
+                    Some(reference) => reference.set_id(dst_id),
+                    None => {
+                        compiler.diagnose(super::diagnostic::internal_error("Invalid data type id"))
                     }
+                }
+            }
+        }
+    }
 
-                    // Mark and queue
-                    {
-                        let node = &mut nodes[id];
+    /// Links a reference to the data type it names, an undeclared name is reported with
+    /// the most similar declared one.
+    fn resolve_reference(
+        compiler: &mut super::Compiler,
+        data_type_names: &HashMap<String, usize>,
+        reference: &mut Reference,
+    ) -> Option<usize> {
+        let name = reference.get_opt()?;
+
+        match data_type_names.get(name) {
+            Some(id) => {
+                reference.set_id(*id);
+                Some(*id)
+            }
+            None => {
+                compiler.diagnose(super::diagnostic::err_undeclared_data_type(
+                    name,
+                    reference.location(),
+                    super::suggestion::similar(name, data_type_names.keys().map(String::as_str)),
+                ));
+                None
+            }
+        }
+    }
 
-                        if !node.dfs_mark {
-                            node.dfs_visited_mark = true;
-                            node.dfs_mark = true;
+    /// Returns the data type an alias refers to, following aliases of aliases.
+    fn resolve_alias(file: &File, graph: &graph::Graph, mut id: usize) -> usize {
+        // Aliases in a cycle are reported, the walk ends anyway
+        for _ in 0..graph.len() {
+            match (file.data_types()[id].kind(), graph.links(id).first()) {
+                (DataTypeKind::Alias(_), Some(dst_id)) => id = *dst_id,
+                _ => break,
+            }
+        }
 
-                            // Add neighbours to queue
-                            for node_id in &node.links {
-                                queue.push_front((*node_id, rank + 1));
-                            }
+        id
+    }
 
-                            stack.push(id);
-                        } else {
-                            // Node was already visited which means a cycle is present
-                            compiler.diagnose(super::diagnostic::err_cyclical_dependency(
-                                stack
-                                    .iter()
-                                    .map(|id| file.data_types()[*id].identifier().get().to_string())
-                                    .collect::<Vec<String>>()
-                                    .as_slice(),
-                            ))
-                        }
-                    }
-                } else {
-                    if let Some(id) = nodes.iter().position(|node| !node.dfs_visited_mark) {
-                        queue.push_front((id, 1usize));
-                    } else {
-                        break;
-                    }
+    fn analyze_field_redefinitions(&mut self, compiler: &mut super::Compiler, fields: &[Field]) {
+        let mut field_names = HashMap::new();
+
+        for field in fields {
+            if let Some(name) = field.identifier().get_opt() {
+                if let Some(first) = field_names.insert(name.clone(), field.identifier()) {
+                    compiler.diagnose(super::diagnostic::err_redefined_field(
+                        field.identifier(),
+                        first,
+                    ));
                 }
             }
+        }
+    }
 
-            for rank in (1..stack.len()).rev() {
-                if rank > 0 {
-                    update_data_type(compiler, file, rank, stack[rank], stack[rank - 1]);
+    fn analyze_reservations(
+        &mut self,
+        compiler: &mut super::Compiler,
+        fields: &[Field],
+        reservations: &[Reservation],
+    ) {
+        for reservation in reservations {
+            if let ReservationKind::Range(start, end) = reservation.kind() {
+                if *start < 1 || start > end {
+                    compiler.diagnose(super::diagnostic::err_invalid_reserved_range(reservation));
                 }
             }
         }
 
-        // Simplify dependencies
-        {
-            for (src_id, src_node) in nodes.iter().enumerate() {
-                // Only simplify for non alias nodes
-                if !src_node.is_alias {
-                    for (link_id, dst_id) in src_node.links.iter().enumerate() {
-                        let mut dst_node = &nodes[*dst_id];
-
-                        // Only simplify alias nodes
-                        // if dst_node.is_alias {
-                        {
-                            let mut dst_id = *dst_id;
-                            while dst_node.is_alias {
-                                dst_id = dst_node.links[0];
-                                dst_node = &nodes[dst_id];
-                            }
-
-                            if let Some(data_type) = file.data_type_mut(src_id) {
-                                match data_type.kind_mut() {
-                                    DataTypeKind::Structure(structure) => {
-                                        if let Some(field) = structure.field_mut(link_id) {
-                                            field.reference_mut().set_id(dst_id);
-                                        }
-                                    }
-                                    DataTypeKind::Variant(variant) => {
-                                        if let Some(field) = variant.field_mut(link_id) {
-                                            field.reference_mut().set_id(dst_id);
-                                        }
-                                    }
-                                    _ => {}
-                                }
-                            } else {
-                                compiler.diagnose(super::diagnostic::internal_error(
-                                    "Invalid data type id",
-                                ));
-                            }
-                        }
-                    }
+        for (index, field) in fields.iter().enumerate() {
+            // Field numbers start at 1 like variant discriminants
+            let number = index as i64 + 1;
+
+            if let Some(reservation) = reservations.iter().find(|r| r.contains_number(number)) {
+                compiler.diagnose(super::diagnostic::err_reserved_field_number(
+                    field.identifier().get(),
+                    number,
+                    reservation,
+                ));
+            }
+
+            if let Some(name) = field.identifier().get_opt() {
+                if let Some(reservation) = reservations.iter().find(|r| r.contains_name(name)) {
+                    compiler.diagnose(super::diagnostic::err_reserved_field_name(
+                        name,
+                        reservation,
+                    ));
                 }
             }
         }
@@ -322,11 +341,8 @@ impl Semantic {
     fn analyze_options(&mut self, compiler: &mut super::Compiler, file: &mut File) {
         // Parse file options
         {
-            for (_, expressions) in file.options_mut() {
-                for expression in expressions {
-                    *expression = Expression::Value(self.analyse_expression(compiler, expression));
-                }
-            }
+            self.analyze_option_expressions(compiler, file, None);
+            Self::analyze_option_definitions(compiler, file, super::options::Scope::File);
 
             if let Some(opt) = file.option("cpp_namespace") {
                 let namespace = match opt.as_slice() {
@@ -338,75 +354,139 @@ impl Semantic {
 
                 file.file_options_mut().cpp_namespace = namespace;
             }
+
+            // Invalid entries are already reported and removed
+            if let Some([Expression::Value(value)]) = file
+                .option("message_buffer_size_bits")
+                .map(|opt| opt.as_slice())
+            {
+                match value {
+                    Value::Integer(16) => {
+                        file.file_options_mut().message_buffer_size_type = SizeType::UInt16;
+                    }
+                    Value::Integer(32) => {
+                        file.file_options_mut().message_buffer_size_type = SizeType::UInt32;
+                    }
+                    _ => {
+                        compiler.diagnose(super::diagnostic::err_invalid_message_buffer_size_bits(
+                            value,
+                            file.option_location("message_buffer_size_bits"),
+                        ));
+                    }
+                }
+            }
         }
 
+        // File options are the defaults of the structures and variants
+        let message_buffer = Self::analyze_reader_writer_option(file.option("message_buffer"));
+        let json = Self::analyze_reader_writer_option(file.option("json"));
+        let validate = Self::analyze_flag_option(file.option("validate"));
+
+        let integer_types = file
+            .data_types()
+            .iter()
+            .map(expression::IntegerType::from)
+            .collect::<Vec<Option<expression::IntegerType>>>();
+
         // Parse data type options
         for data_type in file.data_types_mut() {
             match data_type.kind_mut() {
                 DataTypeKind::Structure(structure) => {
-                    for (_, expressions) in structure.options_mut() {
-                        for expression in expressions {
-                            *expression =
-                                Expression::Value(self.analyse_expression(compiler, expression));
-                        }
-                    }
-
-                    if let Some(opt) = structure.option("message_buffer") {
-                        let (enable_reader, enable_writer) = match opt.as_slice() {
-                            [Expression::Value(v1), Expression::Value(v2)] => {
-                                (v1.is_true(), v2.is_true())
-                            }
-                            [Expression::Value(v1)] => (v1.is_true(), v1.is_true()),
-                            _ => (false, false),
-                        };
-
-                        structure.structure_options_mut().message_buffer =
-                            (enable_reader, enable_writer);
-                    }
-
-                    if let Some(opt) = structure.option("json") {
-                        let (enable_reader, enable_writer) = match opt.as_slice() {
-                            [Expression::Value(v1), Expression::Value(v2)] => {
-                                (v1.is_true(), v2.is_true())
-                            }
-                            [Expression::Value(v1)] => (v1.is_true(), v1.is_true()),
-                            _ => (false, false),
-                        };
-
-                        structure.structure_options_mut().json = (enable_reader, enable_writer);
-                    }
+                    self.analyze_option_expressions(compiler, structure, None);
+                    Self::analyze_option_definitions(
+                        compiler,
+                        structure,
+                        super::options::Scope::Structure,
+                    );
+
+                    structure.structure_options_mut().message_buffer =
+                        Self::analyze_reader_writer_option(structure.option("message_buffer"))
+                            .or(message_buffer)
+                            .unwrap_or_default();
+
+                    structure.structure_options_mut().json =
+                        Self::analyze_reader_writer_option(structure.option("json"))
+                            .or(json)
+                            .unwrap_or_default();
+
+                    structure.structure_options_mut().deprecated =
+                        Self::analyze_deprecated_option(structure.option("deprecated"));
+
+                    structure.structure_options_mut().validate =
+                        Self::analyze_flag_option(structure.option("validate"))
+                            .or(validate)
+                            .unwrap_or_default();
+
+                    self.analyze_field_options(compiler, structure.fields_mut(), &integer_types);
                 }
                 DataTypeKind::Variant(variant) => {
-                    for (_, expressions) in variant.options_mut() {
-                        for expression in expressions {
-                            *expression =
-                                Expression::Value(self.analyse_expression(compiler, expression));
+                    self.analyze_option_expressions(compiler, variant, None);
+                    Self::analyze_option_definitions(
+                        compiler,
+                        variant,
+                        super::options::Scope::Variant,
+                    );
+
+                    variant.variant_options_mut().message_buffer =
+                        Self::analyze_reader_writer_option(variant.option("message_buffer"))
+                            .or(message_buffer)
+                            .unwrap_or_default();
+
+                    variant.variant_options_mut().json =
+                        Self::analyze_reader_writer_option(variant.option("json"))
+                            .or(json)
+                            .unwrap_or_default();
+
+                    variant.variant_options_mut().deprecated =
+                        Self::analyze_deprecated_option(variant.option("deprecated"));
+
+                    variant.variant_options_mut().validate =
+                        Self::analyze_flag_option(variant.option("validate"))
+                            .or(validate)
+                            .unwrap_or_default();
+
+                    self.analyze_field_options(compiler, variant.fields_mut(), &integer_types);
+                }
+                DataTypeKind::Extern(extern_data_type) => {
+                    self.analyze_option_expressions(compiler, extern_data_type, None);
+                    Self::analyze_option_definitions(
+                        compiler,
+                        extern_data_type,
+                        super::options::Scope::Extern,
+                    );
+
+                    match extern_data_type.option("cpp").map(|opt| opt.as_slice()) {
+                        Some([Expression::Value(Value::Literal(cpp))]) => {
+                            extern_data_type.extern_options_mut().cpp = cpp.clone();
+                        }
+                        // Invalid entries are already reported
+                        _ if extern_data_type.option_location("cpp").is_some() => {}
+                        _ => {
+                            compiler.diagnose(super::diagnostic::err_miss_extern_cpp(
+                                extern_data_type.identifier(),
+                            ));
                         }
                     }
 
-                    if let Some(opt) = variant.option("message_buffer") {
-                        let (enable_reader, enable_writer) = match opt.as_slice() {
-                            [Expression::Value(v1), Expression::Value(v2)] => {
-                                (v1.is_true(), v2.is_true())
-                            }
-                            [Expression::Value(v1)] => (v1.is_true(), v1.is_true()),
-                            _ => (false, false),
-                        };
-
-                        variant.variant_options_mut().message_buffer =
-                            (enable_reader, enable_writer);
+                    if let Some([Expression::Value(Value::Literal(include))]) = extern_data_type
+                        .option("cpp_include")
+                        .map(|opt| opt.as_slice())
+                    {
+                        extern_data_type.extern_options_mut().cpp_include = Some(include.clone());
                     }
 
-                    if let Some(opt) = variant.option("json") {
-                        let (enable_reader, enable_writer) = match opt.as_slice() {
-                            [Expression::Value(v1), Expression::Value(v2)] => {
-                                (v1.is_true(), v2.is_true())
-                            }
-                            [Expression::Value(v1)] => (v1.is_true(), v1.is_true()),
-                            _ => (false, false),
-                        };
+                    if let Some([Expression::Value(Value::Literal(function))]) = extern_data_type
+                        .option("cpp_to_wire")
+                        .map(|opt| opt.as_slice())
+                    {
+                        extern_data_type.extern_options_mut().cpp_to_wire = function.clone();
+                    }
 
-                        variant.variant_options_mut().json = (enable_reader, enable_writer);
+                    if let Some([Expression::Value(Value::Literal(function))]) = extern_data_type
+                        .option("cpp_from_wire")
+                        .map(|opt| opt.as_slice())
+                    {
+                        extern_data_type.extern_options_mut().cpp_from_wire = function.clone();
                     }
                 }
                 _ => {}
@@ -414,290 +494,130 @@ impl Semantic {
         }
     }
 
-    fn analyse_expression(
+    fn analyze_field_options(
         &mut self,
         compiler: &mut super::Compiler,
-        expression: &Expression,
-    ) -> Value {
-        match expression {
-            Expression::Value(value) => value.clone(),
-            Expression::UnaryOperator(op, expression) => {
-                let value = self.analyse_expression(compiler, expression);
-                match value {
-                    Value::Null => Value::Null,
-                    Value::True => match op {
-                        UnaryOperator::LogicalNot => Value::False,
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operand(
-                                *op, &value,
-                            ));
-                            Value::Null
-                        }
-                    },
-                    Value::False => match op {
-                        UnaryOperator::LogicalNot => Value::True,
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operand(
-                                *op, &value,
-                            ));
-                            Value::Null
-                        }
-                    },
-                    Value::Integer(integer) => match op {
-                        UnaryOperator::Negation => Value::Integer(-integer),
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operand(
-                                *op, &value,
-                            ));
-                            Value::Null
-                        }
-                    },
-                    Value::Real(real) => match op {
-                        UnaryOperator::Negation => Value::Real(-real),
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operand(
-                                *op, &value,
-                            ));
-                            Value::Null
-                        }
-                    },
-                    Value::Literal(_) => {
-                        compiler.diagnose(super::diagnostic::err_invalid_expression_operand(
-                            *op, &value,
-                        ));
-                        Value::Null
+        fields: &mut [Field],
+        integer_types: &[Option<expression::IntegerType>],
+    ) {
+        for field in fields {
+            let integer_type = field
+                .reference()
+                .get_id()
+                .and_then(|id| integer_types.get(id).copied().flatten());
+
+            self.analyze_option_expressions(compiler, field, integer_type);
+            Self::analyze_option_definitions(compiler, field, super::options::Scope::Field);
+
+            field.field_options_mut().deprecated =
+                Self::analyze_deprecated_option(field.option("deprecated"));
+        }
+    }
+
+    /// Evaluates the arguments of every option entry.
+    ///
+    /// The bounds of a range are values of the field, so they are folded in the integer
+    /// type of the field if it has one.
+    fn analyze_option_expressions(
+        &mut self,
+        compiler: &mut super::Compiler,
+        optioned: &mut dyn Optioned,
+        integer_type: Option<expression::IntegerType>,
+    ) {
+        for name in optioned.option_names() {
+            let location = optioned.option_location(&name);
+            let integer_type = integer_type
+                .filter(|_| name == super::options::RANGE.name)
+                .unwrap_or(expression::IntegerType::INT64);
+
+            let mut is_valid = true;
+            if let Some(expressions) = optioned.option_mut(&name) {
+                for expression in expressions {
+                    match expression::evaluate(compiler, expression, location, integer_type) {
+                        Some(value) => *expression = Expression::Value(value),
+                        None => is_valid = false,
                     }
                 }
             }
-            Expression::BinaryOperator(expression_1, op, expression_2) => {
-                fn handle_integer(
-                    compiler: &mut super::Compiler,
-                    op: BinaryOperator,
-                    value_1: &Value,
-                    integer_1: i64,
-                    value_2: &Value,
-                    integer_2: i64,
-                ) -> Value {
-                    match op {
-                        BinaryOperator::Addition => Value::Integer(integer_1 + integer_2),
-                        BinaryOperator::Subtraction => Value::Integer(integer_1 - integer_2),
-                        BinaryOperator::Multiplication => Value::Integer(integer_1 * integer_2),
-                        BinaryOperator::Division => Value::Integer(integer_1 / integer_2),
-                        BinaryOperator::Modulo => Value::Integer(integer_1 % integer_2),
-                        BinaryOperator::Equal => {
-                            if integer_1 == integer_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        BinaryOperator::NotEqual => {
-                            if integer_1 != integer_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        BinaryOperator::GreaterThan => {
-                            if integer_1 > integer_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        BinaryOperator::GreaterThanEqual => {
-                            if integer_1 >= integer_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        BinaryOperator::LessThan => {
-                            if integer_1 < integer_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        BinaryOperator::LessThanEqual => {
-                            if integer_1 <= integer_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operands(
-                                op, value_1, value_2,
-                            ));
-                            Value::Null
-                        }
-                    }
-                }
-                fn handle_real(
-                    compiler: &mut super::Compiler,
-                    op: BinaryOperator,
-                    value_1: &Value,
-                    real_1: f64,
-                    value_2: &Value,
-                    real_2: f64,
-                ) -> Value {
-                    match op {
-                        BinaryOperator::Addition => Value::Real(real_1 + real_2),
-                        BinaryOperator::Subtraction => Value::Real(real_1 - real_2),
-                        BinaryOperator::Multiplication => Value::Real(real_1 * real_2),
-                        BinaryOperator::Division => Value::Real(real_1 / real_2),
-                        BinaryOperator::Modulo => Value::Real(real_1 % real_2),
-                        BinaryOperator::Equal => {
-                            if real_1 == real_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        BinaryOperator::NotEqual => {
-                            if real_1 != real_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        BinaryOperator::GreaterThan => {
-                            if real_1 > real_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        BinaryOperator::GreaterThanEqual => {
-                            if real_1 >= real_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        BinaryOperator::LessThan => {
-                            if real_1 < real_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        BinaryOperator::LessThanEqual => {
-                            if real_1 <= real_2 {
-                                Value::True
-                            } else {
-                                Value::False
-                            }
-                        }
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operands(
-                                op, value_1, value_2,
-                            ));
-                            Value::Null
-                        }
-                    }
-                }
 
-                let value_1 = self.analyse_expression(compiler, expression_1);
-                let value_2 = self.analyse_expression(compiler, expression_2);
-                match (&value_1, &value_2) {
-                    (Value::Null, Value::Null) => Value::Null,
-                    (Value::False, Value::False) => match op {
-                        BinaryOperator::LogicalAnd => Value::False,
-                        BinaryOperator::LogicalOr => Value::False,
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operands(
-                                *op, &value_1, &value_2,
-                            ));
-                            Value::Null
-                        }
-                    },
-                    (Value::True, Value::False) => match op {
-                        BinaryOperator::LogicalAnd => Value::False,
-                        BinaryOperator::LogicalOr => Value::True,
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operands(
-                                *op, &value_1, &value_2,
-                            ));
-                            Value::Null
-                        }
-                    },
+            // Errors in the arguments are already reported
+            if !is_valid {
+                optioned.options_mut().remove(&name);
+            }
+        }
+    }
 
-                    (Value::False, Value::True) => match op {
-                        BinaryOperator::LogicalAnd => Value::False,
-                        BinaryOperator::LogicalOr => Value::True,
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operands(
-                                *op, &value_1, &value_2,
-                            ));
-                            Value::Null
-                        }
-                    },
-                    (Value::True, Value::True) => match op {
-                        BinaryOperator::LogicalAnd => Value::True,
-                        BinaryOperator::LogicalOr => Value::True,
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operands(
-                                *op, &value_1, &value_2,
-                            ));
-                            Value::Null
-                        }
-                    },
-                    (Value::Integer(integer_1), Value::Integer(integer_2)) => {
-                        handle_integer(compiler, *op, &value_1, *integer_1, &value_2, *integer_2)
-                    }
-                    (Value::Real(real_1), Value::Real(real_2)) => {
-                        handle_real(compiler, *op, &value_1, *real_1, &value_2, *real_2)
-                    }
-                    (Value::Integer(integer), Value::Real(real)) => {
-                        handle_real(compiler, *op, &value_1, *integer as f64, &value_2, *real)
-                    }
-                    (Value::Real(real), Value::Integer(integer)) => {
-                        handle_real(compiler, *op, &value_1, *real, &value_2, *integer as f64)
-                    }
-                    (Value::Literal(literal_1), Value::Literal(literal_2)) => match op {
-                        BinaryOperator::Addition => {
-                            Value::Literal(format!("{}{}", literal_1, literal_2))
-                        }
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operands(
-                                *op, &value_1, &value_2,
-                            ));
-                            Value::Null
-                        }
-                    },
-                    (Value::Literal(literal), _) => match op {
-                        BinaryOperator::Addition => {
-                            Value::Literal(format!("{}{}", literal, value_2))
-                        }
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operands(
-                                *op, &value_1, &value_2,
-                            ));
-                            Value::Null
-                        }
-                    },
-                    (_, Value::Literal(literal)) => match op {
-                        BinaryOperator::Addition => {
-                            Value::Literal(format!("{}{}", value_1, literal))
-                        }
-                        _ => {
-                            compiler.diagnose(super::diagnostic::err_invalid_expression_operands(
-                                *op, &value_1, &value_2,
-                            ));
-                            Value::Null
-                        }
-                    },
-                    _ => {
-                        compiler.diagnose(super::diagnostic::err_invalid_expression_operands(
-                            *op, &value_1, &value_2,
+    /// Accepts `opt name;`, `opt name = enabled;` and `opt name(enable_reader, enable_writer);`.
+    fn analyze_reader_writer_option(opt: Option<&Vec<Expression>>) -> Option<(bool, bool)> {
+        match opt.map(|opt| opt.as_slice()) {
+            Some([Expression::Value(v1), Expression::Value(v2)]) => {
+                Some((v1.is_true(), v2.is_true()))
+            }
+            Some([Expression::Value(v1)]) => Some((v1.is_true(), v1.is_true())),
+            _ => None,
+        }
+    }
+
+    /// Accepts `opt name;` and `opt name = enabled;`.
+    fn analyze_flag_option(opt: Option<&Vec<Expression>>) -> Option<bool> {
+        match opt.map(|opt| opt.as_slice()) {
+            Some([Expression::Value(value)]) => Some(value.is_true()),
+            _ => None,
+        }
+    }
+
+    /// Accepts `opt deprecated;`, `opt deprecated = "message";` and `opt deprecated = false;`.
+    fn analyze_deprecated_option(opt: Option<&Vec<Expression>>) -> Option<String> {
+        match opt.map(|opt| opt.as_slice()) {
+            Some([Expression::Value(Value::Null | Value::True)]) => Some(String::new()),
+            Some([Expression::Value(Value::Literal(message))]) => Some(message.clone()),
+            _ => None,
+        }
+    }
+
+    fn analyze_deprecations(&mut self, compiler: &mut super::Compiler, file: &File) {
+        for data_type in file.data_types() {
+            // Deprecated data types may keep using other deprecated data types
+            if data_type.deprecated().is_some() {
+                continue;
+            }
+
+            let (fields, optioned): (&[Field], &dyn Optioned) = match data_type.kind() {
+                DataTypeKind::Structure(structure) => (structure.fields(), structure),
+                DataTypeKind::Variant(variant) => (variant.fields(), variant),
+                _ => continue,
+            };
+
+            for field in fields {
+                if field.field_options().deprecated.is_some()
+                    || lint::is_allowed(&super::lint::DEPRECATED, &[field, optioned, file])
+                {
+                    continue;
+                }
+
+                // Arrays, boxes and extern data types are followed to their elements
+                let mut reference_id = field.reference().get_id();
+                while let Some(dst_data_type) = reference_id.and_then(|id| file.data_type(id)) {
+                    if let Some(message) = dst_data_type.deprecated() {
+                        compiler.diagnose(super::diagnostic::warn_deprecated_data_type(
+                            data_type.identifier().get(),
+                            field,
+                            dst_data_type.identifier().get(),
+                            message,
                         ));
-                        Value::Null
+                        break;
                     }
+
+                    reference_id = match dst_data_type.kind() {
+                        DataTypeKind::Array(array) => array.reference().get_id(),
+                        DataTypeKind::Box(boxed) => boxed.reference().get_id(),
+                        DataTypeKind::Extern(extern_data_type) => {
+                            extern_data_type.reference().get_id()
+                        }
+                        _ => None,
+                    };
                 }
             }
-            _ => Value::Null,
         }
     }
 }
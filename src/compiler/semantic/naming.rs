@@ -0,0 +1,129 @@
+use super::super::ast::*;
+use super::super::naming::{Case, Conventions, Kind};
+
+use regex::Regex;
+
+/// Naming conventions with the compiled patterns of their cases.
+struct Checker {
+    conventions: Conventions,
+    patterns: Vec<(Case, Regex)>,
+}
+
+impl Checker {
+    fn new(conventions: Conventions) -> Self {
+        Self {
+            conventions,
+            patterns: Case::ALL
+                .iter()
+                .map(|case| (*case, Regex::new(case.pattern()).unwrap()))
+                .collect(),
+        }
+    }
+
+    /// Returns the case the identifier should use if it does not.
+    fn check(&self, kind: Kind, identifier: &Identifier) -> Option<Case> {
+        let case = self.conventions.get(kind)?;
+        let name = identifier.get_opt()?;
+
+        let (_, pattern) = self.patterns.iter().find(|(c, _)| *c == case)?;
+        (!pattern.is_match(name)).then_some(case)
+    }
+}
+
+impl super::Semantic {
+    pub(super) fn analyze_naming(&mut self, compiler: &mut super::super::Compiler, file: &File) {
+        let checker = Checker::new(Self::analyze_naming_conventions(compiler, file));
+        let lint = &super::super::lint::NAMING_CONVENTION;
+
+        for data_type in file.data_types() {
+            let (kind, fielded, optioned): (Kind, Option<&dyn Fielded>, &dyn Optioned) =
+                match data_type.kind() {
+                    DataTypeKind::Structure(structure) => {
+                        (Kind::Structure, Some(structure), structure)
+                    }
+                    DataTypeKind::Variant(variant) => (Kind::Variant, Some(variant), variant),
+                    DataTypeKind::Extern(extern_data_type) => {
+                        (Kind::Extern, None, extern_data_type)
+                    }
+                    _ => continue,
+                };
+
+            if let Some(case) = checker.check(kind, data_type.identifier()) {
+                if !super::lint::is_allowed(lint, &[optioned, file]) {
+                    compiler.diagnose(super::super::diagnostic::err_invalid_data_type_identifer(
+                        kind,
+                        data_type.identifier(),
+                        case,
+                    ));
+                }
+            }
+
+            for field in fielded.map(|fielded| fielded.fields()).unwrap_or_default() {
+                if let Some(case) = checker.check(Kind::Field, field.identifier()) {
+                    if !super::lint::is_allowed(lint, &[field, optioned, file]) {
+                        compiler.diagnose(super::super::diagnostic::err_invalid_field_identifer(
+                            field.identifier(),
+                            case,
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Services have no options, only the file can allow the lint
+        if super::lint::is_allowed(lint, &[file]) {
+            return;
+        }
+
+        for service in file.services() {
+            if let Some(case) = checker.check(Kind::Service, service.identifier()) {
+                compiler.diagnose(super::super::diagnostic::err_invalid_data_type_identifer(
+                    Kind::Service,
+                    service.identifier(),
+                    case,
+                ));
+            }
+
+            for rpc in service.rpcs() {
+                if let Some(case) = checker.check(Kind::Rpc, rpc.identifier()) {
+                    compiler.diagnose(super::super::diagnostic::err_invalid_data_type_identifer(
+                        Kind::Rpc,
+                        rpc.identifier(),
+                        case,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Returns the naming conventions of the compiler overridden by the file options
+    /// like `opt field_case = "camelCase";`.
+    fn analyze_naming_conventions(
+        compiler: &mut super::super::Compiler,
+        file: &File,
+    ) -> Conventions {
+        let mut conventions = *compiler.naming_conventions();
+
+        for kind in Kind::ALL {
+            let Some([Expression::Value(Value::Literal(name))]) =
+                file.option(kind.option_name()).map(|opt| opt.as_slice())
+            else {
+                continue;
+            };
+
+            match Case::from_name(name) {
+                Some(case) => conventions.set(*kind, case),
+                None => compiler.diagnose(super::super::diagnostic::err_unknown_naming_convention(
+                    name,
+                    file.option_location(kind.option_name()),
+                    super::super::suggestion::similar(
+                        name,
+                        Case::ALL.iter().map(|case| case.name()),
+                    ),
+                )),
+            }
+        }
+
+        conventions
+    }
+}
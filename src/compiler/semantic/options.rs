@@ -0,0 +1,91 @@
+use super::super::ast::*;
+use super::super::options::Scope;
+
+impl super::Semantic {
+    /// Checks the option entries against the registered options, entries that are not
+    /// valid are reported and removed so that later passes do not see them.
+    pub(super) fn analyze_option_definitions(
+        compiler: &mut super::super::Compiler,
+        optioned: &mut dyn Optioned,
+        scope: Scope,
+    ) {
+        // Reports follow the order of the entries in the source code
+        for name in optioned.option_names() {
+            let location = optioned.option_location(&name);
+
+            let Some(definition) = compiler.option_definition(&name, scope) else {
+                let scopes = compiler
+                    .option_definitions()
+                    .iter()
+                    .filter(|definition| definition.name == name)
+                    .flat_map(|definition| definition.scopes.iter().copied())
+                    .collect::<Vec<Scope>>();
+
+                if scopes.is_empty() {
+                    let similar = super::super::suggestion::similar(
+                        &name,
+                        compiler
+                            .option_definitions()
+                            .iter()
+                            .filter(|definition| definition.scopes.contains(&scope))
+                            .map(|definition| definition.name),
+                    );
+
+                    compiler.diagnose(super::super::diagnostic::err_unknown_option(
+                        &name, scope, location, similar,
+                    ));
+                } else {
+                    compiler.diagnose(super::super::diagnostic::err_misplaced_option(
+                        &name, scope, &scopes, location,
+                    ));
+                }
+
+                optioned.options_mut().remove(&name);
+                continue;
+            };
+
+            let arguments = match optioned.option(&name).map(|opt| opt.as_slice()) {
+                // `opt name;` has no arguments
+                Some([Expression::Value(Value::Null)]) => &[][..],
+                Some(arguments) => arguments,
+                None => continue,
+            };
+
+            let is_valid = if arguments.len() < definition.min_arguments
+                || definition
+                    .max_arguments
+                    .is_some_and(|max| arguments.len() > max)
+            {
+                compiler.diagnose(super::super::diagnostic::err_invalid_option_argument_count(
+                    definition,
+                    arguments.len(),
+                    location,
+                ));
+                false
+            } else {
+                let mut is_valid = true;
+
+                for (index, argument) in arguments.iter().enumerate() {
+                    let value = argument.as_value();
+
+                    if let Some(expected) = definition.argument_type(index) {
+                        if !expected.accepts(value) {
+                            compiler.diagnose(
+                                super::super::diagnostic::err_invalid_option_argument_type(
+                                    &name, index, expected, value, location,
+                                ),
+                            );
+                            is_valid = false;
+                        }
+                    }
+                }
+
+                is_valid
+            };
+
+            if !is_valid {
+                optioned.options_mut().remove(&name);
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use super::super::ast::*;
+
+impl super::Semantic {
+    pub(super) fn analyze_services(
+        &mut self,
+        compiler: &mut super::super::Compiler,
+        file: &mut File,
+    ) {
+        let mut data_type_names = HashMap::new();
+        for (id, data_type) in file.data_types().iter().enumerate() {
+            if let Some(name) = data_type.identifier().get_opt() {
+                data_type_names.entry(name.as_str()).or_insert(id);
+            }
+        }
+
+        let mut service_names = HashMap::new();
+        let mut service_ids = Vec::with_capacity(file.services().len());
+
+        for service in file.services() {
+            if let Some(name) = service.identifier().get_opt() {
+                if let Some(first) = service_names.insert(name.as_str(), service.identifier()) {
+                    compiler.diagnose(super::super::diagnostic::err_redefined_service(
+                        service.identifier(),
+                        first,
+                    ));
+                }
+            }
+
+            let mut rpc_names = HashMap::new();
+            let mut rpc_ids = Vec::with_capacity(service.rpcs().len());
+
+            for rpc in service.rpcs() {
+                if let Some(name) = rpc.identifier().get_opt() {
+                    if let Some(first) = rpc_names.insert(name.as_str(), rpc.identifier()) {
+                        compiler.diagnose(super::super::diagnostic::err_redefined_rpc(
+                            rpc.identifier(),
+                            first,
+                        ));
+                    }
+                }
+
+                rpc_ids.push((
+                    Self::resolve_rpc_data_type(
+                        compiler,
+                        file,
+                        &data_type_names,
+                        rpc,
+                        rpc.request(),
+                    ),
+                    Self::resolve_rpc_data_type(
+                        compiler,
+                        file,
+                        &data_type_names,
+                        rpc,
+                        rpc.response(),
+                    ),
+                ));
+            }
+
+            service_ids.push(rpc_ids);
+        }
+
+        // Link data types
+        for (service, rpc_ids) in file.services_mut().iter_mut().zip(service_ids) {
+            for (rpc, (request_id, response_id)) in service.rpcs_mut().iter_mut().zip(rpc_ids) {
+                if let Some(request_id) = request_id {
+                    rpc.request_mut().set_id(request_id);
+                }
+                if let Some(response_id) = response_id {
+                    rpc.response_mut().set_id(response_id);
+                }
+            }
+        }
+    }
+
+    /// Resolves the data type of a request or response through aliases.
+    fn resolve_rpc_data_type(
+        compiler: &mut super::super::Compiler,
+        file: &File,
+        data_type_names: &HashMap<&str, usize>,
+        rpc: &Rpc,
+        reference: &Reference,
+    ) -> Option<usize> {
+        let mut name = reference.get_opt()?.as_str();
+
+        loop {
+            let Some(&id) = data_type_names.get(name) else {
+                compiler.diagnose(super::super::diagnostic::err_undeclared_data_type(
+                    name,
+                    reference.location(),
+                    super::super::suggestion::similar(name, data_type_names.keys().copied()),
+                ));
+                return None;
+            };
+
+            let data_type = file.data_type(id)?;
+            let is_message = match data_type.kind() {
+                DataTypeKind::Alias(alias) => {
+                    name = alias.reference().get_opt()?.as_str();
+                    continue;
+                }
+                DataTypeKind::Structure(structure) => {
+                    structure.structure_options().message_buffer == (true, true)
+                }
+                DataTypeKind::Variant(variant) => {
+                    variant.variant_options().message_buffer == (true, true)
+                }
+                _ => false,
+            };
+
+            if !is_message {
+                compiler.diagnose(super::super::diagnostic::err_invalid_rpc_data_type(
+                    rpc.identifier().get(),
+                    reference,
+                ));
+                return None;
+            }
+
+            return Some(id);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! "Did you mean" suggestions for misspelled names.
+
+/// Keywords that start a declaration at the top level of a file.
+pub const TOP_LEVEL_KEYWORDS: &[&str] = &["opt", "struct", "variant", "service", "extern"];
+
+/// Keywords that start an entry inside of a structure or variant body.
+pub const BODY_KEYWORDS: &[&str] = &["var", "opt", "reserved"];
+
+/// Returns the candidate closest to the name by edit distance, if it is close enough
+/// to be a typo.
+///
+/// Up to a third of the characters may differ, a difference only in case always
+/// matches.
+pub fn similar<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name && is_identifier(candidate))
+        .filter_map(|candidate| {
+            if candidate.eq_ignore_ascii_case(name) {
+                return Some((0, candidate));
+            }
+
+            let distance = edit_distance(name, candidate);
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        // Ties are broken alphabetically so that suggestions do not depend on hash order
+        .min_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(b.1)))
+        .map(|(_, candidate)| candidate)
+}
+
+/// Edit distance in characters where swapping two adjacent characters counts as one edit
+/// (optimal string alignment), as transpositions are common typos.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<char>>();
+    let b = b.chars().collect::<Vec<char>>();
+
+    // distances[i][j] is the distance between the first i characters of a and j of b
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+/// Synthesized names like `[int32]` or `string<16>` are never suggested.
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
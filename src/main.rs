@@ -1,15 +1,17 @@
 #![allow(dead_code)]
 
 use std::{
-    io::{Read, Write},
-    path::PathBuf,
+    io::{IsTerminal, Read, Write},
+    path::{Path, PathBuf},
 };
 
-use anyhow::{Context, Result};
-use clap::Parser;
+use anyhow::{bail, Context, Result};
+use clap::{Parser, ValueEnum};
 
 mod compiler;
 
+use compiler::ast::{DataTypeKind, Identified};
+
 #[derive(Parser)]
 enum Cli {
     Build {
@@ -20,7 +22,87 @@ enum Cli {
 
         #[clap(long = "cpp-path")]
         cpp_path: Option<String>,
+
+        /// Fail if the generated files differ from the files on disk instead of writing them
+        #[clap(long = "check", requires = "cpp")]
+        check: bool,
+
+        /// When to colour diagnostics
+        #[clap(long = "color", value_enum, default_value_t = Color::Auto)]
+        color: Color,
+
+        /// How to print diagnostics
+        #[clap(long = "message-format", value_enum, default_value_t = MessageFormat::Human)]
+        message_format: MessageFormat,
+
+        /// Lints to allow
+        #[clap(short = 'A', long = "allow", value_name = "LINT")]
+        allow: Vec<String>,
+
+        /// Lints to report as warnings
+        #[clap(short = 'W', long = "warn", value_name = "LINT")]
+        warn: Vec<String>,
+
+        /// Lints to report as errors
+        #[clap(short = 'D', long = "deny", value_name = "LINT")]
+        deny: Vec<String>,
+
+        /// Report every warning as an error
+        #[clap(long = "deny-warnings")]
+        deny_warnings: bool,
+
+        /// Naming conventions file of lines like `field_case = "camelCase"`
+        #[clap(long = "config", value_name = "PATH")]
+        config: Option<String>,
     },
+    /// Print the byte offsets of the fields in a message buffer
+    Layout {
+        path: String,
+
+        /// Only print the layout of this structure or variant
+        #[clap(long = "type", value_name = "NAME")]
+        type_name: Option<String>,
+    },
+    /// Print the schema hashes of the structures and variants
+    Hash {
+        path: String,
+
+        /// Only print the hash of this structure or variant
+        #[clap(long = "type", value_name = "NAME")]
+        type_name: Option<String>,
+    },
+    /// List the available lints
+    Lints,
+    /// Explain a diagnostic code like E0012
+    Explain { code: String },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Color {
+    /// Colour when printing to a terminal
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum MessageFormat {
+    /// Source snippets with underlines
+    Human,
+    /// One JSON object per line and diagnostic
+    Json,
+    /// A single SARIF log for code scanning
+    Sarif,
+}
+
+impl Color {
+    fn is_enabled(self) -> bool {
+        match self {
+            Color::Auto => std::io::stdout().is_terminal(),
+            Color::Always => true,
+            Color::Never => false,
+        }
+    }
 }
 
 fn main() -> Result<()> {
@@ -31,26 +113,73 @@ fn main() -> Result<()> {
             path,
             cpp,
             cpp_path,
+            check,
+            color,
+            message_format,
+            allow,
+            warn,
+            deny,
+            deny_warnings,
+            config,
         } => {
             let path = PathBuf::from(path);
 
+            let mut compiler = compiler::Compiler::new();
+
+            // Configure lints
+            let levels = [
+                (allow, compiler::lint::Level::Allow),
+                (warn, compiler::lint::Level::Warn),
+                (deny, compiler::lint::Level::Deny),
+            ];
+            for (names, level) in levels {
+                for name in names {
+                    if !compiler.set_lint_level(&name, level) {
+                        bail!("Unknown lint '{name}'");
+                    }
+                }
+            }
+            compiler.set_deny_warnings(deny_warnings);
+
+            // Configure naming conventions, file options override them
+            if let Some(config) = config {
+                let text = std::fs::read_to_string(&config)
+                    .with_context(|| format!("Failed to read config file '{config}'"))?;
+                match compiler::naming::parse_config(&text) {
+                    Ok(conventions) => {
+                        for (kind, case) in conventions {
+                            compiler.set_naming_convention(kind, case);
+                        }
+                    }
+                    Err(error) => bail!("Invalid config file '{config}', {error}"),
+                }
+            }
+
             // Compile
-            let input = {
-                let mut file = std::fs::File::open(&path)
-                    .with_context(|| format!("Failed to open input file"))?;
-                let mut input = String::new();
-                file.read_to_string(&mut input)
-                    .with_context(|| format!("Failed to read from input file"))?;
-                input
-            };
+            let input = read_input(&path)?;
 
-            let mut compiler = compiler::Compiler::new();
-            let file = compiler.compile(&input).with_context(|| {
-                for report in compiler.reports() {
-                    print!("{}\n", report.with_source_code(&input));
+            let file = compiler.compile(&input);
+
+            let file_name = path.display().to_string();
+            match message_format {
+                MessageFormat::Human => {
+                    print_reports(&compiler, &file_name, &input, color.is_enabled());
+                }
+                MessageFormat::Json => {
+                    for report in compiler.reports() {
+                        println!(
+                            "{}",
+                            compiler::message_format::to_json(report, &file_name, &input)
+                        );
+                    }
                 }
-                format!("Failed to compile file")
-            })?;
+                MessageFormat::Sarif => println!(
+                    "{}",
+                    compiler::message_format::to_sarif(compiler.reports(), &file_name, &input)
+                ),
+            }
+
+            let file = file.with_context(|| "Failed to compile file".to_string())?;
 
             // Generate
             {
@@ -63,17 +192,166 @@ fn main() -> Result<()> {
                         None => path.with_extension("g.hpp"),
                     };
 
-                    let mut output_file = std::fs::File::create(output_path)
-                        .with_context(|| format!("Failed to open output file"))?;
                     let code = generator.generate_cplusplus();
 
-                    output_file
-                        .write(code.as_bytes())
-                        .with_context(|| format!("Failed to write to output file"))?;
+                    if check {
+                        let current = std::fs::read(&output_path).with_context(|| {
+                            format!("Failed to read output file '{}'", output_path.display())
+                        })?;
+                        if current != code.as_bytes() {
+                            bail!("Output file '{}' is out of date", output_path.display());
+                        }
+                    } else {
+                        let mut output_file = std::fs::File::create(output_path)
+                            .with_context(|| "Failed to open output file".to_string())?;
+
+                        output_file
+                            .write_all(code.as_bytes())
+                            .with_context(|| "Failed to write to output file".to_string())?;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+        Cli::Layout { path, type_name } => {
+            let file = compile_messages(&path)?;
+
+            // Print the structures and variants in declaration order
+            let data_types = find_messages(&file, type_name.as_deref())?;
+            let tables = data_types
+                .iter()
+                .map(|data_type| compiler::layout::to_table(&file, data_type))
+                .collect::<Vec<String>>();
+            print!("{}", tables.join("\n"));
+
+            Ok(())
+        }
+        Cli::Hash { path, type_name } => {
+            let file = compile_messages(&path)?;
+
+            // A single hash is printed alone, so it can be compared in scripts
+            let data_types = find_messages(&file, type_name.as_deref())?;
+            match (type_name, data_types.as_slice()) {
+                (Some(_), [data_type]) => println!("{:016x}", data_type.fingerprint()),
+                _ => {
+                    for data_type in data_types {
+                        println!(
+                            "{:016x}  {}",
+                            data_type.fingerprint(),
+                            data_type.identifier()
+                        );
+                    }
                 }
             }
 
             Ok(())
         }
+        Cli::Lints => {
+            let width = compiler::lint::LINTS
+                .iter()
+                .map(|lint| lint.name.len())
+                .max()
+                .unwrap_or(0);
+            for lint in compiler::lint::LINTS {
+                println!(
+                    "{:<width$} {:<6} {}",
+                    lint.name,
+                    lint.default_level.to_string(),
+                    lint.description
+                );
+            }
+
+            Ok(())
+        }
+        Cli::Explain { code } => {
+            match compiler::error_codes::explain(&code) {
+                Some(explanation) => print!("{explanation}"),
+                None => bail!("'{code}' is not a diagnostic code"),
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Compiles a file for the commands inspecting its structures and variants.
+fn compile_messages(path: &str) -> Result<compiler::ast::File> {
+    let path = PathBuf::from(path);
+
+    let mut compiler = compiler::Compiler::new();
+
+    let input = read_input(&path)?;
+
+    let file = compiler.compile(&input);
+    print_reports(
+        &compiler,
+        &path.display().to_string(),
+        &input,
+        Color::Auto.is_enabled(),
+    );
+
+    file.with_context(|| "Failed to compile file".to_string())
+}
+
+/// Returns the structures and variants in declaration order, or only the one named.
+fn find_messages<'a>(
+    file: &'a compiler::ast::File,
+    type_name: Option<&str>,
+) -> Result<Vec<&'a compiler::ast::DataType>> {
+    let data_types = file
+        .data_types()
+        .iter()
+        .filter(|data_type| {
+            matches!(
+                data_type.kind(),
+                DataTypeKind::Structure(_) | DataTypeKind::Variant(_)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let Some(type_name) = type_name else {
+        return Ok(data_types);
+    };
+
+    match data_types
+        .iter()
+        .find(|data_type| data_type.identifier().get() == type_name)
+    {
+        Some(data_type) => Ok(vec![*data_type]),
+        None => {
+            let names = data_types
+                .iter()
+                .map(|data_type| data_type.identifier().get());
+            match compiler::suggestion::similar(type_name, names) {
+                Some(similar) => {
+                    bail!("No structure or variant named '{type_name}', did you mean '{similar}'?")
+                }
+                None => bail!("No structure or variant named '{type_name}'"),
+            }
+        }
+    }
+}
+
+fn read_input(path: &Path) -> Result<String> {
+    let mut file =
+        std::fs::File::open(path).with_context(|| "Failed to open input file".to_string())?;
+    let mut input = String::new();
+    file.read_to_string(&mut input)
+        .with_context(|| "Failed to read from input file".to_string())?;
+
+    Ok(input)
+}
+
+/// Prints the diagnostics with source snippets.
+fn print_reports(compiler: &compiler::Compiler, file_name: &str, input: &str, colored: bool) {
+    for report in compiler.reports() {
+        print!("{}\n\n", report.with_source_code(file_name, input, colored));
+    }
+    if let Some(report) = compiler.reports().first() {
+        println!(
+            "For more information about a diagnostic, try `pbc explain {}`.",
+            report.code
+        );
     }
 }
@@ -0,0 +1,64 @@
+//! Outputs of the `pbc` subcommands.
+//!
+//! Every `tests/commands/*.args` file holds the arguments of one `pbc` invocation, run
+//! from the root of the crate, and its output is compared with the `.stdout` file next
+//! to it. Failing invocations also record their exit status and error output. Run with
+//! `PBC_BLESS=1` to update the expected output after an intended change.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn commands() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let bless = std::env::var_os("PBC_BLESS").is_some();
+
+    let mut invocations = std::fs::read_dir(root.join("tests/commands"))
+        .expect("Failed to read commands directory")
+        .map(|entry| entry.expect("Failed to read commands entry").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "args"))
+        .collect::<Vec<_>>();
+    invocations.sort();
+    assert!(!invocations.is_empty(), "Commands directory is empty");
+
+    let mut failures = Vec::new();
+    for invocation in &invocations {
+        let relative_path = invocation.strip_prefix(root).unwrap();
+        let args = std::fs::read_to_string(invocation).expect("Failed to read arguments");
+        let output = Command::new(env!("CARGO_BIN_EXE_pbc"))
+            .current_dir(root)
+            .args(args.split_whitespace())
+            .env("RUST_BACKTRACE", "0")
+            .output()
+            .expect("Failed to run pbc");
+        let mut actual = String::from_utf8(output.stdout).expect("Output is not UTF-8");
+        if !output.status.success() {
+            actual.push_str(&format!(
+                "--- {}\n{}",
+                output.status,
+                String::from_utf8(output.stderr).expect("Error output is not UTF-8")
+            ));
+        }
+
+        let expected_path = invocation.with_extension("stdout");
+        if bless {
+            std::fs::write(&expected_path, &actual).expect("Failed to write expected output");
+            continue;
+        }
+
+        let expected = std::fs::read_to_string(&expected_path).unwrap_or_default();
+        if actual != expected {
+            failures.push(format!(
+                "{} ({})\n--- expected\n{expected}\n--- actual\n{actual}",
+                relative_path.display(),
+                args.trim()
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Outputs differ from the expected ones:\n\n{}",
+        failures.join("\n")
+    );
+}
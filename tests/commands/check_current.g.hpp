@@ -0,0 +1,32 @@
+#pragma once
+#include "message_buffer.hpp"
+#include <rapidjson/document.h>
+#include <array>
+
+class Position;
+
+class Position
+{
+private:
+
+	float x;
+	float y;
+
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0x501ae2c9cae089e8ull;
+
+	const float& GetX() const { return this->x; }
+	Position& SetX(const float& x) { this->x = x; return *this; }
+
+	const float& GetY() const { return this->y; }
+	Position& SetY(const float& y) { this->y = y; return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		return nullptr;
+	}
+};
+
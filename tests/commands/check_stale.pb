@@ -0,0 +1,4 @@
+struct Position {
+    var x: single;
+    var y: single;
+}
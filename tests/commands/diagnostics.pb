@@ -0,0 +1,9 @@
+struct Player {
+    var name: strin;
+    var level: uint8 {
+        opt range(1, 300);
+    }
+}
+
+struct empty {
+}
@@ -0,0 +1,20 @@
+opt message_buffer;
+
+struct Position {
+    var x: single;
+    var y: single;
+}
+
+struct Player {
+    var id: uint32;
+    var name: string<16>;
+    var position: Position;
+    var alive: bool;
+    var scores: [uint16; max 8];
+    var title: string;
+}
+
+variant Event {
+    var moved: Position;
+    var died: bool;
+}
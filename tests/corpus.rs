@@ -0,0 +1,54 @@
+//! Regression corpus of malformed schemas.
+//!
+//! Every `tests/corpus/*.pb` schema is compiled and the printed diagnostics are compared
+//! with the `.stdout` file next to it. Run with `PBC_BLESS=1` to update the expected
+//! output after an intended change.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn corpus() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let bless = std::env::var_os("PBC_BLESS").is_some();
+
+    let mut schemas = std::fs::read_dir(root.join("tests/corpus"))
+        .expect("Failed to read corpus directory")
+        .map(|entry| entry.expect("Failed to read corpus entry").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "pb"))
+        .collect::<Vec<_>>();
+    schemas.sort();
+    assert!(!schemas.is_empty(), "Corpus is empty");
+
+    let mut failures = Vec::new();
+    for schema in &schemas {
+        let relative_path = schema.strip_prefix(root).unwrap();
+        let output = Command::new(env!("CARGO_BIN_EXE_pbc"))
+            .current_dir(root)
+            .args(["build", "--color", "never"])
+            .arg(relative_path)
+            .output()
+            .expect("Failed to run pbc");
+        let actual = String::from_utf8(output.stdout).expect("Output is not UTF-8");
+
+        let expected_path = schema.with_extension("stdout");
+        if bless {
+            std::fs::write(&expected_path, &actual).expect("Failed to write expected output");
+            continue;
+        }
+
+        let expected = std::fs::read_to_string(&expected_path).unwrap_or_default();
+        if actual != expected {
+            failures.push(format!(
+                "{}\n--- expected\n{expected}\n--- actual\n{actual}",
+                relative_path.display()
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Diagnostics differ from the corpus:\n\n{}",
+        failures.join("\n")
+    );
+}
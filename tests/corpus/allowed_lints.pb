@@ -0,0 +1,13 @@
+struct Player {
+    opt allow("unknown_lints", "future_lint");
+
+    var name: string {
+        opt allow = "deprecatd";
+    }
+}
+
+struct Monster {
+    opt allow = "emtpy_body";
+
+    var name: string;
+}
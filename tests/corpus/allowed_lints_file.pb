@@ -0,0 +1,7 @@
+opt allow("unknown_lints", "future_lint");
+
+struct Monster {
+    opt allow = "emtpy_body";
+
+    var name: string;
+}
@@ -0,0 +1,17 @@
+struct Player {
+    var name: string {
+        opt max_len = 64 / 0;
+    }
+    var score: int64 {
+        opt range(0, 9223372036854775807 * 2);
+    }
+    var level: uint8 {
+        opt range(0 - 1, 100 * 3);
+    }
+    var delta: int8 {
+        opt range(-128, 127);
+    }
+    var speed: double {
+        opt range(0.0, 1.5 % 0.0);
+    }
+}
@@ -0,0 +1,22 @@
+struct Old {
+    opt deprecated = "use New";
+
+    var id: uint32;
+}
+
+struct New {
+    var id: uint32;
+}
+
+struct Inventory {
+    var current: Old;
+    var all: [Old];
+    var few: [Old; max 4];
+    var parent: box<Old>;
+    var nested: [box<Old>];
+    var kept: [New];
+
+    var legacy: [Old] {
+        opt deprecated;
+    }
+}
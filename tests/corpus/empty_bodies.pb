@@ -0,0 +1,14 @@
+struct Heartbeat {
+}
+
+variant Signal {
+    opt json;
+}
+
+struct Ping {
+    opt allow = "empty_body";
+}
+
+struct Pong {
+    var sequence: uint32;
+}
@@ -0,0 +1,16 @@
+struct Player {
+    var name: ü;
+    var größe: uint32;
+    var health: uint32 {
+        opt range(0, 99999999999999999999);
+    }
+}
+
+struct Team {
+    var players: [Player];
+
+struct Score € {
+    var points: int64;
+}
+
+opt cpp_namespace = "game;
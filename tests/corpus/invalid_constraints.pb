@@ -0,0 +1,25 @@
+struct Player {
+    var name: string {
+        opt range(1, 10);
+    }
+    var level: uint8 {
+        opt max_len(4);
+        opt pattern("[0-9]+");
+    }
+    var alive: bool {
+        opt non_empty;
+    }
+    var title: string {
+        opt max_len(-1);
+        opt pattern(42);
+    }
+    var score: int32 {
+        opt range("low", "high");
+    }
+    var health: uint32 {
+        opt range(100, 1);
+    }
+    var speed: double {
+        opt range(2.5, 0.5);
+    }
+}
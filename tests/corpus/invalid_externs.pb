@@ -0,0 +1,19 @@
+struct Position {
+    var x: single;
+}
+
+extern type EntityId: uint64 {
+    cpp_include = "game/entity_id.hpp";
+}
+
+extern type Transform: Position {
+    cpp = "game::Transform";
+}
+
+extern type Name: string {
+    cpp = "game::Name";
+}
+
+extern type Handle: EntityId {
+    cpp = "game::Handle";
+}
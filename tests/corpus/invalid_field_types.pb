@@ -0,0 +1,9 @@
+struct Player {
+    var health: 100;
+    var armor: ;
+    var name: string<0>;
+    var tags: [string; 8];
+    var scores: [uint32; max 8;
+    var price: decimal<40>;
+    var level: uint8;
+}
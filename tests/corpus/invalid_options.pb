@@ -0,0 +1,12 @@
+opt namespace "game";
+opt max_players = 4 8;
+opt : 1;
+
+struct Player {
+    opt json
+    var health: uint32 {
+        opt range(0, 100;
+        var armor: uint32;
+    }
+    var armor: uint32;
+}
@@ -0,0 +1,26 @@
+struct Player {
+    var name: string {
+        opt pattern("(?i)abc");
+    }
+    var letters: string {
+        opt pattern("\\p{L}+");
+    }
+    var group: string {
+        opt pattern("(?P<n>x)");
+    }
+    var text: string {
+        opt pattern("\\Aabc\\z");
+    }
+    var hex: string {
+        opt pattern("\\x{41}");
+    }
+    var nested: string {
+        opt pattern("[a-z&&[^aeiou]]");
+    }
+    var unclosed: string {
+        opt pattern("[a-z");
+    }
+    var shared: string {
+        opt pattern("^(?:[a-z_][a-z0-9_]*|\\d{2,4})\\b[[:alpha:]\\s.-]*?$");
+    }
+}
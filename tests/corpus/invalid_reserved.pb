@@ -0,0 +1,11 @@
+struct Player {
+    reserved health;
+    reserved 2 to;
+    reserved 3, "mana" "armor";
+    var health: uint32;
+}
+
+struct Team {
+    reserved 4;
+    var size: uint8;
+}
@@ -0,0 +1,18 @@
+struct Request {
+    opt message_buffer;
+    var name: string;
+}
+
+service Lobby {
+    rpc Join Request returns (Request);
+    rpc Leave(Request) -> (Request);
+    rpc Kick(Request) returns (Request)
+    var name: string;
+    rpc Ban(Request) returns (Request);
+}
+
+extern type EntityId: uint64 {
+    cpp = "game::EntityId"
+    cpp_include = "game/entity_id.hpp";
+    var id: uint64;
+}
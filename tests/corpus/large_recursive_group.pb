@@ -0,0 +1,647 @@
+struct Node0 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node1 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node2 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node3 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node4 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node5 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node6 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node7 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node8 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node9 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node10 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node11 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node12 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node13 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node14 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node15 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node16 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node17 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node18 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node19 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node20 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node21 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node22 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
+
+struct Node23 {
+    var next0: box<Node0>;
+    var next1: box<Node1>;
+    var next2: box<Node2>;
+    var next3: box<Node3>;
+    var next4: box<Node4>;
+    var next5: box<Node5>;
+    var next6: box<Node6>;
+    var next7: box<Node7>;
+    var next8: box<Node8>;
+    var next9: box<Node9>;
+    var next10: box<Node10>;
+    var next11: box<Node11>;
+    var next12: box<Node12>;
+    var next13: box<Node13>;
+    var next14: box<Node14>;
+    var next15: box<Node15>;
+    var next16: box<Node16>;
+    var next17: box<Node17>;
+    var next18: box<Node18>;
+    var next19: box<Node19>;
+    var next20: box<Node20>;
+    var next21: box<Node21>;
+    var next22: box<Node22>;
+    var next23: box<Node23>;
+}
@@ -0,0 +1,10 @@
+/*
+ * Multi-line comments, **emphasis**, CRLF line endings and tabs
+ */
+struct Player {
+	var health: uint32;
+	var position: Vectr;
+}
+
+/* inline */ struct Team { var players: [Playr]; }
+// trailing comment without a line break
\ No newline at end of file
@@ -0,0 +1,14 @@
+opt message_buffer;
+opt message_buffer_size_bits = 24;
+
+struct Chat {
+    var lines: [string<1000>; max 100];
+}
+
+struct Upload {
+    var chunks: [[uint8; max 100000]; max 2];
+}
+
+struct Attachment {
+    var data: box<[uint8; max 100000]>;
+}
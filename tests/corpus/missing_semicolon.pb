@@ -0,0 +1,9 @@
+struct Player {
+    var health: uint32
+    var armor: uint32;
+    var name: string = "player";
+}
+
+struct Team {
+    var players: [Player];
+}
@@ -0,0 +1,19 @@
+strct Position {
+    var x: single;
+}
+
+struct Player {
+    vr level: uint8;
+}
+
+struct Request {
+    var name: string;
+}
+
+service Lobby {
+    rpc Join(Request) retrns (Request);
+}
+
+extern tpe EntityId: uint64 {
+    cpp = "game::EntityId";
+}
@@ -0,0 +1,14 @@
+opt field_case = "snake_cas";
+opt mesage_buffer;
+
+struct Position {
+    var x: single;
+}
+
+struct Player {
+    opt allow = "empty_bdy";
+
+    var position: Positon;
+    var name: strng;
+    var scores: [unt32];
+}
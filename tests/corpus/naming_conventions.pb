@@ -0,0 +1,27 @@
+opt field_case = "camelCase";
+opt service_case = "PascalCase";
+opt rpc_case = "snake";
+
+struct player_state {
+    opt message_buffer;
+
+    var maxHealth: uint32;
+    var max_mana: uint32;
+}
+
+struct Legacy {
+    opt allow = "naming_convention";
+
+    var Old_Name: int32;
+}
+
+variant Event {
+    var player: player_state;
+    var legacy_id: int32 {
+        opt allow = "naming_convention";
+    }
+}
+
+service chat {
+    rpc Send(player_state) returns (player_state);
+}
@@ -0,0 +1,13 @@
+struct Node {
+    var parent: box<box<Node>>;
+    var label: box<string<8>>;
+    var price: box<decimal<2>>;
+    var ancestors: [box<box<Node>>];
+    var spaced: box<string<8> >;
+    var deep: box<box<box<Node>>>;
+}
+
+struct Broken {
+    var extra: box<box<Node>> >;
+    var missing: box<box<Node>;
+}
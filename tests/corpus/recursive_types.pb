@@ -0,0 +1,24 @@
+struct Tree {
+    var name: string;
+    var children: [Tree];
+    var parent: box<Tree>;
+}
+
+variant Expression {
+    var number: int64;
+    var negated: box<Expression>;
+    var operation: Operation;
+}
+
+struct Operation {
+    var left: box<Expression>;
+    var right: box<Expression>;
+}
+
+struct Chain {
+    var links: [Chain; max 4];
+}
+
+struct Pending {
+    var value: box<Missing>;
+}
@@ -0,0 +1,17 @@
+opt cpp_namespace = "game";
+opt cpp_namespace = "engine";
+
+extern type Timestamp : int64 {
+    cpp = "std::time_t";
+    cpp = "int64_t";
+}
+
+struct Player {
+    opt json = true;
+    opt json = false;
+
+    var health: uint32 {
+        opt range(0, 100);
+        opt range(0, 200);
+    }
+}
@@ -0,0 +1,14 @@
+struct Player {
+    var name: string;
+    var level: uint8;
+    var name: string;
+}
+
+variant Event {
+    var joined: Player;
+    var joined: uint32;
+}
+
+struct Player {
+    var id: uint32;
+}
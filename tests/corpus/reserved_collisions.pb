@@ -0,0 +1,17 @@
+struct Player {
+    reserved 0, 5 to 3;
+    reserved 2, "health";
+
+    var name: string;
+    var level: uint8;
+    var health: uint32;
+}
+
+variant Event {
+    reserved 1 to 2;
+    reserved "left";
+
+    var joined: string;
+    var left: string;
+    var chat: string;
+}
@@ -0,0 +1,19 @@
+struct Request {
+    opt message_buffer;
+
+    var name: string;
+}
+
+struct Reply {
+    var accepted: bool;
+}
+
+service Lobby {
+    rpc Join(Request) returns (Request);
+    rpc Join(Request) returns (Request);
+    rpc Leave(Request) returns (Reply);
+}
+
+service Lobby {
+    rpc Kick(Request) returns (Request);
+}
@@ -0,0 +1,17 @@
+strcut Vector {
+    var x: single;
+    var y: single;
+}
+
+struct Player {
+    vra health: uint32;
+    var position: Vector;
+    health = 100;
+    var armor: uint32;
+}
+
+= 42 ;
+
+struct Team {
+    var size: uint8;
+}
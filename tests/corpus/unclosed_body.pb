@@ -0,0 +1,15 @@
+struct Player {
+    var health: uint32;
+
+struct Team {
+    var players: [Player];
+}
+
+variant Target {
+    var player: Player;
+    var team: Team {
+        opt deprecated;
+
+struct Score {
+    var points: int64;
+}
@@ -0,0 +1,9 @@
+opt json = yes;
+
+struct Player {
+    opt message_buffer(enabled, !disabled);
+
+    var health: uint32 {
+        opt range(min(0, 1), 100);
+    }
+}
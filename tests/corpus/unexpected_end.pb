@@ -0,0 +1,3 @@
+struct Player {
+    var health: uint32;
+    var position: [single; max 3
@@ -0,0 +1,31 @@
+opt cpp_namespace = "game";
+opt jsn;
+
+extern type Timestamp : int64 {
+    cpp = "std::time_t";
+    validate;
+}
+
+struct Player {
+    opt json = "yes";
+    opt message_buffer(true, false, true);
+    opt Json;
+    opt range(1, 2);
+
+    var name: string {
+        opt max_len = "10";
+        opt pattern;
+        opt deprecated = 3;
+        opt allow("deprecated", 1);
+    }
+    var health: uint32 {
+        opt range(100);
+    }
+}
+
+variant Event {
+    opt validate;
+    opt deprecated = "use Player";
+
+    var player: Player;
+}
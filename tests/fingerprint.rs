@@ -0,0 +1,51 @@
+//! Stability of the schema hashes.
+//!
+//! The `tests/fingerprint/*.pb` schemas are edits of `original.pb`. Edits that keep the
+//! wire shape must keep every hash, edits that change it must change them.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+/// Returns the hash of every structure and variant of a schema, by name.
+fn hashes(schema: &str) -> HashMap<String, String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_pbc"))
+        .current_dir(Path::new(env!("CARGO_MANIFEST_DIR")))
+        .args(["hash", &format!("tests/fingerprint/{schema}.pb")])
+        .output()
+        .expect("Failed to run pbc");
+    assert!(output.status.success(), "Failed to hash {schema}");
+
+    String::from_utf8(output.stdout)
+        .expect("Output is not UTF-8")
+        .lines()
+        .map(|line| {
+            let (hash, name) = line.split_once(' ').expect("Malformed hash line");
+            (name.trim().to_string(), hash.to_string())
+        })
+        .collect()
+}
+
+#[test]
+fn kept_by_renamed_fields() {
+    assert_eq!(hashes("original"), hashes("renamed_field"));
+}
+
+#[test]
+fn kept_by_comments() {
+    assert_eq!(hashes("original"), hashes("commented"));
+}
+
+#[test]
+fn kept_by_reordered_declarations() {
+    assert_eq!(hashes("original"), hashes("reordered_declarations"));
+}
+
+#[test]
+fn changed_by_reordered_fields() {
+    let original = hashes("original");
+    let reordered = hashes("reordered_fields");
+    for name in ["Player", "Item"] {
+        assert_ne!(original[name], reordered[name], "Hash of {name} did not change");
+    }
+}
@@ -0,0 +1,13 @@
+// Players of the lobby
+struct Player {
+    var name: string; // Shown above the head
+    var level: uint8;
+
+    /* Items carried by the player */
+    var inventory: [Item];
+}
+
+struct Item {
+    var id: uint32;
+    var owner: box<Player>;
+}
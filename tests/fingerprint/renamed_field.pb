@@ -0,0 +1,10 @@
+struct Player {
+    var nickname: string;
+    var level: uint8;
+    var inventory: [Item];
+}
+
+struct Item {
+    var id: uint32;
+    var holder: box<Player>;
+}
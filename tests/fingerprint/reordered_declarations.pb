@@ -0,0 +1,10 @@
+struct Item {
+    var id: uint32;
+    var owner: box<Player>;
+}
+
+struct Player {
+    var name: string;
+    var level: uint8;
+    var inventory: [Item];
+}
@@ -0,0 +1,10 @@
+struct Player {
+    var level: uint8;
+    var name: string;
+    var inventory: [Item];
+}
+
+struct Item {
+    var id: uint32;
+    var owner: box<Player>;
+}
@@ -0,0 +1,66 @@
+//! Golden files of the generated C++.
+//!
+//! Every `tests/generator/*.pb` schema is compiled with `--cpp` and the generated header
+//! is compared with the `.hpp` file next to it. Run with `PBC_BLESS=1` to update the
+//! expected headers after an intended change.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn generator() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("generator");
+    std::fs::create_dir_all(&output_dir).expect("Failed to create output directory");
+    let bless = std::env::var_os("PBC_BLESS").is_some();
+
+    let mut schemas = std::fs::read_dir(root.join("tests/generator"))
+        .expect("Failed to read generator directory")
+        .map(|entry| entry.expect("Failed to read generator entry").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "pb"))
+        .collect::<Vec<_>>();
+    schemas.sort();
+    assert!(!schemas.is_empty(), "Generator directory is empty");
+
+    let mut failures = Vec::new();
+    for schema in &schemas {
+        let relative_path = schema.strip_prefix(root).unwrap();
+        let header_path = output_dir.join(schema.with_extension("hpp").file_name().unwrap());
+        let output = Command::new(env!("CARGO_BIN_EXE_pbc"))
+            .current_dir(root)
+            .args(["build", "--color", "never", "--cpp", "--cpp-path"])
+            .arg(&header_path)
+            .arg(relative_path)
+            .output()
+            .expect("Failed to run pbc");
+        if !output.status.success() {
+            failures.push(format!(
+                "{}\n--- diagnostics\n{}",
+                relative_path.display(),
+                String::from_utf8_lossy(&output.stdout)
+            ));
+            continue;
+        }
+        let actual = std::fs::read_to_string(&header_path).expect("Failed to read header");
+
+        let expected_path = schema.with_extension("hpp");
+        if bless {
+            std::fs::write(&expected_path, &actual).expect("Failed to write expected header");
+            continue;
+        }
+
+        let expected = std::fs::read_to_string(&expected_path).unwrap_or_default();
+        if actual != expected {
+            failures.push(format!(
+                "{}\n--- expected\n{expected}\n--- actual\n{actual}",
+                relative_path.display()
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Generated C++ differs from the golden files:\n\n{}",
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,538 @@
+#pragma once
+#include "message_buffer.hpp"
+#include <rapidjson/document.h>
+#include <array>
+
+class Profile;
+class Account;
+enum LoginKind : uint16_t;
+class Login;
+
+class Profile
+{
+private:
+	friend class Account;
+	friend class Login;
+
+	std::string_view name;
+
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0x1ec55e520e6c3568ull;
+
+	[[deprecated("use Account")]] const std::string_view& GetName() const { return this->name; }
+	[[deprecated("use Account")]] Profile& SetName(const std::string_view& name) { this->name = name; return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		return nullptr;
+	}
+
+	bool Deserialize(const server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 2 + _size_offset))
+			return false;
+
+		{
+			{
+				uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				uint8_t* _data = _buffer; _buffer += _data_size;
+				(*this).name = std::string_view((const char*)_data, (size_t)_data_size);
+			}
+		}
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		_size_offset += (*this).name.size();
+		if (_size_offset > 65535) return false;
+		_message_buffer.SetSize(2 + 2 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		{
+			*(uint16_t*)_buffer = (uint16_t)(*this).name.size(); _buffer += sizeof(uint16_t);
+			std::memcpy((void*)_buffer, (void*)(*this).name.data(), (*this).name.size()); _buffer += (*this).name.size();
+		}
+
+		return true;
+	}
+
+	bool Deserialize(const rapidjson::Value& _value)
+	{
+		const rapidjson::Value& _temp_0 = _value;
+		if (!_temp_0.IsObject()) return false;
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("name");
+			if (_temp_it_1 == _temp_0.MemberEnd()) return false;
+			if (!_temp_it_1->value.IsString()) return false;
+			(*this).name = std::string_view(_temp_it_1->value.GetString(), _temp_it_1->value.GetStringLength());
+		}
+
+		return true;
+	}
+
+	void Serialize(rapidjson::Value& _value, rapidjson::Document::AllocatorType& _allocator)
+	{
+		rapidjson::Value& _temp_0 = _value;
+		_temp_0.SetObject();
+		{
+			_temp_0.AddMember("name", rapidjson::Value((*this).name.data(), (*this).name.size(), _allocator), _allocator);
+		}
+	}
+};
+
+class Account
+{
+private:
+	friend class Login;
+
+	std::string_view name;
+	std::string_view nickname;
+	uint8_t rank;
+	std::string_view title;
+	std::string_view motto;
+
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0x8b020b4a9e73618dull;
+
+	const std::string_view& GetName() const { return this->name; }
+	Account& SetName(const std::string_view& name) { this->name = name; return *this; }
+
+	[[deprecated]] const std::string_view& GetNickname() const { return this->nickname; }
+	[[deprecated]] Account& SetNickname(const std::string_view& nickname) { this->nickname = nickname; return *this; }
+
+	[[deprecated("ranks are computed")]] const uint8_t& GetRank() const { return this->rank; }
+	[[deprecated("ranks are computed")]] Account& SetRank(const uint8_t& rank) { this->rank = rank; return *this; }
+
+	[[deprecated("use \"Account.name\" with a C:\\ path")]] const std::string_view& GetTitle() const { return this->title; }
+	[[deprecated("use \"Account.name\" with a C:\\ path")]] Account& SetTitle(const std::string_view& title) { this->title = title; return *this; }
+
+	[[deprecated("mottos were replaced\nby biographies")]] const std::string_view& GetMotto() const { return this->motto; }
+	[[deprecated("mottos were replaced\nby biographies")]] Account& SetMotto(const std::string_view& motto) { this->motto = motto; return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		return nullptr;
+	}
+
+	bool Deserialize(const server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 9 + _size_offset))
+			return false;
+
+		{
+			{
+				uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				uint8_t* _data = _buffer; _buffer += _data_size;
+				(*this).name = std::string_view((const char*)_data, (size_t)_data_size);
+			}
+			{
+				uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				uint8_t* _data = _buffer; _buffer += _data_size;
+				(*this).nickname = std::string_view((const char*)_data, (size_t)_data_size);
+			}
+			(*this).rank = *(uint8_t*)_buffer; _buffer += sizeof(uint8_t);
+			{
+				uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				uint8_t* _data = _buffer; _buffer += _data_size;
+				(*this).title = std::string_view((const char*)_data, (size_t)_data_size);
+			}
+			{
+				uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				uint8_t* _data = _buffer; _buffer += _data_size;
+				(*this).motto = std::string_view((const char*)_data, (size_t)_data_size);
+			}
+		}
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		_size_offset += (*this).name.size();
+		_size_offset += (*this).nickname.size();
+		_size_offset += (*this).title.size();
+		_size_offset += (*this).motto.size();
+		if (_size_offset > 65535) return false;
+		_message_buffer.SetSize(2 + 9 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		{
+			*(uint16_t*)_buffer = (uint16_t)(*this).name.size(); _buffer += sizeof(uint16_t);
+			std::memcpy((void*)_buffer, (void*)(*this).name.data(), (*this).name.size()); _buffer += (*this).name.size();
+			*(uint16_t*)_buffer = (uint16_t)(*this).nickname.size(); _buffer += sizeof(uint16_t);
+			std::memcpy((void*)_buffer, (void*)(*this).nickname.data(), (*this).nickname.size()); _buffer += (*this).nickname.size();
+			*(uint8_t*)_buffer = (*this).rank; _buffer += sizeof(uint8_t);
+			*(uint16_t*)_buffer = (uint16_t)(*this).title.size(); _buffer += sizeof(uint16_t);
+			std::memcpy((void*)_buffer, (void*)(*this).title.data(), (*this).title.size()); _buffer += (*this).title.size();
+			*(uint16_t*)_buffer = (uint16_t)(*this).motto.size(); _buffer += sizeof(uint16_t);
+			std::memcpy((void*)_buffer, (void*)(*this).motto.data(), (*this).motto.size()); _buffer += (*this).motto.size();
+		}
+
+		return true;
+	}
+
+	bool Deserialize(const rapidjson::Value& _value)
+	{
+		const rapidjson::Value& _temp_0 = _value;
+		if (!_temp_0.IsObject()) return false;
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("name");
+			if (_temp_it_1 == _temp_0.MemberEnd()) return false;
+			if (!_temp_it_1->value.IsString()) return false;
+			(*this).name = std::string_view(_temp_it_1->value.GetString(), _temp_it_1->value.GetStringLength());
+		}
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("nickname");
+			if (_temp_it_1 != _temp_0.MemberEnd())
+			{
+				if (!_temp_it_1->value.IsString()) return false;
+				(*this).nickname = std::string_view(_temp_it_1->value.GetString(), _temp_it_1->value.GetStringLength());
+			}
+		}
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("rank");
+			if (_temp_it_1 != _temp_0.MemberEnd())
+			{
+				if (!_temp_it_1->value.IsUint()) return false;
+				(*this).rank = (uint8_t)_temp_it_1->value.GetUint();
+			}
+		}
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("title");
+			if (_temp_it_1 != _temp_0.MemberEnd())
+			{
+				if (!_temp_it_1->value.IsString()) return false;
+				(*this).title = std::string_view(_temp_it_1->value.GetString(), _temp_it_1->value.GetStringLength());
+			}
+		}
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("motto");
+			if (_temp_it_1 != _temp_0.MemberEnd())
+			{
+				if (!_temp_it_1->value.IsString()) return false;
+				(*this).motto = std::string_view(_temp_it_1->value.GetString(), _temp_it_1->value.GetStringLength());
+			}
+		}
+
+		return true;
+	}
+
+	void Serialize(rapidjson::Value& _value, rapidjson::Document::AllocatorType& _allocator)
+	{
+		rapidjson::Value& _temp_0 = _value;
+		_temp_0.SetObject();
+		{
+			_temp_0.AddMember("name", rapidjson::Value((*this).name.data(), (*this).name.size(), _allocator), _allocator);
+			_temp_0.AddMember("nickname", rapidjson::Value((*this).nickname.data(), (*this).nickname.size(), _allocator), _allocator);
+			_temp_0.AddMember("rank", rapidjson::Value((*this).rank), _allocator);
+			_temp_0.AddMember("title", rapidjson::Value((*this).title.data(), (*this).title.size(), _allocator), _allocator);
+			_temp_0.AddMember("motto", rapidjson::Value((*this).motto.data(), (*this).motto.size(), _allocator), _allocator);
+		}
+	}
+};
+
+enum LoginKind : uint16_t
+{
+	Member = 1,
+	Guest = 2,
+};
+class Login
+{
+private:
+
+	LoginKind kind{};
+	std::array<uint8_t, StaticMax<
+			Account, // member
+			bool, // guest
+			uint8_t>::value>
+		value;
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0x7f0bec52c4a7d350ull;
+
+	~Login()
+	{
+		switch(kind)
+		{
+		case LoginKind::Member: 
+			((Account*)this->value.data())->~Account();
+			break;
+		case LoginKind::Guest: 
+			break;
+		}
+	}
+	Login() = default;
+	Login(const Login& other) : kind(other.kind)
+	{
+		switch(kind)
+		{
+		case LoginKind::Member: 
+			new (this->value.data()) Account(*(const Account*)other.value.data());
+			break;
+		case LoginKind::Guest: 
+			new (this->value.data()) bool(*(const bool*)other.value.data());
+			break;
+		}
+	}
+	Login& operator=(const Login& other) { if (this != &other) { this->~Login(); new (this) Login(other); } return *this; }
+	Login(Login&& other) : kind(other.kind)
+	{
+		switch(kind)
+		{
+		case LoginKind::Member: 
+			new (this->value.data()) Account(std::move(*(Account*)other.value.data()));
+			break;
+		case LoginKind::Guest: 
+			new (this->value.data()) bool(std::move(*(bool*)other.value.data()));
+			break;
+		}
+	}
+	Login& operator=(Login&& other) { if (this != &other) { this->~Login(); new (this) Login(std::move(other)); } return *this; }
+
+	const LoginKind& Kind() const { return this->kind; }
+
+	const Account& GetMember() const { return *(Account*)this->value.data(); }
+	Login& SetMember(const Account& value) { this->~Login(); this->kind = LoginKind::Member; new (this->value.data()) Account(value); return *this; }
+
+	[[deprecated("guests can not log in")]] const bool& GetGuest() const { return *(bool*)this->value.data(); }
+	[[deprecated("guests can not log in")]] Login& SetGuest(const bool& value) { this->~Login(); this->kind = LoginKind::Guest; new (this->value.data()) bool(value); return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		return nullptr;
+	}
+
+	bool Deserialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 3 + _size_offset))
+			return false;
+
+		(&(*this))->~Login();
+		(*this).kind = (LoginKind)*(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		switch((*this).kind)
+		{
+		case LoginKind::Member: 
+		{
+			new (&(*(Account*)(*this).value.data())) Account();
+			{
+				{
+					uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+					uint8_t* _data = _buffer; _buffer += _data_size;
+					(*(Account*)(*this).value.data()).name = std::string_view((const char*)_data, (size_t)_data_size);
+				}
+				{
+					uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+					uint8_t* _data = _buffer; _buffer += _data_size;
+					(*(Account*)(*this).value.data()).nickname = std::string_view((const char*)_data, (size_t)_data_size);
+				}
+				(*(Account*)(*this).value.data()).rank = *(uint8_t*)_buffer; _buffer += sizeof(uint8_t);
+				{
+					uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+					uint8_t* _data = _buffer; _buffer += _data_size;
+					(*(Account*)(*this).value.data()).title = std::string_view((const char*)_data, (size_t)_data_size);
+				}
+				{
+					uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+					uint8_t* _data = _buffer; _buffer += _data_size;
+					(*(Account*)(*this).value.data()).motto = std::string_view((const char*)_data, (size_t)_data_size);
+				}
+			}
+			break;
+		}
+		case LoginKind::Guest: 
+		{
+			(*(bool*)(*this).value.data()) = *(bool*)_buffer; _buffer += sizeof(bool);
+			break;
+		}
+		}
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		switch((*this).kind)
+		{
+		case LoginKind::Member: 
+		{
+			_size_offset += 8;
+			_size_offset += (*(Account*)(*this).value.data()).name.size();
+			_size_offset += (*(Account*)(*this).value.data()).nickname.size();
+			_size_offset += (*(Account*)(*this).value.data()).title.size();
+			_size_offset += (*(Account*)(*this).value.data()).motto.size();
+			break;
+		}
+		case LoginKind::Guest: 
+		{
+			break;
+		}
+		}
+		if (_size_offset > 65535) return false;
+		_message_buffer.SetSize(2 + 3 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		*(uint16_t*)_buffer = (uint16_t)(*this).kind; _buffer += sizeof(uint16_t);
+		switch((*this).kind)
+		{
+		case LoginKind::Member: 
+		{
+			{
+				*(uint16_t*)_buffer = (uint16_t)(*(Account*)(*this).value.data()).name.size(); _buffer += sizeof(uint16_t);
+				std::memcpy((void*)_buffer, (void*)(*(Account*)(*this).value.data()).name.data(), (*(Account*)(*this).value.data()).name.size()); _buffer += (*(Account*)(*this).value.data()).name.size();
+				*(uint16_t*)_buffer = (uint16_t)(*(Account*)(*this).value.data()).nickname.size(); _buffer += sizeof(uint16_t);
+				std::memcpy((void*)_buffer, (void*)(*(Account*)(*this).value.data()).nickname.data(), (*(Account*)(*this).value.data()).nickname.size()); _buffer += (*(Account*)(*this).value.data()).nickname.size();
+				*(uint8_t*)_buffer = (*(Account*)(*this).value.data()).rank; _buffer += sizeof(uint8_t);
+				*(uint16_t*)_buffer = (uint16_t)(*(Account*)(*this).value.data()).title.size(); _buffer += sizeof(uint16_t);
+				std::memcpy((void*)_buffer, (void*)(*(Account*)(*this).value.data()).title.data(), (*(Account*)(*this).value.data()).title.size()); _buffer += (*(Account*)(*this).value.data()).title.size();
+				*(uint16_t*)_buffer = (uint16_t)(*(Account*)(*this).value.data()).motto.size(); _buffer += sizeof(uint16_t);
+				std::memcpy((void*)_buffer, (void*)(*(Account*)(*this).value.data()).motto.data(), (*(Account*)(*this).value.data()).motto.size()); _buffer += (*(Account*)(*this).value.data()).motto.size();
+			}
+			break;
+		}
+		case LoginKind::Guest: 
+		{
+			*(bool*)_buffer = (*(bool*)(*this).value.data()); _buffer += sizeof(bool);
+			break;
+		}
+		}
+
+		return true;
+	}
+
+	bool Deserialize(rapidjson::Value& _value)
+	{
+		const rapidjson::Value& _temp_0 = _value;
+		if (!_temp_0.IsObject())
+			return false;
+		rapidjson::Value::ConstMemberIterator _temp_kind_it_1 = _temp_0.FindMember("_kind");
+		if (_temp_kind_it_1 == _temp_0.MemberEnd() || !_temp_kind_it_1->value.IsUint()) return false;
+		(&(*this))->~Login();
+		(*this).kind = (LoginKind)_temp_kind_it_1->value.GetUint();
+		switch((*this).kind)
+		{
+		case LoginKind::Member: 
+		{
+			new (&(*(Account*)(*this).value.data())) Account();
+			{
+				rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("member");
+				if (_temp_it_1 == _temp_0.MemberEnd()) return false;
+				if (!_temp_it_1->value.IsObject()) return false;
+				const rapidjson::Value& _temp_1 = _temp_it_1->value;
+				{
+					rapidjson::Value::ConstMemberIterator _temp_it_2 = _temp_1.FindMember("name");
+					if (_temp_it_2 == _temp_1.MemberEnd()) return false;
+					if (!_temp_it_2->value.IsString()) return false;
+					(*(Account*)(*this).value.data()).name = std::string_view(_temp_it_2->value.GetString(), _temp_it_2->value.GetStringLength());
+				}
+				{
+					rapidjson::Value::ConstMemberIterator _temp_it_2 = _temp_1.FindMember("nickname");
+					if (_temp_it_2 != _temp_1.MemberEnd())
+					{
+						if (!_temp_it_2->value.IsString()) return false;
+						(*(Account*)(*this).value.data()).nickname = std::string_view(_temp_it_2->value.GetString(), _temp_it_2->value.GetStringLength());
+					}
+				}
+				{
+					rapidjson::Value::ConstMemberIterator _temp_it_2 = _temp_1.FindMember("rank");
+					if (_temp_it_2 != _temp_1.MemberEnd())
+					{
+						if (!_temp_it_2->value.IsUint()) return false;
+						(*(Account*)(*this).value.data()).rank = (uint8_t)_temp_it_2->value.GetUint();
+					}
+				}
+				{
+					rapidjson::Value::ConstMemberIterator _temp_it_2 = _temp_1.FindMember("title");
+					if (_temp_it_2 != _temp_1.MemberEnd())
+					{
+						if (!_temp_it_2->value.IsString()) return false;
+						(*(Account*)(*this).value.data()).title = std::string_view(_temp_it_2->value.GetString(), _temp_it_2->value.GetStringLength());
+					}
+				}
+				{
+					rapidjson::Value::ConstMemberIterator _temp_it_2 = _temp_1.FindMember("motto");
+					if (_temp_it_2 != _temp_1.MemberEnd())
+					{
+						if (!_temp_it_2->value.IsString()) return false;
+						(*(Account*)(*this).value.data()).motto = std::string_view(_temp_it_2->value.GetString(), _temp_it_2->value.GetStringLength());
+					}
+				}
+			}
+			break;
+		}
+		case LoginKind::Guest: 
+		{
+			{
+				rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("guest");
+				if (_temp_it_1 != _temp_0.MemberEnd())
+				{
+					if (!_temp_it_1->value.IsBool()) return false;
+					(*(bool*)(*this).value.data()) = _temp_it_1->value.GetBool();
+				}
+			}
+			break;
+		}
+		default: 
+			return false;
+		}
+
+		return true;
+	}
+
+	void Serialize(rapidjson::Value& _value, rapidjson::Document::AllocatorType& _allocator)
+	{
+		rapidjson::Value& _temp_0 = _value;
+		_temp_0.SetObject();
+		_temp_0.AddMember("_kind", rapidjson::Value((*this).kind), _allocator);
+		switch((*this).kind)
+		{
+		case LoginKind::Member:
+		{
+			{
+				rapidjson::Value _temp_1 = rapidjson::Value(rapidjson::kObjectType);
+				{
+					_temp_1.AddMember("name", rapidjson::Value((*(Account*)(*this).value.data()).name.data(), (*(Account*)(*this).value.data()).name.size(), _allocator), _allocator);
+					_temp_1.AddMember("nickname", rapidjson::Value((*(Account*)(*this).value.data()).nickname.data(), (*(Account*)(*this).value.data()).nickname.size(), _allocator), _allocator);
+					_temp_1.AddMember("rank", rapidjson::Value((*(Account*)(*this).value.data()).rank), _allocator);
+					_temp_1.AddMember("title", rapidjson::Value((*(Account*)(*this).value.data()).title.data(), (*(Account*)(*this).value.data()).title.size(), _allocator), _allocator);
+					_temp_1.AddMember("motto", rapidjson::Value((*(Account*)(*this).value.data()).motto.data(), (*(Account*)(*this).value.data()).motto.size(), _allocator), _allocator);
+				}
+				_temp_0.AddMember("member", _temp_1, _allocator);
+			}
+			break;
+		}
+		case LoginKind::Guest:
+		{
+			_temp_0.AddMember("guest", rapidjson::Value((*(bool*)(*this).value.data())), _allocator);
+			break;
+		}
+		}
+	}
+};
@@ -0,0 +1,32 @@
+opt message_buffer;
+opt json;
+
+struct Profile {
+    opt deprecated = "use Account";
+
+    var name: string;
+}
+
+struct Account {
+    var name: string;
+    var nickname: string {
+        opt deprecated;
+    }
+    var rank: uint8 {
+        opt deprecated = "ranks are computed";
+    }
+    var title: string {
+        opt deprecated = "use \"Account.name\" with a C:\\ path";
+    }
+    var motto: string {
+        opt deprecated = "mottos were replaced
+by biographies";
+    }
+}
+
+variant Login {
+    var member: Account;
+    var guest: bool {
+        opt deprecated = "guests can not log in";
+    }
+}
@@ -0,0 +1,182 @@
+#pragma once
+#include "message_buffer.hpp"
+#include <rapidjson/document.h>
+#include <array>
+#include <memory>
+#include "game/entity_id.hpp"
+#include <chrono>
+#include <new>
+#include <vector>
+
+class Spawn;
+
+class Spawn
+{
+private:
+
+	game::EntityId entity;
+	std::chrono::milliseconds at;
+	std::vector<game::EntityId> children;
+
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0x58a567d7d3ef3ecfull;
+
+	const game::EntityId& GetEntity() const { return this->entity; }
+	Spawn& SetEntity(const game::EntityId& entity) { this->entity = entity; return *this; }
+
+	const std::chrono::milliseconds& GetAt() const { return this->at; }
+	Spawn& SetAt(const std::chrono::milliseconds& at) { this->at = at; return *this; }
+
+	const std::vector<game::EntityId>& GetChildren() const { return this->children; }
+	Spawn& SetChildren(const std::vector<game::EntityId>& children) { this->children = children; return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		return nullptr;
+	}
+
+	bool Deserialize(const server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 18 + _size_offset))
+			return false;
+
+		{
+			{
+				uint64_t _wire;
+				_wire = *(uint64_t*)_buffer; _buffer += sizeof(uint64_t);
+				FromWire(_wire, (*this).entity);
+			}
+			{
+				int64_t _wire;
+				_wire = *(int64_t*)_buffer; _buffer += sizeof(int64_t);
+				game::FromMilliseconds(_wire, (*this).at);
+			}
+			{
+				uint16_t _count_3 = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				(*this).children.resize(_count_3);
+				for (uint16_t _i_3 = 0; _i_3 < _count_3; ++_i_3)
+				{
+					{
+						uint64_t _wire;
+						_wire = *(uint64_t*)_buffer; _buffer += sizeof(uint64_t);
+						FromWire(_wire, (*this).children[_i_3]);
+					}
+				}
+			}
+		}
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		_size_offset += (*this).children.size() * 8;
+		for (const auto& _item_2 : (*this).children)
+		{
+		}
+		if (_size_offset > 65535) return false;
+		_message_buffer.SetSize(2 + 18 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		{
+			{
+				uint64_t _wire = ToWire((*this).entity);
+				*(uint64_t*)_buffer = _wire; _buffer += sizeof(uint64_t);
+			}
+			{
+				int64_t _wire = game::ToMilliseconds((*this).at);
+				*(int64_t*)_buffer = _wire; _buffer += sizeof(int64_t);
+			}
+			*(uint16_t*)_buffer = (uint16_t)(*this).children.size(); _buffer += sizeof(uint16_t);
+			for (const auto& _item_3 : (*this).children)
+			{
+				{
+					uint64_t _wire = ToWire(_item_3);
+					*(uint64_t*)_buffer = _wire; _buffer += sizeof(uint64_t);
+				}
+			}
+		}
+
+		return true;
+	}
+
+	bool Deserialize(const rapidjson::Value& _value)
+	{
+		const rapidjson::Value& _temp_0 = _value;
+		if (!_temp_0.IsObject()) return false;
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("entity");
+			if (_temp_it_1 == _temp_0.MemberEnd()) return false;
+			{
+				uint64_t _wire;
+				if (!_temp_it_1->value.IsUint64()) return false;
+				_wire = _temp_it_1->value.GetUint64();
+				FromWire(_wire, (*this).entity);
+			}
+		}
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("at");
+			if (_temp_it_1 == _temp_0.MemberEnd()) return false;
+			{
+				int64_t _wire;
+				if (!_temp_it_1->value.IsInt64()) return false;
+				_wire = _temp_it_1->value.GetInt64();
+				game::FromMilliseconds(_wire, (*this).at);
+			}
+		}
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("children");
+			if (_temp_it_1 == _temp_0.MemberEnd()) return false;
+			if (!_temp_it_1->value.IsArray()) return false;
+			(*this).children.resize(_temp_it_1->value.Size());
+			for (rapidjson::SizeType _i_1 = 0; _i_1 < _temp_it_1->value.Size(); ++_i_1)
+			{
+				{
+					uint64_t _wire;
+					if (!_temp_it_1->value[_i_1].IsUint64()) return false;
+					_wire = _temp_it_1->value[_i_1].GetUint64();
+					FromWire(_wire, (*this).children[_i_1]);
+				}
+			}
+		}
+
+		return true;
+	}
+
+	void Serialize(rapidjson::Value& _value, rapidjson::Document::AllocatorType& _allocator)
+	{
+		rapidjson::Value& _temp_0 = _value;
+		_temp_0.SetObject();
+		{
+			{
+				uint64_t _wire = ToWire((*this).entity);
+				_temp_0.AddMember("entity", rapidjson::Value(_wire), _allocator);
+			}
+			{
+				int64_t _wire = game::ToMilliseconds((*this).at);
+				_temp_0.AddMember("at", rapidjson::Value(_wire), _allocator);
+			}
+			{
+				rapidjson::Value _temp_1 = rapidjson::Value(rapidjson::kArrayType);
+				for (const auto& _item_1 : (*this).children)
+				{
+					{
+						uint64_t _wire = ToWire(_item_1);
+						_temp_1.PushBack(rapidjson::Value(_wire), _allocator);
+					}
+				}
+				_temp_0.AddMember("children", _temp_1, _allocator);
+			}
+		}
+	}
+};
+
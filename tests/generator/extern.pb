@@ -0,0 +1,20 @@
+opt message_buffer;
+opt json;
+
+extern type EntityId: uint64 {
+    cpp = "game::EntityId";
+    cpp_include = "game/entity_id.hpp";
+}
+
+extern type Timestamp: int64 {
+    cpp = "std::chrono::milliseconds";
+    cpp_include = "<chrono>";
+    cpp_to_wire = "game::ToMilliseconds";
+    cpp_from_wire = "game::FromMilliseconds";
+}
+
+struct Spawn {
+    var entity: EntityId;
+    var at: Timestamp;
+    var children: [EntityId];
+}
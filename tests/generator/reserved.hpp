@@ -0,0 +1,364 @@
+#pragma once
+#include "message_buffer.hpp"
+#include <rapidjson/document.h>
+#include <array>
+
+class Player;
+enum EventKind : uint16_t;
+class Event;
+
+class Player
+{
+private:
+	friend class Event;
+
+	std::string_view name;
+	uint8_t level;
+	uint32_t score;
+
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0x1cd172e4aff28b20ull;
+
+	const std::string_view& GetName() const { return this->name; }
+	Player& SetName(const std::string_view& name) { this->name = name; return *this; }
+
+	const uint8_t& GetLevel() const { return this->level; }
+	Player& SetLevel(const uint8_t& level) { this->level = level; return *this; }
+
+	const uint32_t& GetScore() const { return this->score; }
+	Player& SetScore(const uint32_t& score) { this->score = score; return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		return nullptr;
+	}
+
+	bool Deserialize(const server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 7 + _size_offset))
+			return false;
+
+		{
+			{
+				uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				uint8_t* _data = _buffer; _buffer += _data_size;
+				(*this).name = std::string_view((const char*)_data, (size_t)_data_size);
+			}
+			(*this).level = *(uint8_t*)_buffer; _buffer += sizeof(uint8_t);
+			(*this).score = *(uint32_t*)_buffer; _buffer += sizeof(uint32_t);
+		}
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		_size_offset += (*this).name.size();
+		if (_size_offset > 65535) return false;
+		_message_buffer.SetSize(2 + 7 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		{
+			*(uint16_t*)_buffer = (uint16_t)(*this).name.size(); _buffer += sizeof(uint16_t);
+			std::memcpy((void*)_buffer, (void*)(*this).name.data(), (*this).name.size()); _buffer += (*this).name.size();
+			*(uint8_t*)_buffer = (*this).level; _buffer += sizeof(uint8_t);
+			*(uint32_t*)_buffer = (*this).score; _buffer += sizeof(uint32_t);
+		}
+
+		return true;
+	}
+
+	bool Deserialize(const rapidjson::Value& _value)
+	{
+		const rapidjson::Value& _temp_0 = _value;
+		if (!_temp_0.IsObject()) return false;
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("name");
+			if (_temp_it_1 == _temp_0.MemberEnd()) return false;
+			if (!_temp_it_1->value.IsString()) return false;
+			(*this).name = std::string_view(_temp_it_1->value.GetString(), _temp_it_1->value.GetStringLength());
+		}
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("level");
+			if (_temp_it_1 == _temp_0.MemberEnd()) return false;
+			if (!_temp_it_1->value.IsUint()) return false;
+			(*this).level = (uint8_t)_temp_it_1->value.GetUint();
+		}
+		{
+			rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("score");
+			if (_temp_it_1 == _temp_0.MemberEnd()) return false;
+			if (!_temp_it_1->value.IsUint()) return false;
+			(*this).score = _temp_it_1->value.GetUint();
+		}
+
+		return true;
+	}
+
+	void Serialize(rapidjson::Value& _value, rapidjson::Document::AllocatorType& _allocator)
+	{
+		rapidjson::Value& _temp_0 = _value;
+		_temp_0.SetObject();
+		{
+			_temp_0.AddMember("name", rapidjson::Value((*this).name.data(), (*this).name.size(), _allocator), _allocator);
+			_temp_0.AddMember("level", rapidjson::Value((*this).level), _allocator);
+			_temp_0.AddMember("score", rapidjson::Value((*this).score), _allocator);
+		}
+	}
+};
+
+enum EventKind : uint16_t
+{
+	Joined = 1,
+	Chat = 2,
+};
+class Event
+{
+private:
+
+	EventKind kind{};
+	std::array<uint8_t, StaticMax<
+			Player, // joined
+			std::string_view, // chat
+			uint8_t>::value>
+		value;
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0xc6d023db88f436daull;
+
+	~Event()
+	{
+		switch(kind)
+		{
+		case EventKind::Joined: 
+			((Player*)this->value.data())->~Player();
+			break;
+		case EventKind::Chat: 
+			break;
+		}
+	}
+	Event() = default;
+	Event(const Event& other) : kind(other.kind)
+	{
+		switch(kind)
+		{
+		case EventKind::Joined: 
+			new (this->value.data()) Player(*(const Player*)other.value.data());
+			break;
+		case EventKind::Chat: 
+			new (this->value.data()) std::string_view(*(const std::string_view*)other.value.data());
+			break;
+		}
+	}
+	Event& operator=(const Event& other) { if (this != &other) { this->~Event(); new (this) Event(other); } return *this; }
+	Event(Event&& other) : kind(other.kind)
+	{
+		switch(kind)
+		{
+		case EventKind::Joined: 
+			new (this->value.data()) Player(std::move(*(Player*)other.value.data()));
+			break;
+		case EventKind::Chat: 
+			new (this->value.data()) std::string_view(std::move(*(std::string_view*)other.value.data()));
+			break;
+		}
+	}
+	Event& operator=(Event&& other) { if (this != &other) { this->~Event(); new (this) Event(std::move(other)); } return *this; }
+
+	const EventKind& Kind() const { return this->kind; }
+
+	const Player& GetJoined() const { return *(Player*)this->value.data(); }
+	Event& SetJoined(const Player& value) { this->~Event(); this->kind = EventKind::Joined; new (this->value.data()) Player(value); return *this; }
+
+	const std::string_view& GetChat() const { return *(std::string_view*)this->value.data(); }
+	Event& SetChat(const std::string_view& value) { this->~Event(); this->kind = EventKind::Chat; new (this->value.data()) std::string_view(value); return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		return nullptr;
+	}
+
+	bool Deserialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 4 + _size_offset))
+			return false;
+
+		(&(*this))->~Event();
+		(*this).kind = (EventKind)*(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		switch((*this).kind)
+		{
+		case EventKind::Joined: 
+		{
+			new (&(*(Player*)(*this).value.data())) Player();
+			{
+				{
+					uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+					uint8_t* _data = _buffer; _buffer += _data_size;
+					(*(Player*)(*this).value.data()).name = std::string_view((const char*)_data, (size_t)_data_size);
+				}
+				(*(Player*)(*this).value.data()).level = *(uint8_t*)_buffer; _buffer += sizeof(uint8_t);
+				(*(Player*)(*this).value.data()).score = *(uint32_t*)_buffer; _buffer += sizeof(uint32_t);
+			}
+			break;
+		}
+		case EventKind::Chat: 
+		{
+			new (&(*(std::string_view*)(*this).value.data())) std::string_view();
+			{
+				uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				uint8_t* _data = _buffer; _buffer += _data_size;
+				(*(std::string_view*)(*this).value.data()) = std::string_view((const char*)_data, (size_t)_data_size);
+			}
+			break;
+		}
+		}
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		switch((*this).kind)
+		{
+		case EventKind::Joined: 
+		{
+			_size_offset += 5;
+			_size_offset += (*(Player*)(*this).value.data()).name.size();
+			break;
+		}
+		case EventKind::Chat: 
+		{
+			_size_offset += (*(std::string_view*)(*this).value.data()).size();
+			break;
+		}
+		}
+		if (_size_offset > 65535) return false;
+		_message_buffer.SetSize(2 + 4 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		*(uint16_t*)_buffer = (uint16_t)(*this).kind; _buffer += sizeof(uint16_t);
+		switch((*this).kind)
+		{
+		case EventKind::Joined: 
+		{
+			{
+				*(uint16_t*)_buffer = (uint16_t)(*(Player*)(*this).value.data()).name.size(); _buffer += sizeof(uint16_t);
+				std::memcpy((void*)_buffer, (void*)(*(Player*)(*this).value.data()).name.data(), (*(Player*)(*this).value.data()).name.size()); _buffer += (*(Player*)(*this).value.data()).name.size();
+				*(uint8_t*)_buffer = (*(Player*)(*this).value.data()).level; _buffer += sizeof(uint8_t);
+				*(uint32_t*)_buffer = (*(Player*)(*this).value.data()).score; _buffer += sizeof(uint32_t);
+			}
+			break;
+		}
+		case EventKind::Chat: 
+		{
+			*(uint16_t*)_buffer = (uint16_t)(*(std::string_view*)(*this).value.data()).size(); _buffer += sizeof(uint16_t);
+			std::memcpy((void*)_buffer, (void*)(*(std::string_view*)(*this).value.data()).data(), (*(std::string_view*)(*this).value.data()).size()); _buffer += (*(std::string_view*)(*this).value.data()).size();
+			break;
+		}
+		}
+
+		return true;
+	}
+
+	bool Deserialize(rapidjson::Value& _value)
+	{
+		const rapidjson::Value& _temp_0 = _value;
+		if (!_temp_0.IsObject())
+			return false;
+		rapidjson::Value::ConstMemberIterator _temp_kind_it_1 = _temp_0.FindMember("_kind");
+		if (_temp_kind_it_1 == _temp_0.MemberEnd() || !_temp_kind_it_1->value.IsUint()) return false;
+		(&(*this))->~Event();
+		(*this).kind = (EventKind)_temp_kind_it_1->value.GetUint();
+		switch((*this).kind)
+		{
+		case EventKind::Joined: 
+		{
+			new (&(*(Player*)(*this).value.data())) Player();
+			{
+				rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("joined");
+				if (_temp_it_1 == _temp_0.MemberEnd()) return false;
+				if (!_temp_it_1->value.IsObject()) return false;
+				const rapidjson::Value& _temp_1 = _temp_it_1->value;
+				{
+					rapidjson::Value::ConstMemberIterator _temp_it_2 = _temp_1.FindMember("name");
+					if (_temp_it_2 == _temp_1.MemberEnd()) return false;
+					if (!_temp_it_2->value.IsString()) return false;
+					(*(Player*)(*this).value.data()).name = std::string_view(_temp_it_2->value.GetString(), _temp_it_2->value.GetStringLength());
+				}
+				{
+					rapidjson::Value::ConstMemberIterator _temp_it_2 = _temp_1.FindMember("level");
+					if (_temp_it_2 == _temp_1.MemberEnd()) return false;
+					if (!_temp_it_2->value.IsUint()) return false;
+					(*(Player*)(*this).value.data()).level = (uint8_t)_temp_it_2->value.GetUint();
+				}
+				{
+					rapidjson::Value::ConstMemberIterator _temp_it_2 = _temp_1.FindMember("score");
+					if (_temp_it_2 == _temp_1.MemberEnd()) return false;
+					if (!_temp_it_2->value.IsUint()) return false;
+					(*(Player*)(*this).value.data()).score = _temp_it_2->value.GetUint();
+				}
+			}
+			break;
+		}
+		case EventKind::Chat: 
+		{
+			new (&(*(std::string_view*)(*this).value.data())) std::string_view();
+			{
+				rapidjson::Value::ConstMemberIterator _temp_it_1 = _temp_0.FindMember("chat");
+				if (_temp_it_1 == _temp_0.MemberEnd()) return false;
+				if (!_temp_it_1->value.IsString()) return false;
+				(*(std::string_view*)(*this).value.data()) = std::string_view(_temp_it_1->value.GetString(), _temp_it_1->value.GetStringLength());
+			}
+			break;
+		}
+		default: 
+			return false;
+		}
+
+		return true;
+	}
+
+	void Serialize(rapidjson::Value& _value, rapidjson::Document::AllocatorType& _allocator)
+	{
+		rapidjson::Value& _temp_0 = _value;
+		_temp_0.SetObject();
+		_temp_0.AddMember("_kind", rapidjson::Value((*this).kind), _allocator);
+		switch((*this).kind)
+		{
+		case EventKind::Joined:
+		{
+			{
+				rapidjson::Value _temp_1 = rapidjson::Value(rapidjson::kObjectType);
+				{
+					_temp_1.AddMember("name", rapidjson::Value((*(Player*)(*this).value.data()).name.data(), (*(Player*)(*this).value.data()).name.size(), _allocator), _allocator);
+					_temp_1.AddMember("level", rapidjson::Value((*(Player*)(*this).value.data()).level), _allocator);
+					_temp_1.AddMember("score", rapidjson::Value((*(Player*)(*this).value.data()).score), _allocator);
+				}
+				_temp_0.AddMember("joined", _temp_1, _allocator);
+			}
+			break;
+		}
+		case EventKind::Chat:
+		{
+			_temp_0.AddMember("chat", rapidjson::Value((*(std::string_view*)(*this).value.data()).data(), (*(std::string_view*)(*this).value.data()).size(), _allocator), _allocator);
+			break;
+		}
+		}
+	}
+};
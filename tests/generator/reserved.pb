@@ -0,0 +1,17 @@
+opt message_buffer;
+opt json;
+
+struct Player {
+    reserved 4 to 6, "health", "mana";
+
+    var name: string;
+    var level: uint8;
+    var score: uint32;
+}
+
+variant Event {
+    reserved 3, "left";
+
+    var joined: Player;
+    var chat: string;
+}
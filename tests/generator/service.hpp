@@ -0,0 +1,254 @@
+#pragma once
+#include "message_buffer.hpp"
+#include <rapidjson/document.h>
+#include <array>
+#include <functional>
+
+class JoinRequest;
+class JoinResponse;
+class Empty;
+
+class JoinRequest
+{
+private:
+	friend class JoinResponse;
+	friend class Empty;
+
+	std::string_view name;
+
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0x1ec55e520e6c3568ull;
+
+	const std::string_view& GetName() const { return this->name; }
+	JoinRequest& SetName(const std::string_view& name) { this->name = name; return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		return nullptr;
+	}
+
+	bool Deserialize(const server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 2 + _size_offset))
+			return false;
+
+		{
+			{
+				uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				uint8_t* _data = _buffer; _buffer += _data_size;
+				(*this).name = std::string_view((const char*)_data, (size_t)_data_size);
+			}
+		}
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		_size_offset += (*this).name.size();
+		if (_size_offset > 65535) return false;
+		_message_buffer.SetSize(2 + 2 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		{
+			*(uint16_t*)_buffer = (uint16_t)(*this).name.size(); _buffer += sizeof(uint16_t);
+			std::memcpy((void*)_buffer, (void*)(*this).name.data(), (*this).name.size()); _buffer += (*this).name.size();
+		}
+
+		return true;
+	}
+};
+
+class JoinResponse
+{
+private:
+	friend class Empty;
+
+	uint32_t player_id;
+
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0xc807894468f7641aull;
+
+	const uint32_t& GetPlayerId() const { return this->player_id; }
+	JoinResponse& SetPlayerId(const uint32_t& player_id) { this->player_id = player_id; return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		return nullptr;
+	}
+
+	// Maximum size of the serialized message buffer
+	static constexpr size_t MaxSize = 6;
+
+	bool Deserialize(const server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 4 + _size_offset))
+			return false;
+
+		{
+			(*this).player_id = *(uint32_t*)_buffer; _buffer += sizeof(uint32_t);
+		}
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		_message_buffer.SetSize(2 + 4 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		{
+			*(uint32_t*)_buffer = (*this).player_id; _buffer += sizeof(uint32_t);
+		}
+
+		return true;
+	}
+};
+
+class Empty
+{
+private:
+
+
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0x28be00470e900397ull;
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		return nullptr;
+	}
+
+	// Maximum size of the serialized message buffer
+	static constexpr size_t MaxSize = 2;
+
+	bool Deserialize(const server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 0 + _size_offset))
+			return false;
+
+		{
+		}
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		_message_buffer.SetSize(2 + 0 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		{
+		}
+
+		return true;
+	}
+};
+
+enum class LobbyMethod : uint16_t
+{
+	Join = 1,
+	Leave = 2,
+};
+
+class LobbyServer
+{
+public:
+	virtual ~LobbyServer() = default;
+
+	virtual bool Join(const JoinRequest& request, JoinResponse& reply) = 0;
+	virtual bool Leave(const Empty& request, Empty& reply) = 0;
+
+	bool Dispatch(uint16_t _method_id, server::scripting::sdk::MessageBuffer& _request_buffer, server::scripting::sdk::MessageBuffer& _reply_buffer)
+	{
+		switch((LobbyMethod)_method_id)
+		{
+		case LobbyMethod::Join: 
+		{
+			JoinRequest _request;
+			if (!_request.Deserialize(_request_buffer)) return false;
+
+			JoinResponse _reply;
+			if (!this->Join(_request, _reply)) return false;
+
+			if (!_reply.Serialize(_reply_buffer)) return false;
+			return true;
+		}
+		case LobbyMethod::Leave: 
+		{
+			Empty _request;
+			if (!_request.Deserialize(_request_buffer)) return false;
+
+			Empty _reply;
+			if (!this->Leave(_request, _reply)) return false;
+
+			if (!_reply.Serialize(_reply_buffer)) return false;
+			return true;
+		}
+		default: 
+			return false;
+		}
+	}
+};
+
+class LobbyClient
+{
+public:
+	// Sends the request buffer of a method and receives its reply buffer
+	using Transport = std::function<bool(uint16_t, server::scripting::sdk::MessageBuffer&, server::scripting::sdk::MessageBuffer&)>;
+
+private:
+	Transport transport;
+
+public:
+	explicit LobbyClient(Transport transport) : transport(std::move(transport)) {}
+
+	bool Join(JoinRequest& request, JoinResponse& reply)
+	{
+		server::scripting::sdk::MessageBuffer _request_buffer;
+		if (!request.Serialize(_request_buffer)) return false;
+
+		server::scripting::sdk::MessageBuffer _reply_buffer;
+		if (!this->transport((uint16_t)LobbyMethod::Join, _request_buffer, _reply_buffer)) return false;
+
+		return reply.Deserialize(_reply_buffer);
+	}
+
+	bool Leave(Empty& request, Empty& reply)
+	{
+		server::scripting::sdk::MessageBuffer _request_buffer;
+		if (!request.Serialize(_request_buffer)) return false;
+
+		server::scripting::sdk::MessageBuffer _reply_buffer;
+		if (!this->transport((uint16_t)LobbyMethod::Leave, _request_buffer, _reply_buffer)) return false;
+
+		return reply.Deserialize(_reply_buffer);
+	}
+};
+
@@ -0,0 +1,18 @@
+opt message_buffer;
+
+struct JoinRequest {
+    var name: string;
+}
+
+struct JoinResponse {
+    var player_id: uint32;
+}
+
+struct Empty {
+    opt allow = "empty_body";
+}
+
+service Lobby {
+    rpc Join(JoinRequest) returns (JoinResponse);
+    rpc Leave(Empty) returns (Empty);
+}
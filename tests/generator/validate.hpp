@@ -0,0 +1,416 @@
+#pragma once
+#include "message_buffer.hpp"
+#include <rapidjson/document.h>
+#include <array>
+#include <regex>
+#include <memory>
+#include <new>
+#include <vector>
+
+class Guild;
+class Player;
+enum TargetKind : uint16_t;
+class Target;
+
+class Guild
+{
+private:
+	friend class Player;
+	friend class Target;
+
+	std::string_view tag;
+
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0x1ec55e520e6c3568ull;
+
+	const std::string_view& GetTag() const { return this->tag; }
+	Guild& SetTag(const std::string_view& tag) { this->tag = tag; return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		if ((*this).tag.size() > 4) return "tag";
+		return nullptr;
+	}
+
+	bool Deserialize(const server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 2 + _size_offset))
+			return false;
+
+		{
+			{
+				uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				uint8_t* _data = _buffer; _buffer += _data_size;
+				(*this).tag = std::string_view((const char*)_data, (size_t)_data_size);
+			}
+		}
+
+		if (this->Validate() != nullptr) return false;
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		_size_offset += (*this).tag.size();
+		if (_size_offset > 65535) return false;
+		_message_buffer.SetSize(2 + 2 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		{
+			*(uint16_t*)_buffer = (uint16_t)(*this).tag.size(); _buffer += sizeof(uint16_t);
+			std::memcpy((void*)_buffer, (void*)(*this).tag.data(), (*this).tag.size()); _buffer += (*this).tag.size();
+		}
+
+		return true;
+	}
+};
+
+class Player
+{
+private:
+	friend class Target;
+
+	std::string_view name;
+	uint8_t level;
+	double speed;
+	std::vector<std::string_view> titles;
+	Guild guild;
+
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0x4d45e4ad2cc3e3a6ull;
+
+	const std::string_view& GetName() const { return this->name; }
+	Player& SetName(const std::string_view& name) { this->name = name; return *this; }
+
+	const uint8_t& GetLevel() const { return this->level; }
+	Player& SetLevel(const uint8_t& level) { this->level = level; return *this; }
+
+	const double& GetSpeed() const { return this->speed; }
+	Player& SetSpeed(const double& speed) { this->speed = speed; return *this; }
+
+	const std::vector<std::string_view>& GetTitles() const { return this->titles; }
+	Player& SetTitles(const std::vector<std::string_view>& titles) { this->titles = titles; return *this; }
+
+	const Guild& GetGuild() const { return this->guild; }
+	Player& SetGuild(const Guild& guild) { this->guild = guild; return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		if ((*this).name.size() > 16) return "name";
+		if ((*this).name.empty()) return "name";
+		{
+			static const std::regex _pattern("^[A-Za-z][A-Za-z0-9_]*$");
+			if (!std::regex_match((*this).name.begin(), (*this).name.end(), _pattern)) return "name";
+		}
+		if ((*this).level < 1 || (*this).level > 60) return "level";
+		if ((*this).speed < 0.5 || (*this).speed > 2.5) return "speed";
+		if ((*this).guild.tag.size() > 4) return "guild.tag";
+		return nullptr;
+	}
+
+	bool Deserialize(const server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 15 + _size_offset))
+			return false;
+
+		{
+			{
+				uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				uint8_t* _data = _buffer; _buffer += _data_size;
+				(*this).name = std::string_view((const char*)_data, (size_t)_data_size);
+			}
+			(*this).level = *(uint8_t*)_buffer; _buffer += sizeof(uint8_t);
+			(*this).speed = *(double*)_buffer; _buffer += sizeof(double);
+			{
+				uint16_t _count_3 = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+				(*this).titles.resize(_count_3);
+				for (uint16_t _i_3 = 0; _i_3 < _count_3; ++_i_3)
+				{
+					{
+						uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+						uint8_t* _data = _buffer; _buffer += _data_size;
+						(*this).titles[_i_3] = std::string_view((const char*)_data, (size_t)_data_size);
+					}
+				}
+			}
+			{
+				{
+					uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+					uint8_t* _data = _buffer; _buffer += _data_size;
+					(*this).guild.tag = std::string_view((const char*)_data, (size_t)_data_size);
+				}
+			}
+		}
+
+		if (this->Validate() != nullptr) return false;
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		_size_offset += (*this).name.size();
+		_size_offset += (*this).titles.size() * 2;
+		for (const auto& _item_2 : (*this).titles)
+		{
+			_size_offset += _item_2.size();
+		}
+		_size_offset += (*this).guild.tag.size();
+		if (_size_offset > 65535) return false;
+		_message_buffer.SetSize(2 + 15 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		{
+			*(uint16_t*)_buffer = (uint16_t)(*this).name.size(); _buffer += sizeof(uint16_t);
+			std::memcpy((void*)_buffer, (void*)(*this).name.data(), (*this).name.size()); _buffer += (*this).name.size();
+			*(uint8_t*)_buffer = (*this).level; _buffer += sizeof(uint8_t);
+			*(double*)_buffer = (*this).speed; _buffer += sizeof(double);
+			*(uint16_t*)_buffer = (uint16_t)(*this).titles.size(); _buffer += sizeof(uint16_t);
+			for (const auto& _item_3 : (*this).titles)
+			{
+				*(uint16_t*)_buffer = (uint16_t)_item_3.size(); _buffer += sizeof(uint16_t);
+				std::memcpy((void*)_buffer, (void*)_item_3.data(), _item_3.size()); _buffer += _item_3.size();
+			}
+			{
+				*(uint16_t*)_buffer = (uint16_t)(*this).guild.tag.size(); _buffer += sizeof(uint16_t);
+				std::memcpy((void*)_buffer, (void*)(*this).guild.tag.data(), (*this).guild.tag.size()); _buffer += (*this).guild.tag.size();
+			}
+		}
+
+		return true;
+	}
+};
+
+enum TargetKind : uint16_t
+{
+	Someone = 1,
+	Distance = 2,
+};
+class Target
+{
+private:
+
+	TargetKind kind{};
+	std::array<uint8_t, StaticMax<
+			Player, // someone
+			int32_t, // distance
+			uint8_t>::value>
+		value;
+public:
+	// Fingerprint of the wire shape, to compare with the one of a peer
+	static constexpr uint64_t SchemaHash = 0x406ca06bca181471ull;
+
+	~Target()
+	{
+		switch(kind)
+		{
+		case TargetKind::Someone: 
+			((Player*)this->value.data())->~Player();
+			break;
+		case TargetKind::Distance: 
+			break;
+		}
+	}
+	Target() = default;
+	Target(const Target& other) : kind(other.kind)
+	{
+		switch(kind)
+		{
+		case TargetKind::Someone: 
+			new (this->value.data()) Player(*(const Player*)other.value.data());
+			break;
+		case TargetKind::Distance: 
+			new (this->value.data()) int32_t(*(const int32_t*)other.value.data());
+			break;
+		}
+	}
+	Target& operator=(const Target& other) { if (this != &other) { this->~Target(); new (this) Target(other); } return *this; }
+	Target(Target&& other) : kind(other.kind)
+	{
+		switch(kind)
+		{
+		case TargetKind::Someone: 
+			new (this->value.data()) Player(std::move(*(Player*)other.value.data()));
+			break;
+		case TargetKind::Distance: 
+			new (this->value.data()) int32_t(std::move(*(int32_t*)other.value.data()));
+			break;
+		}
+	}
+	Target& operator=(Target&& other) { if (this != &other) { this->~Target(); new (this) Target(std::move(other)); } return *this; }
+
+	const TargetKind& Kind() const { return this->kind; }
+
+	const Player& GetSomeone() const { return *(Player*)this->value.data(); }
+	Target& SetSomeone(const Player& value) { this->~Target(); this->kind = TargetKind::Someone; new (this->value.data()) Player(value); return *this; }
+
+	const int32_t& GetDistance() const { return *(int32_t*)this->value.data(); }
+	Target& SetDistance(const int32_t& value) { this->~Target(); this->kind = TargetKind::Distance; new (this->value.data()) int32_t(value); return *this; }
+
+
+	// Returns the path of the first invalid field or nullptr
+	const char* Validate() const
+	{
+		switch((*this).kind)
+		{
+		case TargetKind::Someone: 
+		{
+			if ((*(Player*)(*this).value.data()).name.size() > 16) return "someone.name";
+			if ((*(Player*)(*this).value.data()).name.empty()) return "someone.name";
+			{
+				static const std::regex _pattern("^[A-Za-z][A-Za-z0-9_]*$");
+				if (!std::regex_match((*(Player*)(*this).value.data()).name.begin(), (*(Player*)(*this).value.data()).name.end(), _pattern)) return "someone.name";
+			}
+			if ((*(Player*)(*this).value.data()).level < 1 || (*(Player*)(*this).value.data()).level > 60) return "someone.level";
+			if ((*(Player*)(*this).value.data()).speed < 0.5 || (*(Player*)(*this).value.data()).speed > 2.5) return "someone.speed";
+			if ((*(Player*)(*this).value.data()).guild.tag.size() > 4) return "someone.guild.tag";
+			break;
+		}
+		case TargetKind::Distance: 
+		{
+			if ((*(int32_t*)(*this).value.data()) < -100 || (*(int32_t*)(*this).value.data()) > 100) return "distance";
+			break;
+		}
+		default: 
+			break;
+		}
+		return nullptr;
+	}
+
+	bool Deserialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		uint16_t _size_offset = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		if(_message_buffer.GetSize() != (2 + 6 + _size_offset))
+			return false;
+
+		(&(*this))->~Target();
+		(*this).kind = (TargetKind)*(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+		switch((*this).kind)
+		{
+		case TargetKind::Someone: 
+		{
+			new (&(*(Player*)(*this).value.data())) Player();
+			{
+				{
+					uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+					uint8_t* _data = _buffer; _buffer += _data_size;
+					(*(Player*)(*this).value.data()).name = std::string_view((const char*)_data, (size_t)_data_size);
+				}
+				(*(Player*)(*this).value.data()).level = *(uint8_t*)_buffer; _buffer += sizeof(uint8_t);
+				(*(Player*)(*this).value.data()).speed = *(double*)_buffer; _buffer += sizeof(double);
+				{
+					uint16_t _count_4 = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+					(*(Player*)(*this).value.data()).titles.resize(_count_4);
+					for (uint16_t _i_4 = 0; _i_4 < _count_4; ++_i_4)
+					{
+						{
+							uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+							uint8_t* _data = _buffer; _buffer += _data_size;
+							(*(Player*)(*this).value.data()).titles[_i_4] = std::string_view((const char*)_data, (size_t)_data_size);
+						}
+					}
+				}
+				{
+					{
+						uint16_t _data_size = *(uint16_t*)_buffer; _buffer += sizeof(uint16_t);
+						uint8_t* _data = _buffer; _buffer += _data_size;
+						(*(Player*)(*this).value.data()).guild.tag = std::string_view((const char*)_data, (size_t)_data_size);
+					}
+				}
+			}
+			break;
+		}
+		case TargetKind::Distance: 
+		{
+			(*(int32_t*)(*this).value.data()) = *(int32_t*)_buffer; _buffer += sizeof(int32_t);
+			break;
+		}
+		}
+
+		if (this->Validate() != nullptr) return false;
+
+		return true;
+	}
+
+	bool Serialize(server::scripting::sdk::MessageBuffer& _message_buffer)
+	{
+		size_t _size_offset = 0;
+		switch((*this).kind)
+		{
+		case TargetKind::Someone: 
+		{
+			_size_offset += 11;
+			_size_offset += (*(Player*)(*this).value.data()).name.size();
+			_size_offset += (*(Player*)(*this).value.data()).titles.size() * 2;
+			for (const auto& _item_3 : (*(Player*)(*this).value.data()).titles)
+			{
+				_size_offset += _item_3.size();
+			}
+			_size_offset += (*(Player*)(*this).value.data()).guild.tag.size();
+			break;
+		}
+		case TargetKind::Distance: 
+		{
+			break;
+		}
+		}
+		if (_size_offset > 65535) return false;
+		_message_buffer.SetSize(2 + 6 + _size_offset);
+
+		uint8_t* _buffer = _message_buffer.GetBuffer();
+
+		*(uint16_t*)_buffer = (uint16_t)_size_offset; _buffer += sizeof(uint16_t);
+		*(uint16_t*)_buffer = (uint16_t)(*this).kind; _buffer += sizeof(uint16_t);
+		switch((*this).kind)
+		{
+		case TargetKind::Someone: 
+		{
+			{
+				*(uint16_t*)_buffer = (uint16_t)(*(Player*)(*this).value.data()).name.size(); _buffer += sizeof(uint16_t);
+				std::memcpy((void*)_buffer, (void*)(*(Player*)(*this).value.data()).name.data(), (*(Player*)(*this).value.data()).name.size()); _buffer += (*(Player*)(*this).value.data()).name.size();
+				*(uint8_t*)_buffer = (*(Player*)(*this).value.data()).level; _buffer += sizeof(uint8_t);
+				*(double*)_buffer = (*(Player*)(*this).value.data()).speed; _buffer += sizeof(double);
+				*(uint16_t*)_buffer = (uint16_t)(*(Player*)(*this).value.data()).titles.size(); _buffer += sizeof(uint16_t);
+				for (const auto& _item_4 : (*(Player*)(*this).value.data()).titles)
+				{
+					*(uint16_t*)_buffer = (uint16_t)_item_4.size(); _buffer += sizeof(uint16_t);
+					std::memcpy((void*)_buffer, (void*)_item_4.data(), _item_4.size()); _buffer += _item_4.size();
+				}
+				{
+					*(uint16_t*)_buffer = (uint16_t)(*(Player*)(*this).value.data()).guild.tag.size(); _buffer += sizeof(uint16_t);
+					std::memcpy((void*)_buffer, (void*)(*(Player*)(*this).value.data()).guild.tag.data(), (*(Player*)(*this).value.data()).guild.tag.size()); _buffer += (*(Player*)(*this).value.data()).guild.tag.size();
+				}
+			}
+			break;
+		}
+		case TargetKind::Distance: 
+		{
+			*(int32_t*)_buffer = (*(int32_t*)(*this).value.data()); _buffer += sizeof(int32_t);
+			break;
+		}
+		}
+
+		return true;
+	}
+};
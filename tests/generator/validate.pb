@@ -0,0 +1,31 @@
+opt message_buffer;
+opt validate;
+
+struct Player {
+    var name: string {
+        opt non_empty;
+        opt max_len(16);
+        opt pattern("^[A-Za-z][A-Za-z0-9_]*$");
+    }
+    var level: uint8 {
+        opt range(1, 60);
+    }
+    var speed: double {
+        opt range(0.5, 2.5);
+    }
+    var titles: [string];
+    var guild: Guild;
+}
+
+struct Guild {
+    var tag: string {
+        opt max_len(4);
+    }
+}
+
+variant Target {
+    var someone: Player;
+    var distance: int32 {
+        opt range(-100, 100);
+    }
+}